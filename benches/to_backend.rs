@@ -0,0 +1,35 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+#![feature(test)]
+extern crate test;
+
+use generic_json::{to_backend, MetaValue};
+use serde_json::{json, Value};
+use test::Bencher;
+
+fn document() -> Value {
+	let item = json!({
+		"id": 12345,
+		"name": "generic-json",
+		"tags": ["a", "b", "c"],
+		"ratio": 0.25,
+		"nested": { "flag": true, "missing": null }
+	});
+
+	Value::Array(vec![item; 1000])
+}
+
+#[bench]
+fn copy_with_to_backend(b: &mut Bencher) {
+	let value = document();
+	b.iter(|| to_backend::<_, MetaValue>(test::black_box(&value)))
+}
+
+#[bench]
+fn copy_through_a_string(b: &mut Bencher) {
+	let value = document();
+	b.iter(|| {
+		let s = serde_json::to_string(test::black_box(&value)).unwrap();
+		let (copy, _): (MetaValue, _) = generic_json::parse_prefix(&s).unwrap();
+		copy
+	})
+}
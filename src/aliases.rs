@@ -1,4 +1,4 @@
-use super::{Json, JsonNew, ValueMut, ValueRef};
+use super::{Json, JsonNew, NumberFrom, ValueMut, ValueRef};
 use cc_traits::{
 	CollectionMut, CollectionRef, GetMut, Iter, IterMut, KeyedRef, MapInsert, MapIter, MapIterMut,
 	PopBack, PushBack, Remove,
@@ -43,6 +43,7 @@ where
 /// JSON type that can be built.
 pub trait JsonBuild = JsonNew
 where
+	<Self as Json>::Number: NumberFrom,
 	<Self as Json>::String: for<'a> From<&'a str>,
 	<Self as Json>::Array: Default + std::iter::FromIterator<Self>,
 	<Self as Json>::Object: Default + std::iter::FromIterator<(<Self as Json>::Key, Self)>;
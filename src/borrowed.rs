@@ -0,0 +1,289 @@
+use crate::{
+	number,
+	parse::{Builder, Parser},
+	Json, Key, Number, ParseError, ParseOptions, PrecisionLost, Value, ValueMut, ValueRef,
+};
+use std::{borrow::Cow, collections::BTreeMap, fmt, ops::Deref};
+
+/// String borrowing from a parsed document when possible.
+///
+/// Strings containing no escape sequence are borrowed from the document,
+/// other strings are owned.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct BorrowedStr<'a>(Cow<'a, str>);
+
+impl<'a> BorrowedStr<'a> {
+	/// Checks if this string is borrowed from the parsed document.
+	pub fn is_borrowed(&self) -> bool {
+		matches!(self.0, Cow::Borrowed(_))
+	}
+
+	/// Returns the underlying `Cow` string.
+	pub fn as_cow(&self) -> &Cow<'a, str> {
+		&self.0
+	}
+
+	/// Turns this string into the underlying `Cow` string.
+	pub fn into_cow(self) -> Cow<'a, str> {
+		self.0
+	}
+}
+
+impl<'a> Deref for BorrowedStr<'a> {
+	type Target = str;
+
+	fn deref(&self) -> &str {
+		&self.0
+	}
+}
+
+impl<'a> std::borrow::Borrow<str> for BorrowedStr<'a> {
+	fn borrow(&self) -> &str {
+		&self.0
+	}
+}
+
+impl<'a> From<Cow<'a, str>> for BorrowedStr<'a> {
+	fn from(s: Cow<'a, str>) -> Self {
+		Self(s)
+	}
+}
+
+/// Creates an owned string.
+impl<'a, 'b> From<&'b str> for BorrowedStr<'a> {
+	fn from(s: &'b str) -> Self {
+		Self(Cow::Owned(s.to_string()))
+	}
+}
+
+impl<'a> fmt::Display for BorrowedStr<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl<'a> Key<()> for BorrowedStr<'a> {
+	fn metadata(&self) -> &() {
+		&()
+	}
+}
+
+/// Number borrowed from a parsed document.
+///
+/// The number is stored as its lexical representation,
+/// and converted on demand.
+/// Two numbers are equal if they have the same lexical representation.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BorrowedNumber<'a>(&'a str);
+
+impl<'a> BorrowedNumber<'a> {
+	/// Returns the lexical representation of the number.
+	pub fn as_str(&self) -> &'a str {
+		self.0
+	}
+}
+
+impl<'a> fmt::Display for BorrowedNumber<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl<'a> Number for BorrowedNumber<'a> {
+	fn as_u32(&self) -> Option<u32> {
+		self.0.parse().ok()
+	}
+
+	fn as_u64(&self) -> Option<u64> {
+		self.0.parse().ok()
+	}
+
+	fn as_i32(&self) -> Option<i32> {
+		self.0.parse().ok()
+	}
+
+	fn as_i64(&self) -> Option<i64> {
+		self.0.parse().ok()
+	}
+
+	fn as_f32(&self) -> Option<f32> {
+		number::lexical_as_f32(self.0)
+	}
+
+	fn as_f32_lossy(&self) -> f32 {
+		number::lexical_as_f64_lossy(self.0) as f32
+	}
+
+	fn as_f64(&self) -> Option<f64> {
+		number::lexical_as_f64(self.0)
+	}
+
+	fn as_f64_lossy(&self) -> f64 {
+		number::lexical_as_f64_lossy(self.0)
+	}
+
+	fn as_f64_checked(&self) -> Result<f64, PrecisionLost> {
+		number::lexical_as_f64_checked(self.0)
+	}
+
+	fn as_str(&self) -> Option<&str> {
+		Some(self.0)
+	}
+
+	fn is_integer(&self) -> bool {
+		number::lexical_is_integer(self.0)
+	}
+
+	fn is_negative(&self) -> bool {
+		number::lexical_is_negative(self.0)
+	}
+
+	fn is_finite(&self) -> bool {
+		true
+	}
+}
+
+/// JSON value borrowing its strings from the parsed document.
+///
+/// Parsing a large document with [`BorrowedValue::parse`] does not copy
+/// its strings and keys: only the strings containing escape sequences
+/// are allocated. Numbers are kept in their lexical form.
+/// This makes it suitable to inspect large (possibly memory-mapped) documents,
+/// as long as the document outlives the value.
+///
+/// This type does not implement [`JsonNew`](crate::JsonNew):
+/// values are only created by parsing.
+/// It is not read-only however: like any [`Json`] type, a parsed value can be
+/// modified in place through [`Json::as_value_mut`].
+/// The document itself is never modified,
+/// strings and keys inserted this way being owned.
+/// Object members are sorted by key,
+/// and only the last occurrence of a duplicate key is kept.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum BorrowedValue<'a> {
+	Null,
+	Boolean(bool),
+	Number(BorrowedNumber<'a>),
+	String(BorrowedStr<'a>),
+	Array(Vec<BorrowedValue<'a>>),
+	Object(BTreeMap<BorrowedStr<'a>, BorrowedValue<'a>>),
+}
+
+impl<'a> BorrowedValue<'a> {
+	/// Parses the given JSON document.
+	pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+		Self::parse_with(input, ParseOptions::default())
+	}
+
+	/// Parses the given JSON document with the given options.
+	pub fn parse_with(input: &'a str, options: ParseOptions) -> Result<Self, ParseError> {
+		Parser::new(input, options).parse_document(&mut BorrowedBuilder)
+	}
+}
+
+struct BorrowedBuilder;
+
+impl<'a> Builder<'a> for BorrowedBuilder {
+	type Value = BorrowedValue<'a>;
+
+	fn null(&mut self) -> Self::Value {
+		BorrowedValue::Null
+	}
+
+	fn boolean(&mut self, b: bool) -> Self::Value {
+		BorrowedValue::Boolean(b)
+	}
+
+	fn number(&mut self, n: &'a str) -> Option<Self::Value> {
+		Some(BorrowedValue::Number(BorrowedNumber(n)))
+	}
+
+	fn string(&mut self, s: Cow<'a, str>) -> Self::Value {
+		BorrowedValue::String(BorrowedStr(s))
+	}
+
+	fn array(&mut self, items: Vec<Self::Value>) -> Self::Value {
+		BorrowedValue::Array(items)
+	}
+
+	fn object(&mut self, entries: Vec<(Cow<'a, str>, Self::Value)>) -> Self::Value {
+		BorrowedValue::Object(
+			entries
+				.into_iter()
+				.map(|(key, value)| (BorrowedStr(key), value))
+				.collect(),
+		)
+	}
+}
+
+impl<'a> Json for BorrowedValue<'a> {
+	type MetaData = ();
+	type Number = BorrowedNumber<'a>;
+	type String = BorrowedStr<'a>;
+	type Array = Vec<Self>;
+	type Key = BorrowedStr<'a>;
+	type Object = BTreeMap<BorrowedStr<'a>, Self>;
+
+	/// Returns a reference to the actual JSON value (without the metadata).
+	fn as_value_ref(&self) -> ValueRef<'_, Self> {
+		self.into()
+	}
+
+	/// Returns a mutable reference to the actual JSON value (without the metadata).
+	fn as_value_mut(&mut self) -> ValueMut<'_, Self> {
+		self.into()
+	}
+
+	/// Transforms this JSON value into a `Value` and `MetaData`.
+	fn into_parts(self) -> (Value<Self>, Self::MetaData) {
+		let value = match self {
+			Self::Null => Value::Null,
+			Self::Boolean(b) => Value::Boolean(b),
+			Self::Number(n) => Value::Number(n),
+			Self::String(s) => Value::String(s),
+			Self::Array(a) => Value::Array(a),
+			Self::Object(o) => Value::Object(o),
+		};
+
+		(value, ())
+	}
+
+	/// Returns a reference to the metadata associated to the JSON value.
+	fn metadata(&self) -> &Self::MetaData {
+		&()
+	}
+
+	/// Returns a pair containing a mutable reference to the JSON value and a reference to its metadata.
+	fn as_pair_mut(&mut self) -> (ValueMut<'_, Self>, &Self::MetaData) {
+		(self.as_value_mut(), &())
+	}
+}
+
+impl<'b, 'a> From<&'b BorrowedValue<'a>> for ValueRef<'b, BorrowedValue<'a>> {
+	fn from(value: &'b BorrowedValue<'a>) -> Self {
+		match value {
+			BorrowedValue::Null => ValueRef::Null,
+			BorrowedValue::Boolean(b) => ValueRef::Boolean(*b),
+			BorrowedValue::Number(n) => ValueRef::Number(n),
+			BorrowedValue::String(s) => ValueRef::String(s),
+			BorrowedValue::Array(a) => ValueRef::Array(a),
+			BorrowedValue::Object(o) => ValueRef::Object(o),
+		}
+	}
+}
+
+impl<'b, 'a> From<&'b mut BorrowedValue<'a>> for ValueMut<'b, BorrowedValue<'a>> {
+	fn from(value: &'b mut BorrowedValue<'a>) -> Self {
+		match value {
+			BorrowedValue::Null => ValueMut::Null,
+			BorrowedValue::Boolean(b) => ValueMut::Boolean(*b),
+			BorrowedValue::Number(n) => ValueMut::Number(n),
+			BorrowedValue::String(s) => ValueMut::String(s),
+			BorrowedValue::Array(a) => ValueMut::Array(a),
+			BorrowedValue::Object(o) => ValueMut::Object(o),
+		}
+	}
+}
+
+#[cfg(feature = "serde_json-impl")]
+impl<'a> crate::AsSerdeJson for BorrowedValue<'a> {}
@@ -0,0 +1,243 @@
+use crate::{number, trace, write::write_string, Json, Number, ValueRef};
+use cc_traits::{Iter, MapIter};
+use std::fmt::{self, Write};
+
+/// Error returned by [`canonicalize`] when a number
+/// is outside of the range of an `f64`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NumberOutOfRange {
+	lexical: String,
+}
+
+impl NumberOutOfRange {
+	/// Returns the lexical representation of the number.
+	pub fn lexical(&self) -> &str {
+		&self.lexical
+	}
+}
+
+impl fmt::Display for NumberOutOfRange {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "`{}` is outside of the range of an `f64`", self.lexical)
+	}
+}
+
+impl std::error::Error for NumberOutOfRange {}
+
+/// Serializes the given value following the JSON Canonicalization Scheme
+/// ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)).
+///
+/// The output contains no insignificant whitespace,
+/// object members are sorted by the UTF-16 code units of their keys,
+/// and strings only escape the characters that must be escaped.
+/// Numbers are serialized as ECMAScript's `Number.prototype.toString` would,
+/// from their [`as_f64_lossy`](Number::as_f64_lossy) value:
+/// numbers that exceed the precision of an `f64`
+/// (such as integers above 2<sup>53</sup>) are rounded to the nearest `f64`,
+/// as required by the scheme.
+/// Numbers outside of the `f64` range (such as `1e400`), and non-finite numbers
+/// for backends able to hold them, cannot be canonicalized:
+/// a [`NumberOutOfRange`] error is returned instead.
+///
+/// ```
+/// # #[cfg(feature = "serde_json-impl")]
+/// # {
+/// use generic_json::{canonicalize, TriviaValue};
+///
+/// let value = TriviaValue::parse(r#"{
+///   "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+///   "string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+///   "literals": [null, true, false]
+/// }"#).unwrap();
+///
+/// assert_eq!(
+///   canonicalize(&value).unwrap(),
+///   r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+/// );
+///
+/// let value: serde_json::Value = serde_json::from_str(r#"{
+///   "\u20ac": "Euro Sign",
+///   "\r": "Carriage Return",
+///   "\ufb33": "Hebrew Letter Dalet With Dagesh",
+///   "1": "One",
+///   "\ud83d\ude00": "Emoji: Grinning Face",
+///   "\u0080": "Control",
+///   "\u00f6": "Latin Small Letter O With Diaeresis"
+/// }"#).unwrap();
+///
+/// assert_eq!(
+///   canonicalize(&value).unwrap(),
+///   "{\"\\r\":\"Carriage Return\",\"1\":\"One\",\"\u{80}\":\"Control\",\
+///   \"ö\":\"Latin Small Letter O With Diaeresis\",\"€\":\"Euro Sign\",\
+///   \"😀\":\"Emoji: Grinning Face\",\"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}"
+/// );
+///
+/// for (bits, expected) in [
+///   (0x0000000000000000u64, "0"),
+///   (0x8000000000000000, "0"),
+///   (0x0000000000000001, "5e-324"),
+///   (0x8000000000000001, "-5e-324"),
+///   (0x7fefffffffffffff, "1.7976931348623157e+308"),
+///   (0xffefffffffffffff, "-1.7976931348623157e+308"),
+///   (0x4340000000000000, "9007199254740992"),
+///   (0xc340000000000000, "-9007199254740992"),
+///   (0x4430000000000000, "295147905179352830000"),
+///   (0x44b52d02c7e14af5, "9.999999999999997e+22"),
+///   (0x44b52d02c7e14af6, "1e+23"),
+///   (0x44b52d02c7e14af7, "1.0000000000000001e+23"),
+///   (0x444b1ae4d6e2ef4e, "999999999999999700000"),
+///   (0x444b1ae4d6e2ef4f, "999999999999999900000"),
+///   (0x444b1ae4d6e2ef50, "1e+21"),
+///   (0x3eb0c6f7a0b5ed8c, "9.999999999999997e-7"),
+///   (0x3eb0c6f7a0b5ed8d, "0.000001"),
+///   (0x41b3de4355555553, "333333333.3333332"),
+///   (0x41b3de4355555554, "333333333.33333325"),
+///   (0x41b3de4355555555, "333333333.3333333"),
+///   (0x41b3de4355555556, "333333333.3333334"),
+///   (0x41b3de4355555557, "333333333.33333343"),
+///   (0xbecbf647612f3696, "-0.0000033333333333333333"),
+///   (0x43143ff3c1cb0959, "1424953923781206.2"),
+/// ] {
+///   let value = serde_json::Value::from(f64::from_bits(bits));
+///   assert_eq!(canonicalize(&value).unwrap(), expected);
+/// }
+/// # }
+/// ```
+pub fn canonicalize<T: Json>(value: &T) -> Result<String, NumberOutOfRange> {
+	let _operation = trace::operation!("canonicalize");
+	let mut output = String::new();
+	write_canonical(value.as_value_ref(), &mut output)?;
+	Ok(output)
+}
+
+fn write_canonical<T: Json>(
+	value: ValueRef<T>,
+	output: &mut String,
+) -> Result<(), NumberOutOfRange> {
+	match value {
+		ValueRef::Null => output.push_str("null"),
+		ValueRef::Boolean(true) => output.push_str("true"),
+		ValueRef::Boolean(false) => output.push_str("false"),
+		ValueRef::Number(n) => {
+			let f = n.as_f64_lossy();
+			if f.is_finite() {
+				write_es_number(f, output)
+			} else {
+				return Err(NumberOutOfRange {
+					lexical: match n.as_str() {
+						Some(s) => s.to_string(),
+						None => number::to_decimal_string(n),
+					},
+				});
+			}
+		}
+		ValueRef::String(s) => write_string(s, output).unwrap(),
+		ValueRef::Array(a) => {
+			output.push('[');
+			for (i, item) in Iter::iter(a).enumerate() {
+				if i > 0 {
+					output.push(',')
+				}
+
+				write_canonical(item.as_value_ref(), output)?
+			}
+			output.push(']')
+		}
+		ValueRef::Object(o) => {
+			let mut members: Vec<_> = MapIter::iter(o).collect();
+			members.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+
+			output.push('{');
+			for (i, (key, item)) in members.into_iter().enumerate() {
+				if i > 0 {
+					output.push(',')
+				}
+
+				write_string(&key, output).unwrap();
+				output.push(':');
+				write_canonical(item.as_value_ref(), output)?
+			}
+			output.push('}')
+		}
+	}
+
+	Ok(())
+}
+
+/// Writes the given finite number as ECMAScript's `Number.prototype.toString` does.
+fn write_es_number(f: f64, output: &mut String) {
+	if f == 0.0 {
+		// Including `-0`.
+		output.push('0');
+		return;
+	}
+
+	if f < 0.0 {
+		output.push('-')
+	}
+
+	let (digits, exponent) = shortest_digits(f.abs());
+	let k = digits.len() as i32;
+	let n = exponent + 1;
+
+	if k <= n && n <= 21 {
+		output.push_str(&digits);
+		output.extend(std::iter::repeat_n('0', (n - k) as usize))
+	} else if 0 < n && n <= 21 {
+		output.push_str(&digits[..n as usize]);
+		output.push('.');
+		output.push_str(&digits[n as usize..])
+	} else if -6 < n && n <= 0 {
+		output.push_str("0.");
+		output.extend(std::iter::repeat_n('0', -n as usize));
+		output.push_str(&digits)
+	} else {
+		output.push_str(&digits[..1]);
+		if k > 1 {
+			output.push('.');
+			output.push_str(&digits[1..])
+		}
+
+		write!(
+			output,
+			"e{}{}",
+			if n > 0 { '+' } else { '-' },
+			(n - 1).abs()
+		)
+		.unwrap()
+	}
+}
+
+/// Returns the shortest digits `d` and the exponent `e`
+/// such that `d[0].d[1..] * 10^e` rounds to the given positive number.
+///
+/// When two candidates are equally close to the number,
+/// the one with an even last digit is chosen, as in ECMAScript.
+fn shortest_digits(f: f64) -> (String, i32) {
+	let (mut digits, exponent) = split_scientific(&format!("{:e}", f));
+
+	// The number is halfway between two candidates
+	// if its exact value has one more digit, which is a `5`.
+	// Every `f64` is exactly represented with 767 significant digits.
+	let (exact, exact_exponent) = split_scientific(&format!("{:.766e}", f));
+	let exact = exact.trim_end_matches('0');
+	if exponent == exact_exponent && exact.len() == digits.len() + 1 && exact.ends_with('5') {
+		let lower = &exact[..digits.len()];
+		if lower.ends_with(['0', '2', '4', '6', '8'])
+			&& format!("{}e{}", lower, exponent + 1 - lower.len() as i32).parse() == Ok(f)
+		{
+			digits = lower.to_string()
+		}
+	}
+
+	(digits, exponent)
+}
+
+/// Splits the given number in scientific notation into its digits and exponent.
+fn split_scientific(scientific: &str) -> (String, i32) {
+	let (mantissa, exponent) = scientific.split_once('e').unwrap();
+	(
+		mantissa.chars().filter(|c| *c != '.').collect(),
+		exponent.parse().unwrap(),
+	)
+}
@@ -0,0 +1,154 @@
+use crate::{number, Json, ValueRef};
+use cc_traits::{CollectionRef, Get, Iter, Len, MapIter};
+use std::{borrow::Borrow, collections::HashSet, hash::Hash};
+
+/// Options of [`Json::semantically_eq`](crate::Json::semantically_eq).
+///
+/// By default, no difference is ignored.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct CompareOptions {
+	/// Object members whose value is `null` are considered absent.
+	pub ignore_null_fields: bool,
+
+	/// Object members whose value is an empty array or object are considered absent.
+	pub ignore_empty_containers: bool,
+
+	/// Numbers are compared by value, so that `1` and `1.0` are equal.
+	///
+	/// Otherwise an integer is never equal to a non-integer number.
+	pub numeric_coercion: bool,
+}
+
+impl CompareOptions {
+	/// Checks if the given object member value must be considered absent.
+	fn ignores<T: Json>(&self, value: &T) -> bool {
+		(self.ignore_null_fields && value.is_null())
+			|| (self.ignore_empty_containers && value.is_empty_array_or_object())
+	}
+
+	/// Counts the object members that are not ignored.
+	fn count_members<T: Json>(&self, o: &T::Object) -> usize {
+		MapIter::iter(o)
+			.filter(|(_, item)| !self.ignores(&**item))
+			.count()
+	}
+}
+
+/// Matches object members, given one at a time, with the members of an object.
+///
+/// Shared by every structural comparison of the crate, so that they agree
+/// on objects holding duplicate keys: a key matched twice, or a key held
+/// twice by the object, makes the objects different.
+pub(crate) struct MemberMatcher<'b, T: Json, K> {
+	object: &'b T::Object,
+	seen: HashSet<K>,
+}
+
+impl<'b, T: Json, K: Borrow<str> + Hash + Eq> MemberMatcher<'b, T, K> {
+	pub(crate) fn new(object: &'b T::Object) -> Self {
+		Self {
+			object,
+			seen: HashSet::new(),
+		}
+	}
+
+	/// Returns the member of the object associated to the given key.
+	///
+	/// Returns `None` if there is no such member,
+	/// or if the key has already been matched.
+	pub(crate) fn find(&mut self, key: K) -> Option<<T::Object as CollectionRef>::ItemRef<'b>> {
+		let item = self.object.get(key.borrow())?;
+		if self.seen.insert(key) {
+			Some(item)
+		} else {
+			None
+		}
+	}
+
+	/// Checks that exactly `count` distinct keys have been matched.
+	///
+	/// `count` is the number of members of the object that must be matched,
+	/// duplicates included, so that an object holding duplicate keys never
+	/// passes this check.
+	pub(crate) fn matched(&self, count: usize) -> bool {
+		self.seen.len() == count
+	}
+}
+
+pub(crate) fn semantically_eq<A: Json, B: Json>(a: &A, b: &B, options: &CompareOptions) -> bool {
+	value_refs_eq(a.as_value_ref(), b.as_value_ref(), options)
+}
+
+/// Compares two value references as by [`Json::semantically_eq`](crate::Json::semantically_eq).
+pub(crate) fn value_refs_eq<A: Json, B: Json>(
+	a: ValueRef<A>,
+	b: ValueRef<B>,
+	options: &CompareOptions,
+) -> bool {
+	match (a, b) {
+		(ValueRef::Null, ValueRef::Null) => true,
+		(ValueRef::Boolean(a), ValueRef::Boolean(b)) => a == b,
+		(ValueRef::Number(a), ValueRef::Number(b)) => {
+			(options.numeric_coercion
+				|| number::as_i128(a).is_some() == number::as_i128(b).is_some())
+				&& number::numeric_eq(a, b)
+		}
+		(ValueRef::String(a), ValueRef::String(b)) => **a == **b,
+		(ValueRef::Array(a), ValueRef::Array(b)) => {
+			a.len() == b.len()
+				&& Iter::iter(a)
+					.zip(Iter::iter(b))
+					.all(|(a, b)| semantically_eq(&*a, &*b, options))
+		}
+		(ValueRef::Object(a), ValueRef::Object(b)) => {
+			let members: Vec<_> = MapIter::iter(a)
+				.filter(|(_, item)| !options.ignores(&**item))
+				.collect();
+			// Since both objects have as many members, matching every member
+			// of `a` at most once also rules out duplicate keys in `b`.
+			let mut matcher = MemberMatcher::<B, &str>::new(b);
+			members.len() == options.count_members::<B>(b)
+				&& members.iter().all(|(key, a)| match matcher.find(&***key) {
+					Some(b) => semantically_eq(&**a, &*b, options),
+					None => false,
+				})
+		}
+		_ => false,
+	}
+}
+
+/// Compares two value references as by [`json_eq`].
+pub(crate) fn value_refs_json_eq<A: Json, B: Json>(a: ValueRef<A>, b: ValueRef<B>) -> bool {
+	value_refs_eq(a, b, &JSON_EQ)
+}
+
+/// Options of [`json_eq`].
+const JSON_EQ: CompareOptions = CompareOptions {
+	ignore_null_fields: false,
+	ignore_empty_containers: false,
+	numeric_coercion: true,
+};
+
+/// Compares two JSON values of possibly different types, structurally.
+///
+/// Arrays are compared element by element, and objects by key lookup,
+/// regardless of the order in which their members are stored.
+/// Objects holding the same key more than once are never equal.
+/// Numbers are compared by value, so that `1` and `1.0` are equal.
+/// Metadata is ignored.
+///
+/// ```
+/// # #[cfg(feature = "serde_json-impl")]
+/// # {
+/// use generic_json::{json_eq, TriviaValue};
+///
+/// let a = TriviaValue::parse(r#"{ "a": 1, "b": [true, null] }"#).unwrap();
+/// let b = TriviaValue::parse(r#"{ "b": [true, null], "a": 1.0 }"#).unwrap();
+/// assert!(json_eq(&a, &b));
+/// assert!(json_eq(&a, &serde_json::json!({ "b": [true, null], "a": 1 })));
+/// assert!(!json_eq(&a, &serde_json::json!({ "a": 1 })));
+/// # }
+/// ```
+pub fn json_eq<T: Json, U: Json>(a: &T, b: &U) -> bool {
+	semantically_eq(a, b, &JSON_EQ)
+}
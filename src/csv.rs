@@ -0,0 +1,104 @@
+use crate::{number, Json, ValueRef};
+use cc_traits::{Get, Iter, MapIter};
+use std::fmt;
+
+/// Error returned by [`Json::to_csv`](crate::Json::to_csv).
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CsvError {
+	/// The value is not an array.
+	NotAnArray,
+
+	/// The element at the given index is not an object.
+	NotAnObject(usize),
+
+	/// The member with the given key of the element at the given index
+	/// is an array or an object.
+	NestedValue(usize, String),
+}
+
+impl fmt::Display for CsvError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::NotAnArray => write!(f, "value is not an array"),
+			Self::NotAnObject(i) => write!(f, "element {} is not an object", i),
+			Self::NestedValue(i, key) => {
+				write!(f, "member `{}` of element {} is not a scalar", key, i)
+			}
+		}
+	}
+}
+
+impl std::error::Error for CsvError {}
+
+/// Appends the given CSV field to `out`, quoting it if necessary.
+fn push_field(out: &mut String, field: &str) {
+	if field.contains([',', '"', '\n', '\r']) {
+		out.push('"');
+		out.push_str(&field.replace('"', "\"\""));
+		out.push('"')
+	} else {
+		out.push_str(field)
+	}
+}
+
+/// Appends the given CSV record to `out`.
+fn push_record<'s>(out: &mut String, fields: impl IntoIterator<Item = &'s str>) {
+	for (i, field) in fields.into_iter().enumerate() {
+		if i > 0 {
+			out.push(',')
+		}
+
+		push_field(out, field)
+	}
+
+	out.push('\n')
+}
+
+pub(crate) fn to_csv<T: Json>(value: &T, headers: Option<&[&str]>) -> Result<String, CsvError> {
+	let a = value.as_array().ok_or(CsvError::NotAnArray)?;
+
+	let headers: Vec<String> = match headers {
+		Some(headers) => headers.iter().map(|h| h.to_string()).collect(),
+		None => {
+			let mut headers: Vec<String> = Vec::new();
+			for (i, item) in Iter::iter(a).enumerate() {
+				let o = item.as_object().ok_or(CsvError::NotAnObject(i))?;
+				for (key, _) in MapIter::iter(o) {
+					if !headers.iter().any(|h| **h == **key) {
+						headers.push(key.to_string())
+					}
+				}
+			}
+
+			headers
+		}
+	};
+
+	let mut out = String::new();
+	push_record(&mut out, headers.iter().map(String::as_str));
+
+	for (i, item) in Iter::iter(a).enumerate() {
+		let o = item.as_object().ok_or(CsvError::NotAnObject(i))?;
+		let mut fields = Vec::with_capacity(headers.len());
+		for key in &headers {
+			let field = match o.get(key.as_str()) {
+				Some(member) => match member.as_value_ref() {
+					ValueRef::Null => String::new(),
+					ValueRef::Boolean(b) => b.to_string(),
+					ValueRef::Number(n) => number::to_decimal_string(n),
+					ValueRef::String(s) => s.to_string(),
+					ValueRef::Array(_) | ValueRef::Object(_) => {
+						return Err(CsvError::NestedValue(i, key.clone()))
+					}
+				},
+				None => String::new(),
+			};
+
+			fields.push(field)
+		}
+
+		push_record(&mut out, fields.iter().map(String::as_str))
+	}
+
+	Ok(out)
+}
@@ -0,0 +1,55 @@
+use crate::Json;
+use std::{collections::HashSet, iter::FromIterator};
+
+/// Policy used by [`Json::dedup_keys`] to collapse duplicate object keys.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DuplicateKeyPolicy {
+	/// Keep the first entry of each key.
+	KeepFirst,
+
+	/// Keep the last entry of each key.
+	KeepLast,
+}
+
+impl DuplicateKeyPolicy {
+	/// Removes the duplicate keys of the given object, according to this policy.
+	///
+	/// The remaining entries keep their relative order.
+	pub fn dedup<T: Json>(&self, object: T::Object) -> T::Object
+	where
+		T::Object: FromIterator<(T::Key, T)>,
+	{
+		let entries: Vec<_> = object.into_iter().collect();
+
+		let mut keep = vec![false; entries.len()];
+		let mut seen = HashSet::new();
+		match self {
+			Self::KeepFirst => {
+				for (i, (key, _)) in entries.iter().enumerate() {
+					keep[i] = seen.insert(&**key)
+				}
+			}
+			Self::KeepLast => {
+				for (i, (key, _)) in entries.iter().enumerate().rev() {
+					keep[i] = seen.insert(&**key)
+				}
+			}
+		}
+
+		entries
+			.into_iter()
+			.zip(keep)
+			.filter_map(|(entry, keep)| if keep { Some(entry) } else { None })
+			.collect()
+	}
+}
+
+/// Checks if the given object contains the same key more than once.
+#[cfg(feature = "nightly")]
+pub(crate) fn has_duplicate_keys<T: Json>(object: &T::Object) -> bool {
+	let keys: Vec<_> = cc_traits::MapIter::iter(object)
+		.map(|(key, _)| key)
+		.collect();
+	let mut seen = HashSet::new();
+	keys.iter().any(|key| !seen.insert(&***key))
+}
@@ -0,0 +1,135 @@
+use crate::{JsonBuild, NumberFrom, Value};
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::{fmt, marker::PhantomData};
+
+/// Deserializes a JSON value of type `T` from any serde data format.
+///
+/// Every value and key is given the default metadata.
+/// Numbers are built from their decimal representation,
+/// and non-finite floating point numbers are rejected.
+/// Object keys must be strings.
+pub fn deserialize_into<'de, T, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>
+where
+	T: JsonBuild,
+	T::MetaData: Default,
+{
+	Seed(PhantomData).deserialize(deserializer)
+}
+
+/// Seed deserializing a value of type `T`.
+struct Seed<T>(PhantomData<T>);
+
+impl<'de, T> DeserializeSeed<'de> for Seed<T>
+where
+	T: JsonBuild,
+	T::MetaData: Default,
+{
+	type Value = T;
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+		deserializer.deserialize_any(ValueVisitor(PhantomData))
+	}
+}
+
+/// Visitor building a value of type `T`.
+struct ValueVisitor<T>(PhantomData<T>);
+
+impl<T> ValueVisitor<T>
+where
+	T: JsonBuild,
+	T::MetaData: Default,
+{
+	fn number<E: de::Error>(s: &str) -> Result<T, E> {
+		match T::Number::from_lexical(s) {
+			Some(n) => Ok(T::number(n, T::MetaData::default())),
+			None => Err(E::custom(format_args!("invalid number `{}`", s))),
+		}
+	}
+}
+
+impl<'de, T> Visitor<'de> for ValueVisitor<T>
+where
+	T: JsonBuild,
+	T::MetaData: Default,
+{
+	type Value = T;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "a JSON value")
+	}
+
+	fn visit_unit<E: de::Error>(self) -> Result<T, E> {
+		Ok(T::null(T::MetaData::default()))
+	}
+
+	fn visit_none<E: de::Error>(self) -> Result<T, E> {
+		self.visit_unit()
+	}
+
+	fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+		Seed(PhantomData).deserialize(deserializer)
+	}
+
+	fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+		Seed(PhantomData).deserialize(deserializer)
+	}
+
+	fn visit_bool<E: de::Error>(self, b: bool) -> Result<T, E> {
+		Ok(T::boolean(b, T::MetaData::default()))
+	}
+
+	fn visit_i64<E: de::Error>(self, i: i64) -> Result<T, E> {
+		Self::number(&i.to_string())
+	}
+
+	fn visit_u64<E: de::Error>(self, u: u64) -> Result<T, E> {
+		Self::number(&u.to_string())
+	}
+
+	fn visit_i128<E: de::Error>(self, i: i128) -> Result<T, E> {
+		Self::number(&i.to_string())
+	}
+
+	fn visit_u128<E: de::Error>(self, u: u128) -> Result<T, E> {
+		Self::number(&u.to_string())
+	}
+
+	fn visit_f64<E: de::Error>(self, f: f64) -> Result<T, E> {
+		if f.is_finite() {
+			// The `Debug` representation of a finite float is a valid JSON number
+			// that parses back to the same float.
+			Self::number(&format!("{:?}", f))
+		} else {
+			Err(E::custom(format_args!("invalid number `{}`", f)))
+		}
+	}
+
+	fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+		Ok(T::string(s.into(), T::MetaData::default()))
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+		let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(item) = seq.next_element_seed(Seed(PhantomData))? {
+			items.push(item)
+		}
+
+		Ok(T::new(
+			Value::Array(items.into_iter().collect()),
+			T::MetaData::default(),
+		))
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+		let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+		while let Some(key) = map.next_key::<String>()? {
+			let item = map.next_value_seed(Seed(PhantomData))?;
+			entries.push((T::new_key(&key, T::MetaData::default()), item))
+		}
+
+		Ok(T::new(
+			Value::Object(entries.into_iter().collect()),
+			T::MetaData::default(),
+		))
+	}
+}
@@ -0,0 +1,70 @@
+use crate::{number, Json, ValueRef};
+use cc_traits::{Get, Iter, Len, MapIter};
+
+/// Summary of the differences between two JSON values.
+///
+/// Returned by [`Json::diff_summary`](crate::Json::diff_summary).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct DiffSummary {
+	/// Number of object keys found only in the new value.
+	pub added_keys: usize,
+
+	/// Number of object keys found only in the old value.
+	pub removed_keys: usize,
+
+	/// Number of values that differ, other than through added or removed
+	/// keys and elements.
+	///
+	/// A scalar value that differs, or a value whose type differs, counts as one change.
+	pub changed_values: usize,
+
+	/// Number of array elements found only in the new value,
+	/// at the end of a grown array.
+	pub added_elements: usize,
+
+	/// Number of array elements found only in the old value,
+	/// at the end of a shrunk array.
+	pub removed_elements: usize,
+}
+
+impl DiffSummary {
+	/// Checks if no difference has been found.
+	pub fn is_empty(&self) -> bool {
+		*self == Self::default()
+	}
+}
+
+pub(crate) fn diff_summary<A: Json, B: Json>(old: &A, new: &B, summary: &mut DiffSummary) {
+	match (old.as_value_ref(), new.as_value_ref()) {
+		(ValueRef::Null, ValueRef::Null) => (),
+		(ValueRef::Boolean(a), ValueRef::Boolean(b)) if a == b => (),
+		(ValueRef::Number(a), ValueRef::Number(b)) if number::numeric_eq(a, b) => (),
+		(ValueRef::String(a), ValueRef::String(b)) if **a == **b => (),
+		(ValueRef::Array(a), ValueRef::Array(b)) => {
+			for (a, b) in Iter::iter(a).zip(Iter::iter(b)) {
+				diff_summary(&*a, &*b, summary)
+			}
+
+			if a.len() < b.len() {
+				summary.added_elements += b.len() - a.len()
+			} else {
+				summary.removed_elements += a.len() - b.len()
+			}
+		}
+		(ValueRef::Object(a), ValueRef::Object(b)) => {
+			for (key, a) in MapIter::iter(a) {
+				match b.get(&*key) {
+					Some(b) => diff_summary(&*a, &*b, summary),
+					None => summary.removed_keys += 1,
+				}
+			}
+
+			for (key, _) in MapIter::iter(b) {
+				if a.get(&*key).is_none() {
+					summary.added_keys += 1
+				}
+			}
+		}
+		_ => summary.changed_values += 1,
+	}
+}
@@ -0,0 +1,155 @@
+use crate::{JsonBuild, JsonMut, ValueMut};
+use cc_traits::{CollectionMut, GetMut, MapInsert};
+
+/// View into a single member of an object, which may be vacant or occupied.
+///
+/// Returned by [`Json::entry`](crate::Json::entry) and [`ValueMut::entry`].
+///
+/// The key is looked up once when the entry is created,
+/// and an occupied entry holds a reference to its value.
+/// Inserting into a vacant entry looks the key up a second time
+/// since [`MapInsert`] does not return a reference to the inserted value.
+pub enum Entry<'a, T: JsonMut + JsonBuild> {
+	/// Occupied entry.
+	Occupied(OccupiedEntry<'a, T>),
+
+	/// Vacant entry.
+	Vacant(VacantEntry<'a, T>),
+}
+
+/// Member of an object.
+pub struct OccupiedEntry<'a, T: JsonMut + JsonBuild> {
+	key: String,
+	value: &'a mut T,
+}
+
+/// Missing member of an object.
+pub struct VacantEntry<'a, T: JsonMut + JsonBuild> {
+	object: &'a mut T::Object,
+	key: String,
+	metadata: T::MetaData,
+}
+
+impl<'a, T: JsonMut + JsonBuild> Entry<'a, T> {
+	/// Creates the entry of `key` in the given object.
+	///
+	/// If the entry is vacant, `metadata` is given to the key inserted in the object.
+	pub(crate) fn new(object: &'a mut T::Object, key: &str, metadata: T::MetaData) -> Self
+	where
+		T::Object: CollectionMut<ItemMut<'a> = &'a mut T>,
+	{
+		let key = key.to_string();
+
+		// The borrow checker cannot yet see that `object` is not borrowed
+		// anymore when `get_mut` returns `None`, so the reborrow goes
+		// through a raw pointer.
+		let ptr: *mut T::Object = object;
+		// SAFETY: `ptr` comes from `object`, which is not used again
+		// if the returned reference is kept.
+		if let Some(value) = unsafe { &mut *ptr }.get_mut(key.as_str()) {
+			return Self::Occupied(OccupiedEntry { key, value });
+		}
+
+		Self::Vacant(VacantEntry {
+			object,
+			key,
+			metadata,
+		})
+	}
+
+	/// Returns the key of this entry.
+	pub fn key(&self) -> &str {
+		match self {
+			Self::Occupied(entry) => entry.key(),
+			Self::Vacant(entry) => entry.key(),
+		}
+	}
+
+	/// Calls `f` on the value of this entry if it is occupied,
+	/// and returns the entry.
+	pub fn and_modify(mut self, f: impl FnOnce(&mut T)) -> Self {
+		if let Self::Occupied(entry) = &mut self {
+			f(entry.get_mut())
+		}
+
+		self
+	}
+
+	/// Inserts `default` if this entry is vacant,
+	/// and returns a mutable reference to the value of this entry.
+	pub fn or_insert(self, default: T) -> &'a mut T
+	where
+		T::Object: CollectionMut<ItemMut<'a> = &'a mut T>,
+	{
+		self.or_insert_with(|| default)
+	}
+
+	/// Inserts the result of `default` if this entry is vacant,
+	/// and returns a mutable reference to the value of this entry.
+	pub fn or_insert_with(self, default: impl FnOnce() -> T) -> &'a mut T
+	where
+		T::Object: CollectionMut<ItemMut<'a> = &'a mut T>,
+	{
+		match self {
+			Self::Occupied(entry) => entry.into_mut(),
+			Self::Vacant(entry) => entry.insert(default()),
+		}
+	}
+}
+
+impl<'a, T: JsonMut + JsonBuild> OccupiedEntry<'a, T> {
+	/// Returns the key of this entry.
+	pub fn key(&self) -> &str {
+		&self.key
+	}
+
+	/// Returns a reference to the value of this entry.
+	pub fn get(&self) -> &T {
+		self.value
+	}
+
+	/// Returns a mutable reference to the value of this entry.
+	pub fn get_mut(&mut self) -> &mut T {
+		self.value
+	}
+
+	/// Converts this entry into a mutable reference to its value.
+	pub fn into_mut(self) -> &'a mut T {
+		self.value
+	}
+}
+
+impl<'a, T: JsonMut + JsonBuild> VacantEntry<'a, T> {
+	/// Returns the key of this entry.
+	pub fn key(&self) -> &str {
+		&self.key
+	}
+
+	/// Inserts `value` in the object with the key of this entry,
+	/// and returns a mutable reference to it.
+	pub fn insert(self, value: T) -> &'a mut T
+	where
+		T::Object: CollectionMut<ItemMut<'a> = &'a mut T>,
+	{
+		self.object
+			.insert(T::new_key(&self.key, self.metadata), value);
+		self.object.get_mut(self.key.as_str()).unwrap()
+	}
+}
+
+impl<'a, T: 'a + JsonMut + JsonBuild> ValueMut<'a, T>
+where
+	T::MetaData: Default,
+	T::Object: CollectionMut<ItemMut<'a> = &'a mut T>,
+{
+	/// If the value is an object, returns the entry of the given key in it.
+	/// Returns `None` otherwise.
+	///
+	/// If the entry is vacant, the inserted key gets the default metadata.
+	pub fn entry(self, key: &str) -> Option<Entry<'a, T>> {
+		match self {
+			Self::Object(o) => Some(Entry::new(o, key, T::MetaData::default())),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,42 @@
+/// Returns the index of the first byte of `bytes` that must be escaped
+/// in a JSON string, or the length of `bytes` if there is none.
+///
+/// The bytes that must be escaped are `"`, `\` and the control characters.
+/// When the `simd` feature is enabled, the input is scanned in vectorized chunks,
+/// with the same result.
+#[cfg(not(feature = "simd"))]
+pub(crate) fn next_escape(bytes: &[u8]) -> usize {
+	next_escape_scalar(bytes)
+}
+
+#[cfg(feature = "simd")]
+pub(crate) fn next_escape(bytes: &[u8]) -> usize {
+	use std::simd::{
+		cmp::{SimdPartialEq, SimdPartialOrd},
+		u8x16,
+	};
+
+	const LANES: usize = 16;
+	let quote = u8x16::splat(b'"');
+	let backslash = u8x16::splat(b'\\');
+	let space = u8x16::splat(b' ');
+
+	let mut chunks = bytes.chunks_exact(LANES);
+	for (i, chunk) in (&mut chunks).enumerate() {
+		let chunk = u8x16::from_slice(chunk);
+		let mask = chunk.simd_eq(quote) | chunk.simd_eq(backslash) | chunk.simd_lt(space);
+		if mask.any() {
+			return i * LANES + mask.to_bitmask().trailing_zeros() as usize;
+		}
+	}
+
+	let remainder = chunks.remainder();
+	bytes.len() - remainder.len() + next_escape_scalar(remainder)
+}
+
+pub(crate) fn next_escape_scalar(bytes: &[u8]) -> usize {
+	bytes
+		.iter()
+		.position(|&b| b == b'"' || b == b'\\' || b < b' ')
+		.unwrap_or(bytes.len())
+}
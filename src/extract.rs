@@ -0,0 +1,111 @@
+use crate::{Json, Number, Value};
+use std::{convert::TryFrom, fmt};
+
+/// Error returned when a [`Value`] cannot be converted into a Rust value,
+/// either because it has not the expected type,
+/// or because it does not fit in the target type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TryFromValueError {
+	expected: &'static str,
+}
+
+impl TryFromValueError {
+	/// Name of the expected Rust type.
+	pub fn expected(&self) -> &'static str {
+		self.expected
+	}
+}
+
+impl fmt::Display for TryFromValueError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "value is not a valid `{}`", self.expected)
+	}
+}
+
+impl std::error::Error for TryFromValueError {}
+
+macro_rules! number_try_from {
+	($($ty:ident: $as:ident),*) => {
+		$(
+			impl<T: Json> TryFrom<Value<T>> for $ty {
+				type Error = TryFromValueError;
+
+				fn try_from(value: Value<T>) -> Result<Self, Self::Error> {
+					value.as_number().and_then(Number::$as).ok_or(TryFromValueError {
+						expected: stringify!($ty),
+					})
+				}
+			}
+		)*
+	};
+}
+
+number_try_from!(u32: as_u32, u64: as_u64, i32: as_i32, i64: as_i64, f32: as_f32, f64: as_f64);
+
+impl<T: Json> TryFrom<Value<T>> for bool {
+	type Error = TryFromValueError;
+
+	fn try_from(value: Value<T>) -> Result<Self, Self::Error> {
+		value
+			.as_bool()
+			.ok_or(TryFromValueError { expected: "bool" })
+	}
+}
+
+impl<T: Json> TryFrom<Value<T>> for String {
+	type Error = TryFromValueError;
+
+	fn try_from(value: Value<T>) -> Result<Self, Self::Error> {
+		value
+			.as_str()
+			.map(ToString::to_string)
+			.ok_or(TryFromValueError { expected: "String" })
+	}
+}
+
+/// Error returned when a [`Value`] cannot be converted into a [`Vec`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TryFromArrayError<E> {
+	/// The value is not an array.
+	NotAnArray,
+
+	/// The element at the given index could not be converted.
+	Element(usize, E),
+}
+
+/// The error of an invalid element is not included in the message:
+/// it is available as the [`source`](std::error::Error::source) of the error.
+impl<E> fmt::Display for TryFromArrayError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::NotAnArray => write!(f, "value is not an array"),
+			Self::Element(i, _) => write!(f, "invalid array element at index {}", i),
+		}
+	}
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for TryFromArrayError<E> {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::NotAnArray => None,
+			Self::Element(_, e) => Some(e),
+		}
+	}
+}
+
+impl<T: Json, V: TryFrom<Value<T>>> TryFrom<Value<T>> for Vec<V> {
+	type Error = TryFromArrayError<V::Error>;
+
+	fn try_from(value: Value<T>) -> Result<Self, Self::Error> {
+		match value {
+			Value::Array(a) => a
+				.into_iter()
+				.enumerate()
+				.map(|(i, item)| {
+					V::try_from(item.into_value()).map_err(|e| TryFromArrayError::Element(i, e))
+				})
+				.collect(),
+			_ => Err(TryFromArrayError::NotAnArray),
+		}
+	}
+}
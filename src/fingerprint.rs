@@ -0,0 +1,146 @@
+use crate::{compare, number, CompareOptions, Json, ValueRef};
+use cc_traits::{Iter, Len, MapIter};
+use std::{
+	collections::hash_map::DefaultHasher,
+	hash::{Hash, Hasher},
+};
+
+/// 128-bit fingerprint of a JSON value.
+///
+/// Values equal according to [`Json::semantically_eq`] with
+/// [`numeric_coercion`](CompareOptions::numeric_coercion)
+/// (numbers compared by value, objects regardless of member order)
+/// have the same fingerprint.
+/// Different values have different fingerprints with very high probability,
+/// but the fingerprint is not cryptographically secure.
+///
+/// Returned by [`Json::fingerprint`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Fingerprint(u128);
+
+impl Fingerprint {
+	/// Returns the fingerprint as an integer.
+	pub fn as_u128(&self) -> u128 {
+		self.0
+	}
+}
+
+/// Pair of independent 64-bit hashers.
+struct Hasher128(DefaultHasher, DefaultHasher);
+
+impl Hasher128 {
+	fn new() -> Self {
+		let mut low = DefaultHasher::new();
+		let mut high = DefaultHasher::new();
+		low.write_u8(0);
+		high.write_u8(1);
+		Self(low, high)
+	}
+
+	fn finish(&self) -> u128 {
+		(self.1.finish() as u128) << 64 | self.0.finish() as u128
+	}
+}
+
+impl Hasher for Hasher128 {
+	fn write(&mut self, bytes: &[u8]) {
+		self.0.write(bytes);
+		self.1.write(bytes)
+	}
+
+	fn finish(&self) -> u64 {
+		self.0.finish()
+	}
+}
+
+pub(crate) fn fingerprint<T: Json>(value: &T) -> Fingerprint {
+	let mut h = Hasher128::new();
+	match value.as_value_ref() {
+		ValueRef::Null => 0u8.hash(&mut h),
+		ValueRef::Boolean(b) => {
+			1u8.hash(&mut h);
+			b.hash(&mut h)
+		}
+		ValueRef::Number(n) => {
+			2u8.hash(&mut h);
+			match number::to_decimal_string(n).as_str() {
+				// `-0` is equal to `0`.
+				"-0" => "0".hash(&mut h),
+				s => s.hash(&mut h),
+			}
+		}
+		ValueRef::String(s) => {
+			3u8.hash(&mut h);
+			str::hash(s, &mut h)
+		}
+		ValueRef::Array(a) => {
+			4u8.hash(&mut h);
+			a.len().hash(&mut h);
+			for item in Iter::iter(a) {
+				fingerprint(&*item).hash(&mut h)
+			}
+		}
+		ValueRef::Object(o) => {
+			5u8.hash(&mut h);
+			o.len().hash(&mut h);
+			let mut members: Vec<_> = MapIter::iter(o)
+				.map(|(key, item)| (key.to_string(), fingerprint(&*item)))
+				.collect();
+			members.sort();
+			members.hash(&mut h)
+		}
+	}
+
+	Fingerprint(h.finish())
+}
+
+/// Value reference along with its precomputed fingerprint.
+///
+/// Equality first compares the fingerprints,
+/// and falls back to a full comparison only when they match,
+/// making repeated comparisons of unequal values cheap.
+/// Hashing only uses the fingerprint.
+pub struct Fingerprinted<'a, T> {
+	value: &'a T,
+	fingerprint: Fingerprint,
+}
+
+impl<'a, T: Json> Fingerprinted<'a, T> {
+	/// Computes the fingerprint of the given value.
+	pub fn new(value: &'a T) -> Self {
+		Self {
+			value,
+			fingerprint: fingerprint(value),
+		}
+	}
+
+	/// Returns the value.
+	pub fn value(&self) -> &'a T {
+		self.value
+	}
+
+	/// Returns the fingerprint of the value.
+	pub fn fingerprint(&self) -> Fingerprint {
+		self.fingerprint
+	}
+}
+
+impl<'a, T: Json> PartialEq for Fingerprinted<'a, T> {
+	fn eq(&self, other: &Self) -> bool {
+		let options = CompareOptions {
+			numeric_coercion: true,
+			..Default::default()
+		};
+
+		self.fingerprint == other.fingerprint
+			&& compare::semantically_eq(self.value, other.value, &options)
+	}
+}
+
+impl<'a, T: Json> Eq for Fingerprinted<'a, T> {}
+
+impl<'a, T: Json> Hash for Fingerprinted<'a, T> {
+	fn hash<H: Hasher>(&self, h: &mut H) {
+		self.fingerprint.hash(h)
+	}
+}
@@ -0,0 +1,71 @@
+use crate::{Json, ValueRef};
+use cc_traits::{Iter, MapIter};
+
+#[cfg(feature = "nightly")]
+use crate::JsonIntoRef;
+#[cfg(feature = "nightly")]
+use cc_traits::KeyedRef;
+
+/// Folds the scalar leaves of the given value in document order,
+/// stopping at the first error.
+pub(crate) fn try_fold_leaves<T: Json, B, E, F: FnMut(B, ValueRef<T>) -> Result<B, E>>(
+	value: &T,
+	init: B,
+	f: &mut F,
+) -> Result<B, E> {
+	match value.as_value_ref() {
+		ValueRef::Array(a) => {
+			Iter::iter(a).try_fold(init, |acc, item| try_fold_leaves(&*item, acc, f))
+		}
+		ValueRef::Object(o) => {
+			MapIter::iter(o).try_fold(init, |acc, (_, item)| try_fold_leaves(&*item, acc, f))
+		}
+		leaf => f(init, leaf),
+	}
+}
+
+/// Appends the string leaves of the given value to `out`, in document order.
+#[cfg(feature = "nightly")]
+pub(crate) fn string_leaves<'a, T: JsonIntoRef>(value: ValueRef<'a, T>, out: &mut Vec<&'a str>) {
+	match value {
+		ValueRef::String(s) => out.push(s),
+		ValueRef::Array(a) => {
+			for item in Iter::iter(a) {
+				string_leaves(item.into(), out)
+			}
+		}
+		ValueRef::Object(o) => {
+			for (_, item) in MapIter::iter(o) {
+				string_leaves(item.into(), out)
+			}
+		}
+		_ => (),
+	}
+}
+
+/// Appends the string leaves and object keys of the given value to `out`,
+/// in document order, each key preceding its value.
+#[cfg(feature = "nightly")]
+pub(crate) fn string_leaves_with_keys<'a, T: JsonIntoRef>(
+	value: ValueRef<'a, T>,
+	out: &mut Vec<&'a str>,
+) where
+	T::Key: 'a,
+	T::Object: KeyedRef<KeyRef<'a> = &'a T::Key>,
+{
+	match value {
+		ValueRef::String(s) => out.push(s),
+		ValueRef::Array(a) => {
+			for item in Iter::iter(a) {
+				string_leaves_with_keys(item.into(), out)
+			}
+		}
+		ValueRef::Object(o) => {
+			for (key, item) in MapIter::iter(o) {
+				out.push(key);
+				string_leaves_with_keys(item.into(), out)
+			}
+		}
+		_ => (),
+	}
+}
@@ -0,0 +1,114 @@
+use crate::{number, Json, JsonNew, Key, NumberBuf, SharedValue, Value};
+use std::{fmt, ops::Deref};
+
+/// Immutable JSON value whose subtrees are shared.
+///
+/// Returned by [`Json::freeze`].
+/// This is a read-only view over a [`SharedValue`]:
+/// cloning a frozen value is O(1) and the clones can be sent to other threads.
+///
+/// A frozen value does not implement [`Json`] itself,
+/// since the trait also provides mutable access to the value.
+/// Instead, it dereferences (using [`Deref`]) to the underlying [`SharedValue`],
+/// only reachable through a shared reference:
+/// the methods of [`Json`] taking `&self` can be called directly on a frozen value,
+/// and generic functions expecting a `&T` where `T: Json`
+/// can be given `&*frozen`.
+///
+/// ```
+/// # #[cfg(feature = "serde_json-impl")]
+/// # {
+/// use cc_traits::Get;
+/// use generic_json::{pointer, Json};
+/// use serde_json::json;
+/// use std::thread;
+///
+/// let frozen = json!({ "name": "generic-json", "tags": ["json", "generic"] }).freeze().unwrap();
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let frozen = frozen.clone();
+///         thread::spawn(move || {
+///             let tags = frozen.as_object().unwrap().get("tags").unwrap();
+///             tags.as_array().unwrap().get(i % 2).unwrap().as_str().unwrap().len()
+///         })
+///     })
+///     .collect();
+///
+/// let lengths: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+/// assert_eq!(lengths, [4, 7, 4, 7]);
+///
+/// let name = frozen.as_object().unwrap().get("name").unwrap();
+/// assert_eq!(name.as_str(), Some("generic-json"));
+///
+/// // Generic functions take the underlying value.
+/// let tag = pointer(&*frozen, "/tags/1").unwrap();
+/// assert_eq!(tag.as_str(), Some("generic"));
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct FrozenValue<M = ()>(SharedValue<M>);
+
+impl<M: Clone + Send + Sync> FrozenValue<M> {
+	/// Freezes the given value, keeping its metadata.
+	///
+	/// Numbers keep their lexical representation when available
+	/// (see [`Number::as_str`](crate::Number::as_str)).
+	/// Returns `None` if the value contains a number
+	/// that has no valid JSON representation.
+	pub(crate) fn new<T: Json<MetaData = M>>(value: T) -> Option<Self> {
+		share(value).map(Self)
+	}
+
+	/// Turns this frozen value back into a [`SharedValue`].
+	///
+	/// Subtrees stay shared with the other clones of this value
+	/// until they are mutated.
+	pub fn into_shared(self) -> SharedValue<M> {
+		self.0
+	}
+}
+
+/// Converts the given value into a [`SharedValue`], keeping its metadata.
+///
+/// Returns `None` if the value contains a number
+/// that has no valid JSON representation.
+fn share<T: Json>(value: T) -> Option<SharedValue<T::MetaData>>
+where
+	T::MetaData: Clone + Send + Sync,
+{
+	let (value, metadata) = value.into_parts();
+	let value = match value {
+		Value::Null => Value::Null,
+		Value::Boolean(b) => Value::Boolean(b),
+		Value::Number(n) => {
+			Value::Number(number::to_json_string(&n).and_then(|s| NumberBuf::new(s).ok())?)
+		}
+		Value::String(s) => Value::String((*s).into()),
+		Value::Array(a) => Value::Array(a.into_iter().map(share).collect::<Option<_>>()?),
+		Value::Object(o) => Value::Object(
+			o.into_iter()
+				.map(|(key, item)| {
+					let key = SharedValue::new_key(&key, key.metadata().clone());
+					Some((key, share(item)?))
+				})
+				.collect::<Option<_>>()?,
+		),
+	};
+
+	Some(SharedValue::new(value, metadata))
+}
+
+impl<M> Deref for FrozenValue<M> {
+	type Target = SharedValue<M>;
+
+	fn deref(&self) -> &SharedValue<M> {
+		&self.0
+	}
+}
+
+impl<M> fmt::Debug for FrozenValue<M> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
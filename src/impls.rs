@@ -3,3 +3,6 @@ mod ijson;
 
 #[cfg(feature = "serde_json-impl")]
 mod serde_json;
+
+#[cfg(feature = "serde_json-impl")]
+pub use self::serde_json::{valueref_to_serde, AsSerdeJson, UnrepresentableNumber};
@@ -1,7 +1,8 @@
-use crate::{Json, JsonNew, Key, Number, Value, ValueMut, ValueRef};
+use crate::{number, Json, JsonNew, Key, Number, NumberFrom, Value, ValueMut, ValueRef};
 use ijson::{
 	Destructured, DestructuredMut, DestructuredRef, IArray, INumber, IObject, IString, IValue,
 };
+use std::convert::TryFrom;
 
 impl Key<()> for IString {
 	fn metadata(&self) -> &() {
@@ -32,7 +33,7 @@ impl Number for INumber {
 
 	#[inline(always)]
 	fn as_f32(&self) -> Option<f32> {
-		self.to_f32()
+		self.to_f64().and_then(number::f64_as_f32)
 	}
 
 	#[inline(always)]
@@ -49,6 +50,38 @@ impl Number for INumber {
 	fn as_f64_lossy(&self) -> f64 {
 		self.to_f64_lossy()
 	}
+
+	#[inline(always)]
+	fn is_integer(&self) -> bool {
+		!self.has_decimal_point()
+	}
+
+	#[inline(always)]
+	fn is_negative(&self) -> bool {
+		self.to_f64_lossy() < 0.0
+	}
+
+	#[inline(always)]
+	fn is_finite(&self) -> bool {
+		true
+	}
+}
+
+impl NumberFrom for INumber {
+	#[inline(always)]
+	fn from_u64(u: u64) -> Self {
+		u.into()
+	}
+
+	#[inline(always)]
+	fn from_i64(i: i64) -> Self {
+		i.into()
+	}
+
+	#[inline(always)]
+	fn from_f64(f: f64) -> Option<Self> {
+		INumber::try_from(f).ok()
+	}
 }
 
 impl Json for IValue {
@@ -151,3 +184,6 @@ impl<'a> From<&'a mut IValue> for ValueMut<'a, IValue> {
 		}
 	}
 }
+
+#[cfg(feature = "serde_json-impl")]
+impl crate::AsSerdeJson for IValue {}
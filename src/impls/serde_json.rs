@@ -1,4 +1,23 @@
-use crate::{Json, JsonNew, Number, Value, ValueMut, ValueRef};
+use crate::{number, Json, JsonNew, Number, NumberFrom, Value, ValueMut, ValueRef};
+use std::fmt;
+
+/// Cheap view of a JSON value as a `serde_json::Value`.
+///
+/// Allows generic code to take a fast path when the JSON backend
+/// is `serde_json::Value`, for instance to call a library expecting a `&serde_json::Value`.
+pub trait AsSerdeJson: Json {
+	/// Returns this value as a `serde_json::Value` if it is one,
+	/// or `None` for any other backend.
+	fn as_serde_json(&self) -> Option<&serde_json::Value> {
+		None
+	}
+}
+
+impl AsSerdeJson for serde_json::Value {
+	fn as_serde_json(&self) -> Option<&serde_json::Value> {
+		Some(self)
+	}
+}
 
 impl Number for serde_json::Number {
 	#[inline(always)]
@@ -23,7 +42,7 @@ impl Number for serde_json::Number {
 
 	#[inline(always)]
 	fn as_f32(&self) -> Option<f32> {
-		self.as_f64().map(|f| f as f32)
+		self.as_f64().and_then(number::f64_as_f32)
 	}
 
 	#[inline(always)]
@@ -52,6 +71,53 @@ impl Number for serde_json::Number {
 			},
 		}
 	}
+
+	#[cfg(feature = "arbitrary_precision")]
+	#[inline(always)]
+	fn as_str(&self) -> Option<&str> {
+		Some(serde_json::Number::as_str(self))
+	}
+
+	#[inline(always)]
+	fn is_integer(&self) -> bool {
+		self.is_i64() || self.is_u64()
+	}
+
+	#[inline(always)]
+	fn is_negative(&self) -> bool {
+		match self.as_i64() {
+			Some(i) => i < 0,
+			None => self.as_f64_lossy() < 0.0,
+		}
+	}
+
+	#[inline(always)]
+	fn is_finite(&self) -> bool {
+		true
+	}
+}
+
+impl NumberFrom for serde_json::Number {
+	#[inline(always)]
+	fn from_u64(u: u64) -> Self {
+		u.into()
+	}
+
+	#[inline(always)]
+	fn from_i64(i: i64) -> Self {
+		i.into()
+	}
+
+	#[inline(always)]
+	fn from_f64(f: f64) -> Option<Self> {
+		serde_json::Number::from_f64(f)
+	}
+
+	/// Parses the number, exactly with the `arbitrary_precision` feature.
+	#[inline(always)]
+	fn from_lexical(s: &str) -> Option<Self> {
+		s.parse().ok()
+	}
 }
 
 impl Json for serde_json::Value {
@@ -153,3 +219,86 @@ impl<'a> From<&'a mut serde_json::Value> for ValueMut<'a, serde_json::Value> {
 		}
 	}
 }
+
+/// Error returned by [`valueref_to_serde`] when a number
+/// cannot be represented exactly by a `serde_json::Number`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct UnrepresentableNumber {
+	lexical: String,
+}
+
+impl UnrepresentableNumber {
+	/// Returns the lexical representation of the number.
+	pub fn lexical(&self) -> &str {
+		&self.lexical
+	}
+}
+
+impl fmt::Display for UnrepresentableNumber {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"`{}` cannot be represented exactly by a `serde_json` number",
+			self.lexical
+		)
+	}
+}
+
+impl std::error::Error for UnrepresentableNumber {}
+
+/// Converts the given number into a `serde_json::Number`, without losing precision.
+fn number_to_serde<N: Number>(n: &N) -> Result<serde_json::Number, UnrepresentableNumber> {
+	if let Some(i) = n.as_i64() {
+		return Ok(i.into());
+	}
+
+	if let Some(u) = n.as_u64() {
+		return Ok(u.into());
+	}
+
+	#[cfg(feature = "arbitrary_precision")]
+	if let Some(n) = n.as_str().and_then(|s| s.parse().ok()) {
+		return Ok(n);
+	}
+
+	n.as_f64_checked()
+		.ok()
+		.and_then(serde_json::Number::from_f64)
+		.ok_or_else(|| UnrepresentableNumber {
+			lexical: match n.as_str() {
+				Some(s) => s.to_string(),
+				None => number::to_decimal_string(n),
+			},
+		})
+}
+
+/// Converts the given borrowed JSON value, from any backend,
+/// into a `serde_json::Value`, without going through [`Value`].
+///
+/// Numbers are converted exactly.
+/// Integers representable as an `i64` or `u64` are always supported.
+/// With the `arbitrary_precision` feature, numbers exposing their lexical
+/// representation (see [`Number::as_str`]) are converted from it.
+/// Otherwise the number must be a finite `f64` without loss of precision
+/// (see [`Number::as_f64_checked`]).
+/// An error is returned if a number cannot be converted.
+pub fn valueref_to_serde<T: Json>(
+	value: ValueRef<'_, T>,
+) -> Result<serde_json::Value, UnrepresentableNumber> {
+	Ok(match value {
+		ValueRef::Null => serde_json::Value::Null,
+		ValueRef::Boolean(b) => serde_json::Value::Bool(b),
+		ValueRef::Number(n) => serde_json::Value::Number(number_to_serde(n)?),
+		ValueRef::String(s) => serde_json::Value::String(s.to_string()),
+		ValueRef::Array(a) => serde_json::Value::Array(
+			cc_traits::Iter::iter(a)
+				.map(|item| valueref_to_serde(item.as_value_ref()))
+				.collect::<Result<_, _>>()?,
+		),
+		ValueRef::Object(o) => serde_json::Value::Object(
+			cc_traits::MapIter::iter(o)
+				.map(|(key, item)| Ok((key.to_string(), valueref_to_serde(item.as_value_ref())?)))
+				.collect::<Result<_, _>>()?,
+		),
+	})
+}
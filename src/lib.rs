@@ -77,21 +77,127 @@
 //! For instance the `JsonClone` trait alias ensures that every component
 //! of the JSON value implements `Clone`.
 #![cfg_attr(feature = "nightly", feature(trait_alias))]
-#![feature(generic_associated_types)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 use cc_traits::{Get, GetKeyValue, Iter, Keyed, Len, MapIter};
-use std::{hash::Hash, ops::Deref};
+use std::{
+	hash::{Hash, Hasher},
+	ops::Deref,
+};
 
+#[cfg(feature = "nightly")]
+use cc_traits::{GetMut, IterMut, MapInsert, MapIterMut};
+#[cfg(feature = "nightly")]
+use std::collections::BTreeMap;
+
+mod borrowed;
+mod canonical;
+mod compare;
+mod csv;
+mod dedup;
+#[cfg(all(feature = "serde", feature = "nightly"))]
+mod deserialize;
+mod diff;
+#[cfg(feature = "nightly")]
+mod entry;
+mod escape;
+mod extract;
+mod fingerprint;
+mod fold;
+mod frozen;
 mod impls;
+#[cfg(feature = "nightly")]
+mod macros;
+mod meta;
+#[cfg(feature = "nightly")]
+mod normalize;
 pub mod number;
+mod pairs;
+mod parse;
+#[cfg(feature = "nightly")]
+mod patch;
+mod pointer;
+mod pretty;
+mod query;
 mod reference;
+mod refs;
+#[cfg(feature = "nightly")]
+mod rename;
+mod sanitize;
+mod schema;
+mod selection;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shape;
+mod shared;
+mod stream;
+#[cfg(feature = "nightly")]
+mod strip;
+mod struct_json;
+mod trace;
+#[cfg(feature = "nightly")]
+mod tree;
+mod trivia;
+#[cfg(feature = "nightly")]
+mod unwrap;
+mod validate;
 mod value;
+mod visit;
+mod write;
 
 #[cfg(feature = "nightly")]
 mod aliases;
 
-pub use number::Number;
+pub use borrowed::*;
+pub use canonical::{canonicalize, NumberOutOfRange};
+pub use compare::{json_eq, CompareOptions};
+pub use csv::CsvError;
+pub use dedup::*;
+#[cfg(all(feature = "serde", feature = "nightly"))]
+pub use deserialize::deserialize_into;
+pub use diff::DiffSummary;
+#[cfg(feature = "nightly")]
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use extract::{TryFromArrayError, TryFromValueError};
+pub use fingerprint::{Fingerprint, Fingerprinted};
+pub use frozen::FrozenValue;
+#[cfg(feature = "serde_json-impl")]
+pub use impls::{valueref_to_serde, AsSerdeJson, UnrepresentableNumber};
+#[cfg(feature = "nightly")]
+pub use macros::ToJsonValue;
+pub use meta::*;
+pub use number::{number_from_str, Number, NumberBuf, NumberFrom, PrecisionLost};
+pub use pairs::PairsError;
+#[cfg(feature = "nightly")]
+pub use parse::parse_prefix;
+pub use parse::{peek_kind, ParseError, ParseErrorKind, ParseOptions};
+#[cfg(feature = "nightly")]
+pub use patch::{
+	apply_patch, merge_array_by_key, merge_patch, merge_patch_with, KeyOrder, MergeOptions,
+	PatchError, PatchErrorKind, PatchOp,
+};
+pub use pointer::{pointer, PointerError};
+#[cfg(feature = "nightly")]
+pub use pointer::{pointer_mut, with_pointer};
+pub use pretty::PrettyDebug;
+pub use query::*;
 pub use reference::*;
+pub use refs::RefError;
+#[cfg(feature = "nightly")]
+pub use rename::CaseStyle;
+pub use sanitize::*;
+pub use schema::*;
+pub use selection::*;
+pub use shared::*;
+#[cfg(feature = "nightly")]
+pub use strip::{convert, strip_metadata, to_backend};
+pub use struct_json::*;
+#[cfg(feature = "nightly")]
+pub use tree::{JsonPath, PathComponent, TreeIter};
+pub use trivia::*;
+pub use validate::SchemaError;
 pub use value::*;
+pub use visit::Visit;
+pub use write::{display, display_pretty, JsonDisplay, PrettyConfig};
 
 #[cfg(feature = "nightly")]
 pub use aliases::*;
@@ -166,6 +272,19 @@ pub trait Json: Sized + Eq {
 	/// Returns a pair containing a mutable reference to the JSON value and a reference to its metadata.
 	fn as_pair_mut(&mut self) -> (ValueMut<'_, Self>, &Self::MetaData);
 
+	/// Returns a view of this value whose `Debug` implementation
+	/// renders it over multiple indented lines.
+	///
+	/// See [`PrettyDebug`].
+	fn pretty_debug(&self) -> PrettyDebug<'_, Self> {
+		PrettyDebug(self)
+	}
+
+	/// Returns the kind of the value.
+	fn kind(&self) -> ValueKind {
+		self.as_value_ref().kind()
+	}
+
 	/// Returns `true` if the value is a `Null`. Returns `false` otherwise.
 	fn is_null(&self) -> bool {
 		self.as_value_ref().is_null()
@@ -184,11 +303,97 @@ pub trait Json: Sized + Eq {
 	#[inline]
 	fn is_empty_object(&self) -> bool {
 		match self.as_value_ref() {
-			ValueRef::Array(a) => a.is_empty(),
+			ValueRef::Object(o) => o.is_empty(),
 			_ => false,
 		}
 	}
 
+	/// Returns the kind shared by all the elements of the array.
+	///
+	/// Returns `None` if the value is not an array,
+	/// and `Some(None)` if the array is empty or its elements have different kinds.
+	fn array_element_kind(&self) -> Option<Option<ValueKind>> {
+		self.as_array().map(|a| {
+			let mut kinds = Iter::iter(a).map(|item| item.kind());
+			let first = kinds.next()?;
+			kinds.all(|kind| kind == first).then_some(first)
+		})
+	}
+
+	/// If the value is an array, calls `f` on each of its elements
+	/// and returns the `Some` results, in order.
+	/// Returns `None` otherwise.
+	///
+	/// ```
+	/// # #[cfg(feature = "serde_json-impl")]
+	/// # {
+	/// use generic_json::Json;
+	/// use serde_json::json;
+	///
+	/// let value = json!([1, "two", -3, null, 4.5, [5]]);
+	/// assert_eq!(value.array_filter_map(|item| item.as_i64()), Some(vec![1, -3]));
+	/// assert_eq!(json!({}).array_filter_map(|item| item.as_i64()), None);
+	/// # }
+	/// ```
+	fn array_filter_map<B>(
+		&self,
+		mut f: impl FnMut(ValueRef<'_, Self>) -> Option<B>,
+	) -> Option<Vec<B>> {
+		self.as_array().map(|a| {
+			Iter::iter(a)
+				.filter_map(|item| f(item.as_value_ref()))
+				.collect()
+		})
+	}
+
+	/// If the value is an array, splits it into arrays of `size` elements,
+	/// the last one holding the remaining elements.
+	/// Returns `None` if the value is not an array or if `size` is zero.
+	///
+	/// The elements are copied into the type `T` (see [`to_backend`]),
+	/// and every value of the result is given the default metadata.
+	///
+	/// ```
+	/// # #[cfg(feature = "serde_json-impl")]
+	/// # {
+	/// use generic_json::Json;
+	/// use serde_json::json;
+	///
+	/// let value = json!([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+	/// let chunks: Vec<serde_json::Value> = value.chunk_array(3).unwrap();
+	/// assert_eq!(chunks, [json!([0, 1, 2]), json!([3, 4, 5]), json!([6, 7, 8]), json!([9])]);
+	///
+	/// let chunks: Vec<serde_json::Value> = value.chunk_array(5).unwrap();
+	/// assert_eq!(chunks, [json!([0, 1, 2, 3, 4]), json!([5, 6, 7, 8, 9])]);
+	///
+	/// assert!(value.chunk_array::<serde_json::Value>(0).is_none());
+	/// assert!(json!({}).chunk_array::<serde_json::Value>(3).is_none());
+	/// # }
+	/// ```
+	#[cfg(feature = "nightly")]
+	fn chunk_array<T: JsonBuild>(&self, size: usize) -> Option<Vec<T>>
+	where
+		T::MetaData: Default,
+	{
+		if size == 0 {
+			return None;
+		}
+
+		let a = self.as_array()?;
+		let mut chunks = Vec::with_capacity(a.len().div_ceil(size));
+		let mut items = Iter::iter(a).peekable();
+		while items.peek().is_some() {
+			let chunk = items
+				.by_ref()
+				.take(size)
+				.map(|item| to_backend(&*item))
+				.collect();
+			chunks.push(T::array(chunk, T::MetaData::default()))
+		}
+
+		Some(chunks)
+	}
+
 	/// Checks if the value is an empty array or empty object.
 	#[inline]
 	fn is_empty_array_or_object(&self) -> bool {
@@ -242,6 +447,32 @@ pub trait Json: Sized + Eq {
 		self.as_value_ref().is_object()
 	}
 
+	/// Checks if the value is truthy according to JavaScript rules.
+	///
+	/// `false`, `null`, `""` and numbers equal to zero
+	/// (including `-0` and `0.0`) are falsy, as well as `NaN`
+	/// for backends able to hold it.
+	/// Every other value is truthy, including empty arrays and objects.
+	///
+	/// ```
+	/// # #[cfg(feature = "serde_json-impl")]
+	/// # {
+	/// use generic_json::Json;
+	/// use serde_json::json;
+	///
+	/// for falsy in [json!(false), json!(null), json!(""), json!(0), json!(-0.0), json!(0.0)] {
+	///     assert!(!falsy.is_js_truthy());
+	/// }
+	///
+	/// for truthy in [json!(true), json!("0"), json!(-1), json!(0.5), json!([]), json!({})] {
+	///     assert!(truthy.is_js_truthy());
+	/// }
+	/// # }
+	/// ```
+	fn is_js_truthy(&self) -> bool {
+		self.as_value_ref().is_js_truthy()
+	}
+
 	/// If the value is a boolean, returns the associated `bool`.
 	/// Returns `None` otherwise.
 	fn as_bool(&self) -> Option<bool> {
@@ -294,12 +525,28 @@ pub trait Json: Sized + Eq {
 		self.as_value_ref().as_f64_lossy()
 	}
 
+	/// Returns this number as an `f64` if it is a number,
+	/// or an error if the conversion loses precision.
+	///
+	/// See [`Number::as_f64_checked`].
+	fn as_f64_checked(&self) -> Option<Result<f64, PrecisionLost>> {
+		self.as_value_ref().as_f64_checked()
+	}
+
 	/// If the value is a string, returns its associated [`str`].
 	/// Returns `None` otherwise.
 	fn as_str(&self) -> Option<&str> {
 		self.as_value_ref().into_str()
 	}
 
+	/// If the value is a string, returns its length in UTF-16 code units.
+	/// Returns `None` otherwise.
+	///
+	/// This matches the JavaScript `String.length` of the value.
+	fn utf16_len(&self) -> Option<usize> {
+		self.as_value_ref().utf16_len()
+	}
+
 	/// If the value is an array, returns a reference to it.
 	/// Returns `None` otherwise.
 	fn as_array(&self) -> Option<&Self::Array> {
@@ -323,6 +570,954 @@ pub trait Json: Sized + Eq {
 	fn as_object_mut(&mut self) -> Option<&mut Self::Object> {
 		self.as_value_mut().into_object_mut()
 	}
+
+	/// If the value is an array of numbers, returns the elements as `f64`,
+	/// potentially losing precision in the process.
+	/// Returns `None` otherwise, or if an element is not a number.
+	fn to_f64_vec(&self) -> Option<Vec<f64>> {
+		let a = self.as_array()?;
+		let mut result = Vec::with_capacity(a.len());
+		for item in Iter::iter(a) {
+			result.push(item.as_f64_lossy()?)
+		}
+
+		Some(result)
+	}
+
+	/// Formats this array of flat objects as CSV, one row per element.
+	///
+	/// The first row holds the headers: the given ones, in order,
+	/// or the union of the object keys in order of appearance.
+	/// Each following row holds the members of an element matching the headers.
+	/// Missing members and `null` give empty fields,
+	/// and numbers are formatted through their `i64`, `u64` or `f64` representation.
+	/// Fields are quoted when needed, and rows end with `\n`.
+	///
+	/// Returns an error if the value is not an array of objects,
+	/// or if an element has an array or object member matching a header.
+	fn to_csv(&self, headers: Option<&[&str]>) -> Result<String, CsvError> {
+		csv::to_csv(self, headers)
+	}
+
+	/// Writes this value as a compact JSON document to the given writer.
+	///
+	/// Numbers are written using their lexical representation when the backend
+	/// stores it (see [`Number::as_str`]).
+	/// Otherwise integers representable as an `i64` or `u64` are written exactly,
+	/// and other numbers using the shortest decimal representation
+	/// of their nearest `f64`.
+	/// An error of kind [`InvalidData`](std::io::ErrorKind::InvalidData)
+	/// is returned if the value contains a non-finite number.
+	///
+	/// When the `simd` feature is enabled (nightly only), strings are scanned
+	/// for the characters to escape using vectorized instructions.
+	/// The output is the same.
+	fn to_writer<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+		write::write(self, writer)
+	}
+
+	/// Writes this value as a compact JSON document in a new byte buffer.
+	///
+	/// See [`to_writer`](Self::to_writer) for details.
+	fn to_vec(&self) -> std::io::Result<Vec<u8>> {
+		let mut buffer = Vec::new();
+		write::write(self, &mut buffer)?;
+		Ok(buffer)
+	}
+
+	/// Returns the number of nodes found at each depth of this value.
+	///
+	/// The item at index `d` is the number of nodes at depth `d`,
+	/// the root being at depth 0 and the elements (or members) of an array
+	/// (or object) at depth `d` being at depth `d + 1`.
+	/// The length of the result is the depth of the deepest node plus one.
+	fn depth_histogram(&self) -> Vec<usize> {
+		shape::depth_histogram(self)
+	}
+
+	/// Feeds the shape of this value into the given hasher,
+	/// ignoring scalar values.
+	///
+	/// The shape is made of the kind of each node, the length of arrays
+	/// and the (sorted) keys of objects.
+	/// Two values with the same shape but different strings, numbers or booleans
+	/// feed the hasher identically, which is useful to group documents by structure.
+	fn structural_shape_hash<H: Hasher>(&self, h: &mut H) {
+		shape::structural_shape_hash(self, h)
+	}
+
+	/// Compares this value with the JSON document read from `reader`, structurally.
+	///
+	/// The document is parsed event by event and compared on the fly
+	/// without being loaded in memory,
+	/// stopping at the first difference.
+	/// Numbers are compared by value (`1` and `1.0` are equal),
+	/// and objects by key lookup, regardless of the order of their members.
+	/// Objects containing duplicate keys, in the document or in this value, are never equal.
+	///
+	/// Returns an error of kind [`InvalidData`](std::io::ErrorKind::InvalidData)
+	/// wrapping a [`ParseError`] if the document is malformed
+	/// before the first difference is found.
+	fn eq_stream<R: std::io::Read>(&self, reader: R) -> std::io::Result<bool> {
+		stream::eq_stream(self, reader)
+	}
+
+	/// Counts the differences between this value and `other`, across the whole tree.
+	///
+	/// This value is considered as the old value and `other` as the new one.
+	/// Object members are matched by key, and array elements by index.
+	/// Numbers are compared by value (`1` and `1.0` are equal).
+	/// This is cheaper to compute than a full diff.
+	fn diff_summary<J: Json>(&self, other: &J) -> DiffSummary {
+		let _operation = trace::operation!("diff_summary");
+		let mut summary = DiffSummary::default();
+		diff::diff_summary(self, other, &mut summary);
+		summary
+	}
+
+	/// Compares this value with `other`, ignoring the differences allowed by `options`.
+	///
+	/// Arrays are compared element by element, and objects by key lookup,
+	/// regardless of the order of their members.
+	/// Objects holding the same key more than once are never equal.
+	fn semantically_eq<J: Json>(&self, other: &J, options: &CompareOptions) -> bool {
+		compare::semantically_eq(self, other, options)
+	}
+
+	/// Validates this value against the given JSON Schema.
+	///
+	/// Only a subset of JSON Schema is supported:
+	/// `type` (including `integer`), `required`, `properties`, `items`, `enum`,
+	/// `minimum` and `maximum`. Other keywords, and schemas that are not objects,
+	/// are ignored.
+	///
+	/// Each error carries the JSON Pointer to the invalid value,
+	/// or to the missing member for `required`.
+	fn validate_schema<S: Json>(&self, schema: &S) -> Result<(), Vec<SchemaError>> {
+		let mut errors = Vec::new();
+		validate::validate(self, schema, &mut String::new(), &mut errors);
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+
+	/// Computes the fingerprint of this value.
+	///
+	/// Comparing fingerprints is a cheap way to detect unequal values.
+	/// See [`Fingerprinted`] to reuse a fingerprint across comparisons.
+	fn fingerprint(&self) -> Fingerprint {
+		fingerprint::fingerprint(self)
+	}
+
+	/// Visits every node of this value in document order,
+	/// parents before their children.
+	///
+	/// `f` is called with the JSON Pointer to the node (the empty string
+	/// for the root) and the node itself.
+	/// The pointer buffer is reused across calls: copy it to keep it.
+	fn walk_with_path<F: FnMut(&str, ValueRef<'_, Self>)>(&self, mut f: F) {
+		pointer::walk_with_path(self, &mut String::new(), &mut f)
+	}
+
+	/// Returns the string values found in this value, in document order.
+	///
+	/// Object keys are not included:
+	/// see [`string_leaves_with_keys`](Self::string_leaves_with_keys).
+	#[cfg(feature = "nightly")]
+	fn string_leaves(&self) -> impl Iterator<Item = &str>
+	where
+		Self: JsonIntoRef,
+	{
+		let mut strings = Vec::new();
+		fold::string_leaves(self.as_value_ref(), &mut strings);
+		strings.into_iter()
+	}
+
+	/// Returns the string values and object keys found in this value,
+	/// in document order, each key preceding its value.
+	#[cfg(feature = "nightly")]
+	fn string_leaves_with_keys<'a>(&'a self) -> impl Iterator<Item = &'a str>
+	where
+		Self: JsonIntoRef,
+		Self::Object: cc_traits::KeyedRef<KeyRef<'a> = &'a Self::Key>,
+	{
+		let mut strings = Vec::new();
+		fold::string_leaves_with_keys(self.as_value_ref(), &mut strings);
+		strings.into_iter()
+	}
+
+	/// Folds the scalar leaves of this value (`null`, booleans, numbers and strings)
+	/// in document order, stopping at the first error.
+	///
+	/// Arrays and objects are traversed but not passed to `f`,
+	/// so empty arrays and objects are skipped.
+	fn try_fold_leaves<B, E>(
+		&self,
+		init: B,
+		mut f: impl FnMut(B, ValueRef<'_, Self>) -> Result<B, E>,
+	) -> Result<B, E> {
+		fold::try_fold_leaves(self, init, &mut f)
+	}
+
+	/// Encodes this value as an `application/x-www-form-urlencoded` string.
+	///
+	/// Each scalar leaf of the value becomes a `key=value` pair,
+	/// where nested object members and array elements are designated
+	/// using the bracket convention (e.g. `a[b][0]=1`).
+	/// Keys and values are percent-encoded.
+	/// `null` leaves are encoded with an empty value,
+	/// and empty arrays and objects produce no pair.
+	///
+	/// Returns an empty string if the value is neither an object nor an array.
+	///
+	/// The elements of a top-level array are keyed by their bare index
+	/// (`0=a&1=b`), which `from_query_string` parses back as an object
+	/// (`{"0": "a", "1": "b"}`), not an array.
+	/// Every other value made of objects, arrays and strings round-trips,
+	/// except for empty arrays and objects that produce no pair.
+	fn to_query_string(&self) -> String {
+		query::to_query_string(self)
+	}
+
+	/// If the value is a reference object, returns the reference target.
+	/// Returns `None` otherwise.
+	///
+	/// A reference object is an object with a `$ref` member whose value is a string,
+	/// such as `{ "$ref": "#/definitions/foo" }`, as used by JSON Schema.
+	/// Other members of the object are ignored.
+	#[cfg(feature = "nightly")]
+	fn as_ref_object(&self) -> Option<&str>
+	where
+		Self: JsonIntoRef,
+	{
+		self.as_object()
+			.and_then(|o| o.get("$ref"))
+			.and_then(|target| {
+				let target: ValueRef<'_, Self> = target.into();
+				target.into_str()
+			})
+	}
+
+	/// Collects every reference object (see [`Json::as_ref_object`]) in this value.
+	///
+	/// Returns a list of `(pointer, target)` pairs where `pointer` is the
+	/// JSON Pointer to the reference object, and `target` the reference target.
+	/// Pairs are listed in document order.
+	fn collect_refs(&self) -> Vec<(String, String)> {
+		refs::collect_refs(self)
+	}
+
+	/// Resolves the local references of this value.
+	///
+	/// Every reference object (see [`Json::as_ref_object`]) whose target
+	/// is a local JSON Pointer fragment (e.g. `#/definitions/foo`) is
+	/// replaced by a copy of the designated value, itself resolved.
+	/// Copies keep the metadata of the designated value,
+	/// and only referenced values are copied.
+	/// The replaced reference objects are discarded, along with their metadata.
+	/// Other references are left untouched.
+	///
+	/// Returns an error if a reference target does not exist,
+	/// if a reference (transitively) refers to itself,
+	/// or if a reference target contains a number that cannot be
+	/// rebuilt with [`NumberFrom`].
+	/// In case of error, this value may be partially resolved.
+	#[cfg(feature = "nightly")]
+	fn resolve_local_refs(&mut self) -> Result<(), RefError>
+	where
+		Self: JsonMut + JsonBuild,
+	{
+		refs::resolve_local_refs(self)
+	}
+
+	/// If the value is an object, returns the first member whose key
+	/// matches `key` ignoring ASCII case.
+	/// Returns `None` otherwise.
+	///
+	/// If multiple keys only differ by case, the first one in the
+	/// iteration order of the object is selected.
+	/// Depending on the backend, this is not necessarily the insertion order.
+	#[cfg(feature = "nightly")]
+	fn get_ci(&self, key: &str) -> Option<ValueRef<'_, Self>>
+	where
+		Self: JsonIntoRef,
+	{
+		self.as_object().and_then(|o| {
+			MapIter::iter(o)
+				.find(|(k, _)| k.eq_ignore_ascii_case(key))
+				.map(|(_, v)| v.into())
+		})
+	}
+
+	/// Returns the deepest node containing both nodes designated by
+	/// the JSON Pointers `a` and `b`, along with the JSON Pointer to it.
+	///
+	/// A node contains itself: if `a` designates an ancestor of `b`,
+	/// the node designated by `a` is returned.
+	/// Returns `None` if `a` or `b` is invalid or does not resolve.
+	///
+	/// ```
+	/// # #[cfg(feature = "serde_json-impl")]
+	/// # {
+	/// use generic_json::Json;
+	/// use serde_json::json;
+	///
+	/// let value = json!({ "a": { "b": [1, { "c": 2 }], "d": 3 } });
+	///
+	/// let (pointer, node) = value.common_ancestor("/a/b/0", "/a/b/1").unwrap();
+	/// assert_eq!(pointer, "/a/b");
+	/// assert!(node.is_array());
+	///
+	/// let (pointer, node) = value.common_ancestor("/a/b/1/c", "/a/d").unwrap();
+	/// assert_eq!(pointer, "/a");
+	/// assert!(node.is_object());
+	///
+	/// assert_eq!(value.common_ancestor("/a", "/a/d").unwrap().0, "/a");
+	/// assert!(value.common_ancestor("/a/b", "/a/e").is_none());
+	/// # }
+	/// ```
+	#[cfg(feature = "nightly")]
+	fn common_ancestor(&self, a: &str, b: &str) -> Option<(String, ValueRef<'_, Self>)>
+	where
+		Self: JsonIntoRef,
+	{
+		pointer::common_ancestor(self, a, b)
+	}
+
+	/// If the value is an object, returns its members sorted by key.
+	/// Returns `None` otherwise.
+	///
+	/// Keys are compared by their UTF-8 representation.
+	/// This gives a deterministic order whatever the iteration order of the backend.
+	/// Members with the same key are kept in their iteration order.
+	#[cfg(feature = "nightly")]
+	fn sorted_entries<'a>(&'a self) -> Option<Vec<(&'a str, ValueRef<'a, Self>)>>
+	where
+		Self: JsonIntoRef,
+		Self::Object: cc_traits::KeyedRef<KeyRef<'a> = &'a Self::Key>,
+	{
+		self.as_object().map(|o| {
+			let mut entries: Vec<_> = MapIter::iter(o)
+				.map(|(key, item)| (&**key, item.into()))
+				.collect();
+			entries.sort_by_key(|(key, _)| *key);
+			entries
+		})
+	}
+
+	/// If the value is an object, returns a copy of its members
+	/// in iteration order. Returns `None` otherwise.
+	///
+	/// If the object holds duplicate keys,
+	/// the last value is kept at the position of the first.
+	/// The metadata of the members is discarded.
+	#[cfg(feature = "indexmap")]
+	fn to_index_map(&self) -> Option<indexmap::IndexMap<String, Value<Self>>>
+	where
+		Self: Clone,
+	{
+		self.as_object().map(|o| {
+			MapIter::iter(o)
+				.map(|(key, item)| ((**key).to_string(), (*item).clone().into_value()))
+				.collect()
+		})
+	}
+
+	/// Returns a content identifier of this value:
+	/// the SHA-256 digest of its canonical serialization (see [`canonicalize`]).
+	///
+	/// Values that are structurally equal produce the same identifier,
+	/// whatever their backend, metadata or key order.
+	/// Numbers are canonicalized through their `f64` value,
+	/// so that `1` and `1.0` produce the same identifier.
+	/// Returns an error if the value contains a number
+	/// that cannot be canonicalized.
+	///
+	/// ```
+	/// # #[cfg(all(feature = "serde_json-impl", feature = "ijson-impl"))]
+	/// # {
+	/// use generic_json::{Json, TriviaValue};
+	/// use ijson::{IArray, IObject, IValue};
+	///
+	/// let a: serde_json::Value = serde_json::from_str(r#"{ "a": 1, "b": [true, "x"] }"#).unwrap();
+	/// let b = TriviaValue::parse(r#"{ "b": [ true, "x" ], "a": 1.0 } // comment"#).unwrap();
+	/// let mut c = IObject::new();
+	/// c.insert("b", IArray::from(vec![IValue::TRUE, IValue::from("x")]));
+	/// c.insert("a", 1.0);
+	/// let c = IValue::from(c);
+	/// assert_eq!(a.content_id().unwrap(), b.content_id().unwrap());
+	/// assert_eq!(a.content_id().unwrap(), c.content_id().unwrap());
+	///
+	/// let d: serde_json::Value = serde_json::from_str(r#"{ "a": 1, "b": ["x", true] }"#).unwrap();
+	/// assert_ne!(a.content_id().unwrap(), d.content_id().unwrap());
+	/// # }
+	/// ```
+	#[cfg(feature = "digest")]
+	fn content_id(&self) -> Result<[u8; 32], NumberOutOfRange> {
+		use sha2::{Digest, Sha256};
+		Ok(Sha256::digest(canonicalize(self)?.as_bytes()).into())
+	}
+
+	/// Freezes this value into an immutable representation
+	/// that can be cheaply cloned and shared between threads.
+	///
+	/// The metadata of every value and key is preserved.
+	/// Returns `None` if the value contains a number
+	/// that has no valid JSON representation, such as `NaN`.
+	/// See [`FrozenValue`].
+	fn freeze(self) -> Option<FrozenValue<Self::MetaData>>
+	where
+		Self::MetaData: Send + Sync,
+	{
+		FrozenValue::new(self)
+	}
+
+	/// Iterates over every node of this value in pre-order,
+	/// along with its path from this value.
+	///
+	/// See [`TreeIter`].
+	#[cfg(feature = "nightly")]
+	fn iter_tree<'a>(&'a self) -> TreeIter<'a, Self>
+	where
+		Self: JsonIntoRef,
+		Self::Object: cc_traits::KeyedRef<KeyRef<'a> = &'a Self::Key>,
+	{
+		TreeIter::new(self)
+	}
+
+	/// Iterates over the elements of the value if it is an array.
+	/// Yields nothing otherwise.
+	#[cfg(feature = "nightly")]
+	fn as_array_or_empty(&self) -> impl Iterator<Item = ValueRef<'_, Self>>
+	where
+		Self: JsonIntoRef,
+	{
+		self.as_array()
+			.into_iter()
+			.flat_map(|a| Iter::iter(a).map(Into::into))
+	}
+
+	/// Iterates over the members of the value if it is an object.
+	/// Yields nothing otherwise.
+	#[cfg(feature = "nightly")]
+	fn as_object_or_empty<'a>(&'a self) -> impl Iterator<Item = (&'a str, ValueRef<'a, Self>)>
+	where
+		Self: JsonIntoRef,
+		Self::Object: cc_traits::KeyedRef<KeyRef<'a> = &'a Self::Key>,
+	{
+		self.as_object()
+			.into_iter()
+			.flat_map(|o| MapIter::iter(o).map(|(key, item)| (&**key, item.into())))
+	}
+
+	/// If the value is an array, calls `f` with a mutable reference to each of its elements,
+	/// in order.
+	/// Does nothing otherwise.
+	#[cfg(feature = "nightly")]
+	fn for_each_array_mut<F: FnMut(ValueMut<'_, Self>)>(&mut self, mut f: F)
+	where
+		Self: JsonMut,
+	{
+		if let Some(a) = self.as_array_mut() {
+			for mut item in a.iter_mut() {
+				f(item.as_value_mut())
+			}
+		}
+	}
+
+	/// If the value is an object, returns the entry of the given key in it,
+	/// for in-place manipulation.
+	/// Returns `None` otherwise.
+	///
+	/// If the entry is vacant, the inserted key gets a copy of the metadata of this value.
+	///
+	/// ```
+	/// use generic_json::{Json, JsonNew, MetaValue, NumberBuf, NumberFrom};
+	///
+	/// let mut histogram = MetaValue::empty_object(());
+	/// for word in "the cat saw the other cat and the dog".split(' ') {
+	///     let count = histogram
+	///         .entry(word)
+	///         .unwrap()
+	///         .or_insert(MetaValue::number(NumberBuf::from_i64(0), ()));
+	///     let n = count.as_i64().unwrap();
+	///     *count = MetaValue::number(NumberBuf::from_i64(n + 1), ());
+	/// }
+	///
+	/// assert_eq!(histogram["the"].as_i64(), Some(3));
+	/// assert_eq!(histogram["cat"].as_i64(), Some(2));
+	/// assert_eq!(histogram["dog"].as_i64(), Some(1));
+	///
+	/// histogram
+	///     .entry("dog")
+	///     .unwrap()
+	///     .and_modify(|count| *count = MetaValue::null(()))
+	///     .or_insert_with(|| unreachable!());
+	/// assert!(histogram["dog"].is_null());
+	/// ```
+	#[cfg(feature = "nightly")]
+	fn entry<'a>(&'a mut self, key: &str) -> Option<Entry<'a, Self>>
+	where
+		Self: JsonMut + JsonBuild,
+		Self::Object: cc_traits::CollectionMut<ItemMut<'a> = &'a mut Self>,
+	{
+		let metadata = self.metadata().clone();
+		self.as_object_mut().map(|o| Entry::new(o, key, metadata))
+	}
+
+	/// If the value is an object, returns a mutable reference to the array
+	/// found at `key`, inserting an empty array first if the key is absent.
+	///
+	/// Returns `None` if the value is not an object,
+	/// or if the key holds a value that is not an array.
+	/// The inserted key and array get a copy of the metadata of this value.
+	#[cfg(feature = "nightly")]
+	fn get_or_insert_array<'a>(&'a mut self, key: &str) -> Option<&'a mut Self::Array>
+	where
+		Self: JsonMut + JsonBuild,
+		Self::Object: cc_traits::CollectionMut<ItemMut<'a> = &'a mut Self>,
+	{
+		let metadata = self.metadata().clone();
+		let o = self.as_object_mut()?;
+		if o.get(key).is_none() {
+			o.insert(
+				Self::new_key(key, metadata.clone()),
+				Self::empty_array(metadata),
+			);
+		}
+
+		o.get_mut(key)?.as_array_mut()
+	}
+
+	/// If the value is an object, returns a mutable reference to the object
+	/// found at `key`, inserting an empty object first if the key is absent.
+	///
+	/// Returns `None` if the value is not an object,
+	/// or if the key holds a value that is not an object.
+	/// The inserted key and object get a copy of the metadata of this value.
+	#[cfg(feature = "nightly")]
+	fn get_or_insert_object<'a>(&'a mut self, key: &str) -> Option<&'a mut Self::Object>
+	where
+		Self: JsonMut + JsonBuild,
+		Self::Object: cc_traits::CollectionMut<ItemMut<'a> = &'a mut Self>,
+	{
+		let metadata = self.metadata().clone();
+		let o = self.as_object_mut()?;
+		if o.get(key).is_none() {
+			o.insert(
+				Self::new_key(key, metadata.clone()),
+				Self::empty_object(metadata),
+			);
+		}
+
+		o.get_mut(key)?.as_object_mut()
+	}
+
+	/// Returns the JSON Merge Patch ([RFC 7386](https://www.rfc-editor.org/rfc/rfc7386))
+	/// turning this value into `target`.
+	///
+	/// If both values are objects, the patch holds `null` for each removed key,
+	/// the value of each added key, and the patch of each changed member,
+	/// recursively.
+	/// Members are compared as by [`json_eq`].
+	/// Any other change, including a change inside an array,
+	/// replaces the whole value.
+	/// Every value and key of the patch is given the default metadata.
+	///
+	/// Merge patches cannot set an object member to `null`:
+	/// such members of `target` are removed when the patch is applied.
+	///
+	/// ```
+	/// # #[cfg(feature = "serde_json-impl")]
+	/// # {
+	/// use generic_json::{merge_patch, Json, Value};
+	/// use serde_json::json;
+	///
+	/// let mut source = json!({ "a": "b", "c": { "d": "e", "f": "g" }, "h": [1, 2] });
+	/// let target = json!({ "a": "z", "c": { "d": "e" }, "h": [1], "i": { "j": 1.5 } });
+	///
+	/// let patch: Value<serde_json::Value> = source.merge_patch_diff(&target);
+	/// let patch = patch.with_default();
+	/// assert_eq!(patch, json!({ "a": "z", "c": { "f": null }, "h": [1], "i": { "j": 1.5 } }));
+	///
+	/// merge_patch(&mut source, &patch);
+	/// assert_eq!(source, target);
+	/// # }
+	/// ```
+	#[cfg(feature = "nightly")]
+	fn merge_patch_diff<T: JsonBuild>(&self, target: &impl Json) -> Value<T>
+	where
+		T::MetaData: Default,
+	{
+		patch::merge_patch_diff(self, target)
+	}
+
+	/// Sets the node designated by the given JSON Pointer to `value`.
+	///
+	/// The parent of the node must exist.
+	/// If the node exists, it is replaced, keeping its metadata,
+	/// and the old value is returned.
+	/// Otherwise the node is added to its parent object,
+	/// or appended to its parent array if the last reference token is
+	/// the length of the array or `-`, and `None` is returned.
+	/// Added nodes and keys get a copy of the metadata of their parent.
+	#[cfg(feature = "nightly")]
+	fn set_pointer(
+		&mut self,
+		pointer: &str,
+		value: Value<Self>,
+	) -> Result<Option<Value<Self>>, PointerError>
+	where
+		Self: JsonMut + JsonBuild,
+	{
+		pointer::set_pointer(self, pointer, value)
+	}
+
+	/// Recursively replaces every non-finite number (`NaN`, `inf`, `-inf`)
+	/// contained in this value according to the given `policy`.
+	///
+	/// The metadata of a replaced number is kept on its replacement.
+	#[cfg(feature = "nightly")]
+	fn sanitize(&mut self, policy: SanitizePolicy)
+	where
+		Self: JsonMut + JsonBuild,
+	{
+		let replacement = match self.as_value_mut() {
+			ValueMut::Number(n) => policy.replacement::<Self>(n),
+			ValueMut::Array(a) => {
+				for mut item in a.iter_mut() {
+					item.sanitize(policy)
+				}
+
+				None
+			}
+			ValueMut::Object(o) => {
+				for (_, mut item) in o.iter_mut() {
+					item.sanitize(policy)
+				}
+
+				None
+			}
+			_ => None,
+		};
+
+		if let Some(value) = replacement {
+			let metadata = self.metadata().clone();
+			*self = Self::new(value, metadata)
+		}
+	}
+
+	/// Recursively replaces every integer number whose magnitude
+	/// exceeds `threshold` with a string holding its decimal representation.
+	///
+	/// This preserves large integers when the value is sent to JavaScript,
+	/// where integers above 2^53 lose precision
+	/// (use `threshold = (1 << 53) - 1` for this purpose).
+	/// Non-integer numbers are left untouched.
+	/// The metadata of a replaced number is kept on its replacement.
+	#[cfg(feature = "nightly")]
+	fn stringify_large_ints(&mut self, threshold: i128)
+	where
+		Self: JsonMut + JsonBuild,
+	{
+		let replacement = match self.as_value_mut() {
+			ValueMut::Number(n) => number::as_i128(&*n)
+				.filter(|i| i.abs() > threshold)
+				.map(|i| Value::String(i.to_string().as_str().into())),
+			ValueMut::Array(a) => {
+				for mut item in a.iter_mut() {
+					item.stringify_large_ints(threshold)
+				}
+
+				None
+			}
+			ValueMut::Object(o) => {
+				for (_, mut item) in o.iter_mut() {
+					item.stringify_large_ints(threshold)
+				}
+
+				None
+			}
+			_ => None,
+		};
+
+		if let Some(value) = replacement {
+			let metadata = self.metadata().clone();
+			*self = Self::new(value, metadata)
+		}
+	}
+
+	/// Recursively removes the object members whose value is `null`.
+	///
+	/// Array elements are kept, even when `null`.
+	/// Returns `true` if any member has been removed.
+	#[cfg(feature = "nightly")]
+	fn strip_nulls(&mut self) -> bool
+	where
+		Self: JsonMut + JsonBuild,
+	{
+		match self.as_value_mut() {
+			ValueMut::Array(a) => {
+				let mut changed = false;
+				for mut item in a.iter_mut() {
+					changed |= item.strip_nulls()
+				}
+
+				changed
+			}
+			ValueMut::Object(o) => {
+				let mut changed = false;
+				if MapIter::iter(&*o).any(|(_, item)| item.is_null()) {
+					let object = std::mem::take(o);
+					*o = object
+						.into_iter()
+						.filter(|(_, item)| !item.is_null())
+						.collect();
+					changed = true
+				}
+
+				for (_, mut item) in o.iter_mut() {
+					changed |= item.strip_nulls()
+				}
+
+				changed
+			}
+			_ => false,
+		}
+	}
+
+	/// Recursively replaces every integral floating point number
+	/// (such as `2.0` or `1e3`) with the equal integer.
+	///
+	/// Numbers that do not fit in an `i64` or `u64` are left untouched.
+	/// The metadata of a replaced number is kept on its replacement.
+	/// Returns `true` if any number has been replaced.
+	#[cfg(feature = "nightly")]
+	fn normalize_integral_floats(&mut self) -> bool
+	where
+		Self: JsonMut + JsonBuild,
+	{
+		let replacement = match self.as_value_mut() {
+			ValueMut::Number(n) => normalize::integral_float(&*n),
+			ValueMut::Array(a) => {
+				let mut changed = false;
+				for mut item in a.iter_mut() {
+					changed |= item.normalize_integral_floats()
+				}
+
+				return changed;
+			}
+			ValueMut::Object(o) => {
+				let mut changed = false;
+				for (_, mut item) in o.iter_mut() {
+					changed |= item.normalize_integral_floats()
+				}
+
+				return changed;
+			}
+			_ => None,
+		};
+
+		match replacement {
+			Some(n) => {
+				let metadata = self.metadata().clone();
+				*self = Self::number(n, metadata);
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Returns this value with its `null` object members removed
+	/// and its integral floating point numbers replaced with integers.
+	///
+	/// See [`strip_nulls`](Self::strip_nulls) and
+	/// [`normalize_integral_floats`](Self::normalize_integral_floats).
+	/// The value is only cloned if it needs to be changed.
+	///
+	/// This returns a `Cow<Self>` rather than a `Cow<Value<Self>>`:
+	/// backend values are not stored as [`Value`], so an unchanged
+	/// value could not be borrowed as one.
+	#[cfg(feature = "nightly")]
+	fn normalized(&self) -> std::borrow::Cow<'_, Self>
+	where
+		Self: JsonMut + JsonBuild + JsonClone,
+	{
+		if normalize::is_normalized(self.as_value_ref()) {
+			std::borrow::Cow::Borrowed(self)
+		} else {
+			let mut value = self.clone();
+			value.strip_nulls();
+			value.normalize_integral_floats();
+			std::borrow::Cow::Owned(value)
+		}
+	}
+
+	/// Recursively removes the duplicate keys of every object
+	/// contained in this value, according to the given `policy`.
+	///
+	/// This is a no-op for backends whose objects cannot hold duplicate keys.
+	/// Objects containing duplicates are rebuilt from their remaining entries,
+	/// hence the `Default` and `FromIterator` bounds on the object type.
+	#[cfg(feature = "nightly")]
+	fn dedup_keys(&mut self, policy: DuplicateKeyPolicy)
+	where
+		Self: JsonMut,
+		Self::Object: Default + std::iter::FromIterator<(Self::Key, Self)>,
+	{
+		match self.as_value_mut() {
+			ValueMut::Array(a) => {
+				for mut item in a.iter_mut() {
+					item.dedup_keys(policy)
+				}
+			}
+			ValueMut::Object(o) => {
+				if dedup::has_duplicate_keys::<Self>(o) {
+					let object = std::mem::take(o);
+					*o = policy.dedup::<Self>(object)
+				}
+
+				for (_, mut item) in o.iter_mut() {
+					item.dedup_keys(policy)
+				}
+			}
+			_ => (),
+		}
+	}
+
+	/// Recursively replaces single-element arrays by their element.
+	///
+	/// `should_unwrap` is called on each single-element array with the path
+	/// of object keys leading to it (array indexes are not included),
+	/// and returns whether the array must be replaced.
+	/// An array is unwrapped at most once: `[[x]]` becomes `[x]`.
+	/// The element keeps its own metadata.
+	#[cfg(feature = "nightly")]
+	fn unwrap_single_arrays(&mut self, mut should_unwrap: impl FnMut(&[&str]) -> bool)
+	where
+		Self: JsonMut,
+	{
+		unwrap::unwrap_single_arrays(self, &mut Vec::new(), &mut should_unwrap)
+	}
+
+	/// Recursively renames the object keys found in this value
+	/// according to the given `mapping` from old to new keys.
+	///
+	/// If a renamed key collides with another key of the same object,
+	/// the entry coming last in the iteration order of the object is kept,
+	/// at the position of the first.
+	/// Returns the JSON Pointers to the kept entries of each collision,
+	/// using the new keys.
+	#[cfg(feature = "nightly")]
+	fn rename_keys(&mut self, mapping: &BTreeMap<String, String>) -> Vec<String>
+	where
+		Self: JsonMut + JsonBuild,
+	{
+		let mut collisions = Vec::new();
+		rename::rename_keys(
+			self,
+			&|key| mapping.get(key).cloned(),
+			&mut String::new(),
+			&mut collisions,
+		);
+		collisions
+	}
+
+	/// Recursively converts the object keys found in this value to the given case style.
+	///
+	/// Collisions are handled as in [`rename_keys`](Self::rename_keys):
+	/// if a converted key collides with another key of the same object,
+	/// the entry coming last in the iteration order of the object is kept,
+	/// at the position of the first.
+	/// Returns the JSON Pointers to the kept entries of each collision,
+	/// using the new keys.
+	///
+	/// ```
+	/// # #[cfg(feature = "serde_json-impl")]
+	/// # {
+	/// use generic_json::{CaseStyle, Json};
+	/// use serde_json::json;
+	///
+	/// let mut value = json!({ "FooBar": 1, "items": [{ "HTTPServer": true }] });
+	/// value.recase_keys(CaseStyle::Snake);
+	/// assert_eq!(value, json!({ "foo_bar": 1, "items": [{ "http_server": true }] }));
+	///
+	/// value.recase_keys(CaseStyle::Camel);
+	/// assert_eq!(value, json!({ "fooBar": 1, "items": [{ "httpServer": true }] }));
+	///
+	/// let mut value = json!({ "a": 1, "A": 2 });
+	/// assert_eq!(value.recase_keys(CaseStyle::Lower), ["/a"]);
+	/// # }
+	/// ```
+	#[cfg(feature = "nightly")]
+	fn recase_keys(&mut self, style: CaseStyle) -> Vec<String>
+	where
+		Self: JsonMut + JsonBuild,
+	{
+		let mut collisions = Vec::new();
+		rename::rename_keys(
+			self,
+			&|key| Some(style.convert(key)).filter(|new_key| new_key != key),
+			&mut String::new(),
+			&mut collisions,
+		);
+		collisions
+	}
+
+	/// If the value is an object, returns a copy keeping only the members
+	/// whose key is in `keys`.
+	/// Returns a copy of the value otherwise.
+	#[cfg(feature = "nightly")]
+	fn pick(&self, keys: &[&str]) -> Value<Self>
+	where
+		Self: JsonClone + JsonBuild,
+	{
+		selection::filter_members(self, |key| keys.contains(&key))
+	}
+
+	/// If the value is an object, returns a copy without the members
+	/// whose key is in `keys`.
+	/// Returns a copy of the value otherwise.
+	#[cfg(feature = "nightly")]
+	fn omit(&self, keys: &[&str]) -> Value<Self>
+	where
+		Self: JsonClone + JsonBuild,
+	{
+		selection::filter_members(self, |key| !keys.contains(&key))
+	}
+
+	/// Builds an object from an array of `[key, value]` pairs,
+	/// where each key is a string.
+	///
+	/// The values are converted into the target type `T`,
+	/// numbers being converted exactly when both types can represent them.
+	/// If multiple pairs have the same key, the resulting object
+	/// depends on how the target object type collects duplicate keys.
+	///
+	/// Returns an error if this value is not an array,
+	/// if one of its elements is not a two-element array with a string key,
+	/// or if a number cannot be represented by the target type.
+	#[cfg(feature = "nightly")]
+	fn pairs_to_object<T>(&self) -> Result<T, PairsError>
+	where
+		T: JsonBuild<MetaData = Self::MetaData>,
+	{
+		pairs::pairs_to_object(self)
+	}
+
+	/// Extracts the parts of this value selected by `selection`,
+	/// preserving its structure.
+	///
+	/// See [`Selection::project`] for more details.
+	#[cfg(feature = "nightly")]
+	fn project(&self, selection: &Selection) -> Value<Self>
+	where
+		Self: JsonClone + JsonBuild,
+	{
+		selection.project(self)
+	}
 }
 
 impl<J: Json> From<J> for Value<J> {
@@ -384,6 +1579,88 @@ pub trait JsonNew: Json {
 	{
 		Self::object(Self::Object::default(), metadata)
 	}
+
+	/// Creates a new object value from the given map entries.
+	///
+	/// Keys, values and the object itself are given the default metadata.
+	#[cfg(feature = "nightly")]
+	fn object_from_map<K: Into<String>, V: Into<Value<Self>>>(
+		map: impl IntoIterator<Item = (K, V)>,
+	) -> Self
+	where
+		Self: JsonBuild,
+		Self::MetaData: Default,
+	{
+		let object = map
+			.into_iter()
+			.map(|(key, value)| {
+				let key = key.into();
+				(
+					Self::new_key(&key, Self::MetaData::default()),
+					Self::new(value.into(), Self::MetaData::default()),
+				)
+			})
+			.collect();
+
+		Self::object(object, Self::MetaData::default())
+	}
+
+	/// Creates a new array of numbers.
+	///
+	/// Elements are given the default metadata.
+	#[cfg(feature = "nightly")]
+	fn number_array<N: Into<Self::Number>>(
+		numbers: impl IntoIterator<Item = N>,
+		metadata: Self::MetaData,
+	) -> Self
+	where
+		Self: JsonBuild,
+		Self::MetaData: Default,
+	{
+		let array = numbers
+			.into_iter()
+			.map(|n| Self::number(n.into(), Self::MetaData::default()))
+			.collect();
+
+		Self::array(array, metadata)
+	}
+
+	/// Creates a new array of strings.
+	///
+	/// Elements are given the default metadata.
+	#[cfg(feature = "nightly")]
+	fn string_array<S: AsRef<str>>(
+		strings: impl IntoIterator<Item = S>,
+		metadata: Self::MetaData,
+	) -> Self
+	where
+		Self: JsonBuild,
+		Self::MetaData: Default,
+	{
+		let array = strings
+			.into_iter()
+			.map(|s| Self::string(s.as_ref().into(), Self::MetaData::default()))
+			.collect();
+
+		Self::array(array, metadata)
+	}
+
+	/// Creates a new array of booleans.
+	///
+	/// Elements are given the default metadata.
+	#[cfg(feature = "nightly")]
+	fn bool_array(booleans: impl IntoIterator<Item = bool>, metadata: Self::MetaData) -> Self
+	where
+		Self: JsonBuild,
+		Self::MetaData: Default,
+	{
+		let array = booleans
+			.into_iter()
+			.map(|b| Self::boolean(b, Self::MetaData::default()))
+			.collect();
+
+		Self::array(array, metadata)
+	}
 }
 
 /// Null JSON type.
@@ -0,0 +1,242 @@
+use crate::{Json, JsonBuild, NumberFrom, Value};
+
+/// Conversion into a JSON value of any type.
+///
+/// Used by the [`json!`](crate::json) macro to build interpolated expressions
+/// and literals.
+/// Numbers are built with [`NumberFrom`],
+/// and non-finite floating point numbers are converted into `null`.
+pub trait ToJsonValue<T: Json> {
+	/// Converts this value into a JSON value.
+	fn to_json_value(&self) -> Value<T>;
+}
+
+impl<T: Json, U: ToJsonValue<T> + ?Sized> ToJsonValue<T> for &U {
+	fn to_json_value(&self) -> Value<T> {
+		(**self).to_json_value()
+	}
+}
+
+impl<T: Json> ToJsonValue<T> for bool {
+	fn to_json_value(&self) -> Value<T> {
+		Value::Boolean(*self)
+	}
+}
+
+macro_rules! to_json_value_int {
+	($from:ident: $($ty:ty),*) => {
+		$(
+			impl<T: Json> ToJsonValue<T> for $ty where T::Number: NumberFrom {
+				fn to_json_value(&self) -> Value<T> {
+					Value::Number(T::Number::$from((*self).into()))
+				}
+			}
+		)*
+	};
+}
+
+to_json_value_int!(from_i64: i8, i16, i32, i64);
+to_json_value_int!(from_u64: u8, u16, u32, u64);
+
+impl<T: Json> ToJsonValue<T> for isize
+where
+	T::Number: NumberFrom,
+{
+	fn to_json_value(&self) -> Value<T> {
+		Value::Number(T::Number::from_i64(*self as i64))
+	}
+}
+
+impl<T: Json> ToJsonValue<T> for usize
+where
+	T::Number: NumberFrom,
+{
+	fn to_json_value(&self) -> Value<T> {
+		Value::Number(T::Number::from_u64(*self as u64))
+	}
+}
+
+impl<T: Json> ToJsonValue<T> for f32
+where
+	T::Number: NumberFrom,
+{
+	fn to_json_value(&self) -> Value<T> {
+		(*self as f64).to_json_value()
+	}
+}
+
+impl<T: Json> ToJsonValue<T> for f64
+where
+	T::Number: NumberFrom,
+{
+	fn to_json_value(&self) -> Value<T> {
+		match T::Number::from_f64(*self) {
+			Some(n) => Value::Number(n),
+			None => Value::Null,
+		}
+	}
+}
+
+impl<T: Json> ToJsonValue<T> for str {
+	fn to_json_value(&self) -> Value<T> {
+		Value::String(self.into())
+	}
+}
+
+impl<T: Json> ToJsonValue<T> for String {
+	fn to_json_value(&self) -> Value<T> {
+		Value::String(self.as_str().into())
+	}
+}
+
+impl<T: Json, U: ToJsonValue<T>> ToJsonValue<T> for Option<U> {
+	fn to_json_value(&self) -> Value<T> {
+		match self {
+			Some(u) => u.to_json_value(),
+			None => Value::Null,
+		}
+	}
+}
+
+impl<T: JsonBuild, U: ToJsonValue<T>> ToJsonValue<T> for [U]
+where
+	T::MetaData: Default,
+{
+	fn to_json_value(&self) -> Value<T> {
+		Value::Array(
+			self.iter()
+				.map(|u| u.to_json_value().with_default())
+				.collect(),
+		)
+	}
+}
+
+impl<T: JsonBuild, U: ToJsonValue<T>> ToJsonValue<T> for Vec<U>
+where
+	T::MetaData: Default,
+{
+	fn to_json_value(&self) -> Value<T> {
+		self.as_slice().to_json_value()
+	}
+}
+
+impl<T: Json> ToJsonValue<T> for Value<T>
+where
+	Value<T>: Clone,
+{
+	fn to_json_value(&self) -> Value<T> {
+		self.clone()
+	}
+}
+
+/// Builds a [`Value`] of the given JSON type from a JSON literal.
+///
+/// The JSON type comes first, followed by a semicolon and the value.
+/// Values, object keys included, are given the default metadata.
+/// Literals are converted with [`ToJsonValue`],
+/// as well as Rust expressions interpolated with `#[expr]`.
+///
+/// ```
+/// use generic_json::{json, Json, MetaValue, Number, Value};
+///
+/// let tags = vec![1, 2, 3];
+/// let value: Value<MetaValue> = json!(MetaValue; {
+///     "name": "generic-json",
+///     "version": [0, 7, -1],
+///     "ratio": 3.14,
+///     "tags": #[tags],
+///     "nested": { "ok": true, "missing": null }
+/// });
+///
+/// assert_eq!(value["tags"][2].as_number().unwrap().as_i64(), Some(3));
+/// assert_eq!(value["version"][2].as_number().unwrap().as_i64(), Some(-1));
+/// assert!(value["nested"]["missing"].is_null());
+/// ```
+///
+/// The same literal builds a `serde_json::Value`:
+///
+/// ```
+/// # #[cfg(feature = "serde_json-impl")]
+/// # {
+/// use generic_json::{json, Value};
+///
+/// let value: Value<serde_json::Value> = json!(serde_json::Value; [1, "two", #[Some(3.5)]]);
+/// assert_eq!(value.with_default(), serde_json::json!([1, "two", 3.5]));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! json {
+	// Values.
+	(@value $t:ty; null) => {
+		$crate::Value::<$t>::Null
+	};
+	(@value $t:ty; true) => {
+		$crate::Value::<$t>::Boolean(true)
+	};
+	(@value $t:ty; false) => {
+		$crate::Value::<$t>::Boolean(false)
+	};
+	(@value $t:ty; [ $($tt:tt)* ]) => {
+		$crate::Value::<$t>::Array(
+			$crate::json!(@array $t; [] $($tt)*)
+				.into_iter()
+				.map($crate::Value::<$t>::with_default)
+				.collect()
+		)
+	};
+	(@value $t:ty; { $($tt:tt)* }) => {
+		$crate::Value::<$t>::Object(
+			$crate::json!(@object $t; [] $($tt)*)
+				.into_iter()
+				.map(|(key, value): (&str, $crate::Value<$t>)| {
+					(
+						<$t as $crate::JsonNew>::new_key(key, ::std::default::Default::default()),
+						value.with_default()
+					)
+				})
+				.collect()
+		)
+	};
+	(@value $t:ty; #[$e:expr]) => {
+		$crate::ToJsonValue::<$t>::to_json_value(&$e)
+	};
+	(@value $t:ty; - $l:literal) => {
+		$crate::ToJsonValue::<$t>::to_json_value(&-$l)
+	};
+	(@value $t:ty; $l:literal) => {
+		$crate::ToJsonValue::<$t>::to_json_value(&$l)
+	};
+
+	// Array elements.
+	(@array $t:ty; [$($done:expr,)*]) => {
+		::std::vec![$($done),*]
+	};
+	(@array $t:ty; [$($done:expr,)*] #[$e:expr] $(, $($rest:tt)*)?) => {
+		$crate::json!(@array $t; [$($done,)* $crate::json!(@value $t; #[$e]),] $($($rest)*)?)
+	};
+	(@array $t:ty; [$($done:expr,)*] - $l:literal $(, $($rest:tt)*)?) => {
+		$crate::json!(@array $t; [$($done,)* $crate::json!(@value $t; - $l),] $($($rest)*)?)
+	};
+	(@array $t:ty; [$($done:expr,)*] $v:tt $(, $($rest:tt)*)?) => {
+		$crate::json!(@array $t; [$($done,)* $crate::json!(@value $t; $v),] $($($rest)*)?)
+	};
+
+	// Object members.
+	(@object $t:ty; [$($done:expr,)*]) => {
+		::std::vec![$($done),*]
+	};
+	(@object $t:ty; [$($done:expr,)*] $k:literal : #[$e:expr] $(, $($rest:tt)*)?) => {
+		$crate::json!(@object $t; [$($done,)* ($k, $crate::json!(@value $t; #[$e])),] $($($rest)*)?)
+	};
+	(@object $t:ty; [$($done:expr,)*] $k:literal : - $l:literal $(, $($rest:tt)*)?) => {
+		$crate::json!(@object $t; [$($done,)* ($k, $crate::json!(@value $t; - $l)),] $($($rest)*)?)
+	};
+	(@object $t:ty; [$($done:expr,)*] $k:literal : $v:tt $(, $($rest:tt)*)?) => {
+		$crate::json!(@object $t; [$($done,)* ($k, $crate::json!(@value $t; $v)),] $($($rest)*)?)
+	};
+
+	// Entry point.
+	($t:ty; $($json:tt)+) => {
+		$crate::json!(@value $t; $($json)+)
+	};
+}
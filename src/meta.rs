@@ -0,0 +1,661 @@
+use crate::{Json, JsonNew, Key, NumberBuf, Value, ValueMut, ValueRef};
+use cc_traits::{
+	Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Keyed, KeyedRef, Len,
+	MapInsert, MapIter, MapIterMut, Remove,
+};
+use std::{
+	borrow::Borrow,
+	collections::HashMap,
+	fmt,
+	hash::{Hash, Hasher},
+	iter::FromIterator,
+	ops::{Deref, Index, IndexMut},
+};
+
+/// JSON value with metadata attached to each node.
+///
+/// Each value, including the elements of arrays and the members of objects,
+/// carries its own metadata of type `M` (such as a source span).
+/// Object keys carry their own metadata as well (see [`MetaKey`]).
+/// The metadata is ignored when comparing values.
+///
+/// Object members are kept in insertion order.
+#[derive(Clone)]
+pub struct MetaValue<M = ()> {
+	value: Meta<M>,
+	metadata: M,
+}
+
+#[derive(Clone)]
+enum Meta<M> {
+	Null,
+	Boolean(bool),
+	Number(NumberBuf),
+	String(String),
+	Array(Vec<MetaValue<M>>),
+	Object(MetaObject<M>),
+}
+
+impl<M> MetaValue<M> {
+	/// Returns a mutable reference to the metadata of this value.
+	pub fn metadata_mut(&mut self) -> &mut M {
+		&mut self.metadata
+	}
+}
+
+impl<M: Clone> MetaValue<M> {
+	/// Combines the metadata of each object key with the metadata
+	/// of the associated value, recursively.
+	///
+	/// For each object member, `combine` is called with the key metadata
+	/// and the value metadata, and its result replaces the value metadata.
+	/// Keys keep their metadata.
+	pub fn fold_key_meta_into_values(self, mut combine: impl FnMut(M, M) -> M) -> Self {
+		self.fold_key_meta(&mut combine)
+	}
+
+	fn fold_key_meta<F: FnMut(M, M) -> M>(self, combine: &mut F) -> Self {
+		let value = match self.value {
+			Meta::Array(a) => Meta::Array(
+				a.into_iter()
+					.map(|item| item.fold_key_meta(combine))
+					.collect(),
+			),
+			Meta::Object(o) => Meta::Object(
+				o.into_iter()
+					.map(|(key, item)| {
+						let item = item.fold_key_meta(combine);
+						let metadata = combine(key.metadata.clone(), item.metadata);
+						(
+							key,
+							Self {
+								value: item.value,
+								metadata,
+							},
+						)
+					})
+					.collect(),
+			),
+			value => value,
+		};
+
+		Self {
+			value,
+			metadata: self.metadata,
+		}
+	}
+}
+
+impl<M> PartialEq for MetaValue<M> {
+	fn eq(&self, other: &Self) -> bool {
+		match (&self.value, &other.value) {
+			(Meta::Null, Meta::Null) => true,
+			(Meta::Boolean(a), Meta::Boolean(b)) => a == b,
+			(Meta::Number(a), Meta::Number(b)) => a == b,
+			(Meta::String(a), Meta::String(b)) => a == b,
+			(Meta::Array(a), Meta::Array(b)) => a == b,
+			(Meta::Object(a), Meta::Object(b)) => a == b,
+			_ => false,
+		}
+	}
+}
+
+impl<M> Eq for MetaValue<M> {}
+
+impl<M> fmt::Debug for MetaValue<M> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match &self.value {
+			Meta::Null => write!(f, "Null"),
+			Meta::Boolean(b) => write!(f, "Boolean({:?})", b),
+			Meta::Number(n) => write!(f, "Number({})", n),
+			Meta::String(s) => write!(f, "String({:?})", s),
+			Meta::Array(a) => f.debug_list().entries(a.iter()).finish(),
+			Meta::Object(o) => o.fmt(f),
+		}
+	}
+}
+
+/// Object key of a [`MetaValue`].
+///
+/// The metadata is ignored when comparing or hashing keys.
+#[derive(Clone)]
+pub struct MetaKey<M = ()> {
+	key: String,
+	metadata: M,
+}
+
+impl<M> MetaKey<M> {
+	/// Creates a new key with the given metadata.
+	pub fn new(key: String, metadata: M) -> Self {
+		Self { key, metadata }
+	}
+
+	/// Returns a mutable reference to the metadata of this key.
+	pub fn metadata_mut(&mut self) -> &mut M {
+		&mut self.metadata
+	}
+
+	/// Turns this key into its string and metadata.
+	pub fn into_parts(self) -> (String, M) {
+		(self.key, self.metadata)
+	}
+}
+
+impl<M> Deref for MetaKey<M> {
+	type Target = str;
+
+	fn deref(&self) -> &str {
+		&self.key
+	}
+}
+
+impl<M> Borrow<str> for MetaKey<M> {
+	fn borrow(&self) -> &str {
+		&self.key
+	}
+}
+
+impl<M> PartialEq for MetaKey<M> {
+	fn eq(&self, other: &Self) -> bool {
+		self.key == other.key
+	}
+}
+
+impl<M> Eq for MetaKey<M> {}
+
+impl<M> Hash for MetaKey<M> {
+	fn hash<H: Hasher>(&self, h: &mut H) {
+		self.key.hash(h)
+	}
+}
+
+impl<M> fmt::Debug for MetaKey<M> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.key.fmt(f)
+	}
+}
+
+impl<M> Key<M> for MetaKey<M> {
+	fn metadata(&self) -> &M {
+		&self.metadata
+	}
+}
+
+/// Object of a [`MetaValue`].
+///
+/// Members are kept in insertion order, and keys are unique:
+/// inserting a member whose key is already present replaces the previous value
+/// at its position.
+pub struct MetaObject<M = ()> {
+	entries: Vec<(MetaKey<M>, MetaValue<M>)>,
+	indexes: HashMap<String, usize>,
+}
+
+impl<M> MetaObject<M> {
+	/// Creates a new empty object.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	fn index_of(&self, key: &str) -> Option<usize> {
+		self.indexes.get(key).copied()
+	}
+}
+
+impl<M: Clone> Clone for MetaObject<M> {
+	fn clone(&self) -> Self {
+		Self {
+			entries: self.entries.clone(),
+			indexes: self.indexes.clone(),
+		}
+	}
+}
+
+impl<M> Default for MetaObject<M> {
+	fn default() -> Self {
+		Self {
+			entries: Vec::new(),
+			indexes: HashMap::new(),
+		}
+	}
+}
+
+impl<M> PartialEq for MetaObject<M> {
+	/// Compares the members of both objects, regardless of their order.
+	fn eq(&self, other: &Self) -> bool {
+		self.entries.len() == other.entries.len()
+			&& self
+				.entries
+				.iter()
+				.all(|(key, value)| match other.index_of(key) {
+					Some(i) => other.entries[i].1 == *value,
+					None => false,
+				})
+	}
+}
+
+impl<M> Eq for MetaObject<M> {}
+
+impl<M> fmt::Debug for MetaObject<M> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_map()
+			.entries(self.entries.iter().map(|(k, v)| (k, v)))
+			.finish()
+	}
+}
+
+impl<M> Collection for MetaObject<M> {
+	type Item = MetaValue<M>;
+}
+
+impl<M> CollectionRef for MetaObject<M> {
+	type ItemRef<'a>
+		= &'a MetaValue<M>
+	where
+		M: 'a;
+
+	cc_traits::covariant_item_ref!();
+}
+
+impl<M> CollectionMut for MetaObject<M> {
+	type ItemMut<'a>
+		= &'a mut MetaValue<M>
+	where
+		M: 'a;
+
+	cc_traits::covariant_item_mut!();
+}
+
+impl<M> Keyed for MetaObject<M> {
+	type Key = MetaKey<M>;
+}
+
+impl<M> KeyedRef for MetaObject<M> {
+	type KeyRef<'a>
+		= &'a MetaKey<M>
+	where
+		M: 'a;
+
+	cc_traits::covariant_key_ref!();
+}
+
+impl<M> Len for MetaObject<M> {
+	fn len(&self) -> usize {
+		self.entries.len()
+	}
+}
+
+impl<'q, M> Get<&'q str> for MetaObject<M> {
+	fn get(&self, key: &'q str) -> Option<&MetaValue<M>> {
+		self.index_of(key).map(|i| &self.entries[i].1)
+	}
+}
+
+impl<'q, M> GetKeyValue<&'q str> for MetaObject<M> {
+	fn get_key_value(&self, key: &'q str) -> Option<(&MetaKey<M>, &MetaValue<M>)> {
+		self.index_of(key).map(|i| {
+			let (key, value) = &self.entries[i];
+			(key, value)
+		})
+	}
+}
+
+impl<'q, M> GetMut<&'q str> for MetaObject<M> {
+	fn get_mut(&mut self, key: &'q str) -> Option<&mut MetaValue<M>> {
+		self.index_of(key).map(move |i| &mut self.entries[i].1)
+	}
+}
+
+impl<'q, M> Index<&'q str> for MetaObject<M> {
+	type Output = MetaValue<M>;
+
+	/// Returns the value associated to the given key.
+	///
+	/// # Panics
+	///
+	/// Panics if there is no such key.
+	fn index(&self, key: &'q str) -> &MetaValue<M> {
+		self.get(key)
+			.unwrap_or_else(|| panic!("no such key `{}`", key))
+	}
+}
+
+impl<'q, M> IndexMut<&'q str> for MetaObject<M> {
+	/// Returns the value associated to the given key.
+	///
+	/// # Panics
+	///
+	/// Panics if there is no such key.
+	fn index_mut(&mut self, key: &'q str) -> &mut MetaValue<M> {
+		self.get_mut(key)
+			.unwrap_or_else(|| panic!("no such key `{}`", key))
+	}
+}
+
+impl<M> MapIter for MetaObject<M> {
+	type Iter<'a>
+		= MetaObjectIter<'a, M>
+	where
+		M: 'a;
+
+	fn iter(&self) -> MetaObjectIter<'_, M> {
+		MetaObjectIter(self.entries.iter())
+	}
+}
+
+impl<M> MapIterMut for MetaObject<M> {
+	type IterMut<'a>
+		= MetaObjectIterMut<'a, M>
+	where
+		M: 'a;
+
+	fn iter_mut(&mut self) -> MetaObjectIterMut<'_, M> {
+		MetaObjectIterMut(self.entries.iter_mut())
+	}
+}
+
+impl<M> MapInsert<MetaKey<M>> for MetaObject<M> {
+	type Output = Option<MetaValue<M>>;
+
+	/// Inserts a member at the end of the object,
+	/// or replaces the value of the member with the same key, keeping its position.
+	///
+	/// Returns the replaced value, if any.
+	fn insert(&mut self, key: MetaKey<M>, value: MetaValue<M>) -> Option<MetaValue<M>> {
+		match self.index_of(&key) {
+			Some(i) => {
+				let entry = &mut self.entries[i];
+				entry.0 = key;
+				Some(std::mem::replace(&mut entry.1, value))
+			}
+			None => {
+				self.indexes.insert(key.key.clone(), self.entries.len());
+				self.entries.push((key, value));
+				None
+			}
+		}
+	}
+}
+
+impl<'q, M> Remove<&'q str> for MetaObject<M> {
+	/// Removes the member with the given key,
+	/// preserving the order of the other members.
+	fn remove(&mut self, key: &'q str) -> Option<MetaValue<M>> {
+		let i = self.indexes.remove(key)?;
+		let (_, value) = self.entries.remove(i);
+		for index in self.indexes.values_mut() {
+			if *index > i {
+				*index -= 1
+			}
+		}
+
+		Some(value)
+	}
+}
+
+impl<M> IntoIterator for MetaObject<M> {
+	type Item = (MetaKey<M>, MetaValue<M>);
+	type IntoIter = std::vec::IntoIter<(MetaKey<M>, MetaValue<M>)>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.entries.into_iter()
+	}
+}
+
+/// Members with duplicate keys replace the previous ones, at their position.
+impl<M> FromIterator<(MetaKey<M>, MetaValue<M>)> for MetaObject<M> {
+	fn from_iter<I: IntoIterator<Item = (MetaKey<M>, MetaValue<M>)>>(iter: I) -> Self {
+		let mut object = Self::new();
+		for (key, value) in iter {
+			object.insert(key, value);
+		}
+
+		object
+	}
+}
+
+/// Iterator over the members of a [`MetaObject`].
+pub struct MetaObjectIter<'a, M>(std::slice::Iter<'a, (MetaKey<M>, MetaValue<M>)>);
+
+impl<'a, M> Iterator for MetaObjectIter<'a, M> {
+	type Item = (&'a MetaKey<M>, &'a MetaValue<M>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|(key, value)| (key, value))
+	}
+}
+
+/// Iterator over the members of a [`MetaObject`], with mutable values.
+pub struct MetaObjectIterMut<'a, M>(std::slice::IterMut<'a, (MetaKey<M>, MetaValue<M>)>);
+
+impl<'a, M> Iterator for MetaObjectIterMut<'a, M> {
+	type Item = (&'a MetaKey<M>, &'a mut MetaValue<M>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|(key, value)| (&*key, value))
+	}
+}
+
+impl<M: Clone + Send + Sync> Json for MetaValue<M> {
+	type MetaData = M;
+	type Number = NumberBuf;
+	type String = String;
+	type Array = Vec<Self>;
+	type Key = MetaKey<M>;
+	type Object = MetaObject<M>;
+
+	/// Returns a reference to the actual JSON value (without the metadata).
+	fn as_value_ref(&self) -> ValueRef<'_, Self> {
+		self.into()
+	}
+
+	/// Returns a mutable reference to the actual JSON value (without the metadata).
+	fn as_value_mut(&mut self) -> ValueMut<'_, Self> {
+		self.into()
+	}
+
+	/// Transforms this JSON value into a `Value` and `MetaData`.
+	fn into_parts(self) -> (Value<Self>, Self::MetaData) {
+		let value = match self.value {
+			Meta::Null => Value::Null,
+			Meta::Boolean(b) => Value::Boolean(b),
+			Meta::Number(n) => Value::Number(n),
+			Meta::String(s) => Value::String(s),
+			Meta::Array(a) => Value::Array(a),
+			Meta::Object(o) => Value::Object(o),
+		};
+
+		(value, self.metadata)
+	}
+
+	/// Returns a reference to the metadata associated to the JSON value.
+	fn metadata(&self) -> &Self::MetaData {
+		&self.metadata
+	}
+
+	/// Returns a pair containing a mutable reference to the JSON value and a reference to its metadata.
+	fn as_pair_mut(&mut self) -> (ValueMut<'_, Self>, &Self::MetaData) {
+		let value = match &mut self.value {
+			Meta::Null => ValueMut::Null,
+			Meta::Boolean(b) => ValueMut::Boolean(*b),
+			Meta::Number(n) => ValueMut::Number(n),
+			Meta::String(s) => ValueMut::String(s),
+			Meta::Array(a) => ValueMut::Array(a),
+			Meta::Object(o) => ValueMut::Object(o),
+		};
+
+		(value, &self.metadata)
+	}
+}
+
+impl<M: Clone + Send + Sync> JsonNew for MetaValue<M> {
+	fn new(value: Value<Self>, metadata: M) -> Self {
+		let value = match value {
+			Value::Null => Meta::Null,
+			Value::Boolean(b) => Meta::Boolean(b),
+			Value::Number(n) => Meta::Number(n),
+			Value::String(s) => Meta::String(s),
+			Value::Array(a) => Meta::Array(a),
+			Value::Object(o) => Meta::Object(o),
+		};
+
+		Self { value, metadata }
+	}
+
+	fn new_key(key: &str, metadata: M) -> MetaKey<M> {
+		MetaKey::new(key.to_string(), metadata)
+	}
+}
+
+impl<'a, M: Clone + Send + Sync> From<&'a MetaValue<M>> for ValueRef<'a, MetaValue<M>> {
+	fn from(value: &'a MetaValue<M>) -> Self {
+		match &value.value {
+			Meta::Null => ValueRef::Null,
+			Meta::Boolean(b) => ValueRef::Boolean(*b),
+			Meta::Number(n) => ValueRef::Number(n),
+			Meta::String(s) => ValueRef::String(s),
+			Meta::Array(a) => ValueRef::Array(a),
+			Meta::Object(o) => ValueRef::Object(o),
+		}
+	}
+}
+
+impl<'a, M: Clone + Send + Sync> From<&'a mut MetaValue<M>> for ValueMut<'a, MetaValue<M>> {
+	fn from(value: &'a mut MetaValue<M>) -> Self {
+		value.as_pair_mut().0
+	}
+}
+
+#[cfg(feature = "serde_json-impl")]
+impl<M: Clone + Send + Sync> crate::AsSerdeJson for MetaValue<M> {}
+
+impl<M: Clone + Send + Sync> Index<usize> for MetaValue<M> {
+	type Output = Self;
+
+	/// Returns the element of the array at the given index.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an array, or if the index is out of bounds.
+	/// See [`ValueRef::get_index`] for a non-panicking alternative.
+	fn index(&self, i: usize) -> &Self {
+		match &self.value {
+			Meta::Array(a) => a
+				.get(i)
+				.unwrap_or_else(|| panic!("index {} out of bounds", i)),
+			_ => panic!("cannot index {} with an integer", self.kind()),
+		}
+	}
+}
+
+impl<'q, M: Clone + Send + Sync> Index<&'q str> for MetaValue<M> {
+	type Output = Self;
+
+	/// Returns the value associated to the given key in the object.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an object, or if there is no such key.
+	/// See [`ValueRef::get_key`] for a non-panicking alternative.
+	fn index(&self, key: &'q str) -> &Self {
+		match &self.value {
+			Meta::Object(o) => &o[key],
+			_ => panic!("cannot index {} with a string", self.kind()),
+		}
+	}
+}
+
+impl<M: Clone + Send + Sync> IndexMut<usize> for MetaValue<M> {
+	/// Returns the element of the array at the given index.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an array, or if the index is out of bounds.
+	/// See [`ValueMut::get_mut_index`] for a non-panicking alternative.
+	fn index_mut(&mut self, i: usize) -> &mut Self {
+		let kind = self.kind();
+		match &mut self.value {
+			Meta::Array(a) => a
+				.get_mut(i)
+				.unwrap_or_else(|| panic!("index {} out of bounds", i)),
+			_ => panic!("cannot index {} with an integer", kind),
+		}
+	}
+}
+
+impl<'q, M: Clone + Send + Sync> IndexMut<&'q str> for MetaValue<M> {
+	/// Returns the value associated to the given key in the object.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an object, or if there is no such key.
+	/// See [`ValueMut::get_mut_key`] for a non-panicking alternative.
+	fn index_mut(&mut self, key: &'q str) -> &mut Self {
+		let kind = self.kind();
+		match &mut self.value {
+			Meta::Object(o) => &mut o[key],
+			_ => panic!("cannot index {} with a string", kind),
+		}
+	}
+}
+
+/// Collects the values into an array, with the default metadata.
+impl<M: Default> FromIterator<MetaValue<M>> for MetaValue<M> {
+	fn from_iter<I: IntoIterator<Item = MetaValue<M>>>(iter: I) -> Self {
+		Self {
+			value: Meta::Array(iter.into_iter().collect()),
+			metadata: M::default(),
+		}
+	}
+}
+
+/// Collects the members into an object, with the default metadata
+/// for the object and its keys.
+///
+/// Members with duplicate keys replace the previous ones, at their position.
+impl<M: Default> FromIterator<(String, MetaValue<M>)> for MetaValue<M> {
+	fn from_iter<I: IntoIterator<Item = (String, MetaValue<M>)>>(iter: I) -> Self {
+		Self {
+			value: Meta::Object(
+				iter.into_iter()
+					.map(|(key, value)| (MetaKey::new(key, M::default()), value))
+					.collect(),
+			),
+			metadata: M::default(),
+		}
+	}
+}
+
+/// Appends the values to the array.
+///
+/// # Panics
+///
+/// Panics if this value is not an array.
+impl<M> Extend<MetaValue<M>> for MetaValue<M> {
+	fn extend<I: IntoIterator<Item = MetaValue<M>>>(&mut self, iter: I) {
+		match &mut self.value {
+			Meta::Array(a) => a.extend(iter),
+			_ => panic!("cannot extend a non-array value with elements"),
+		}
+	}
+}
+
+/// Inserts the members into the object, with the default metadata for their keys.
+///
+/// Members with a key already present replace the previous ones, at their position.
+///
+/// # Panics
+///
+/// Panics if this value is not an object.
+impl<M: Default> Extend<(String, MetaValue<M>)> for MetaValue<M> {
+	fn extend<I: IntoIterator<Item = (String, MetaValue<M>)>>(&mut self, iter: I) {
+		match &mut self.value {
+			Meta::Object(o) => {
+				for (key, value) in iter {
+					o.insert(MetaKey::new(key, M::default()), value);
+				}
+			}
+			_ => panic!("cannot extend a non-object value with members"),
+		}
+	}
+}
@@ -0,0 +1,40 @@
+use crate::{Json, NumberFrom, ValueRef};
+use cc_traits::{Iter, MapIter};
+
+/// Returns the integer equal to the given number,
+/// if it is an integral floating point number such as `2.0` or `1e3`.
+///
+/// Returns `None` if the number is already an integer, is not integral,
+/// or does not fit in an `i64` or `u64`.
+pub(crate) fn integral_float<N: NumberFrom>(n: &N) -> Option<N> {
+	if n.as_i64().is_some() || n.as_u64().is_some() {
+		return None;
+	}
+
+	let f = n.as_f64()?;
+	if f.fract() != 0.0 {
+		None
+	} else if (-9223372036854775808.0..9223372036854775808.0).contains(&f) {
+		Some(N::from_i64(f as i64))
+	} else if (0.0..18446744073709551616.0).contains(&f) {
+		Some(N::from_u64(f as u64))
+	} else {
+		None
+	}
+}
+
+/// Checks that the given value contains no `null` object member
+/// and no integral floating point number.
+pub(crate) fn is_normalized<T: Json>(value: ValueRef<T>) -> bool
+where
+	T::Number: NumberFrom,
+{
+	match value {
+		ValueRef::Number(n) => integral_float(n).is_none(),
+		ValueRef::Array(a) => Iter::iter(a).all(|item| is_normalized(item.as_value_ref())),
+		ValueRef::Object(o) => {
+			MapIter::iter(o).all(|(_, item)| !item.is_null() && is_normalized(item.as_value_ref()))
+		}
+		_ => true,
+	}
+}
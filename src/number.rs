@@ -1,3 +1,6 @@
+use crate::{Json, Value};
+use std::{cmp::Ordering, fmt, str::FromStr};
+
 /// JSON number.
 pub trait Number: Eq {
 	/// Returns this number as an `u32` if it can be exactly represented as such.
@@ -23,8 +26,215 @@ pub trait Number: Eq {
 
 	/// Returns this number as an `f64`, potentially losing precision in the process.
 	fn as_f64_lossy(&self) -> f64;
+
+	/// Returns this number as an `f64`,
+	/// or an error if the nearest `f64` does not designate the same integer or decimal number.
+	///
+	/// Unlike [`as_f64`](Self::as_f64), this checks that an integer
+	/// is not rounded by the conversion.
+	/// The default implementation relies on [`as_f64`](Self::as_f64) for non-integer numbers.
+	fn as_f64_checked(&self) -> Result<f64, PrecisionLost>
+	where
+		Self: Sized,
+	{
+		let f = self.as_f64_lossy();
+		let exact = match as_i128(self) {
+			Some(i) => f as i128 == i,
+			None => self.as_f64().is_some(),
+		};
+
+		if exact {
+			Ok(f)
+		} else {
+			Err(PrecisionLost {
+				lexical: to_decimal_string(self),
+				nearest: f,
+			})
+		}
+	}
+
+	/// Returns the lexical representation of this number,
+	/// if the backend stores it.
+	///
+	/// This allows numbers that cannot be represented as an `i64`, `u64` or `f64`
+	/// to be re-emitted without losing precision.
+	/// The default implementation returns `None`.
+	fn as_str(&self) -> Option<&str> {
+		None
+	}
+
+	/// Checks if this number is lower than or equal to `bound`.
+	///
+	/// The comparison is exact when the number is an integer
+	/// representable as an `i64` or `u64`,
+	/// and goes through [`as_f64_lossy`](Self::as_f64_lossy) otherwise.
+	fn le_i64(&self, bound: i64) -> bool
+	where
+		Self: Sized,
+	{
+		match as_i128(self) {
+			Some(i) => i <= bound as i128,
+			None => self.as_f64_lossy() <= bound as f64,
+		}
+	}
+
+	/// Checks if this number is greater than or equal to `bound`.
+	///
+	/// The comparison is exact when the number is an integer
+	/// representable as an `i64` or `u64`,
+	/// and goes through [`as_f64_lossy`](Self::as_f64_lossy) otherwise.
+	fn ge_i64(&self, bound: i64) -> bool
+	where
+		Self: Sized,
+	{
+		match as_i128(self) {
+			Some(i) => i >= bound as i128,
+			None => self.as_f64_lossy() >= bound as f64,
+		}
+	}
+
+	/// Checks if this number is equal to `other`, possibly of another type, by value.
+	///
+	/// Unlike `==`, this does not depend on the representation of the numbers:
+	/// `1` and `1.0` are equal.
+	/// Integers representable as an `i64` or `u64` are compared exactly,
+	/// and other numbers through [`as_f64_lossy`](Self::as_f64_lossy).
+	///
+	/// ```
+	/// # #[cfg(all(feature = "serde_json-impl", feature = "ijson-impl"))]
+	/// # {
+	/// use generic_json::Number;
+	/// use std::convert::TryFrom;
+	///
+	/// let a = serde_json::Number::from(1);
+	/// let b = ijson::INumber::try_from(1.0).unwrap();
+	/// assert!(a != serde_json::Number::from_f64(1.0).unwrap());
+	/// assert!(a.numeric_eq(&b));
+	/// assert!(b.numeric_eq(&a));
+	/// assert!(!a.numeric_eq(&ijson::INumber::try_from(1.5).unwrap()));
+	/// # }
+	/// ```
+	fn numeric_eq(&self, other: &impl Number) -> bool
+	where
+		Self: Sized,
+	{
+		numeric_eq(self, other)
+	}
+
+	/// Compares this number with `other`, following a total order.
+	///
+	/// Unlike `PartialOrd`, this is defined for every pair of numbers
+	/// (including `NaN` for backends that can hold it),
+	/// so that numbers can be sorted consistently.
+	/// The default implementation compares the numbers through
+	/// [`as_f64_lossy`](Self::as_f64_lossy) using [`f64::total_cmp`],
+	/// except that `-0.0` and `0.0` are equal.
+	/// Numbers that only differ beyond the precision of an `f64` are equal.
+	fn total_cmp(&self, other: &Self) -> Ordering {
+		let a = self.as_f64_lossy();
+		let b = other.as_f64_lossy();
+		if a == b {
+			Ordering::Equal
+		} else {
+			a.total_cmp(&b)
+		}
+	}
+
+	/// Checks if this number is represented as an integer.
+	///
+	/// The default implementation checks that the number
+	/// can be represented as an `i64` or `u64`.
+	fn is_integer(&self) -> bool {
+		self.as_i64().is_some() || self.as_u64().is_some()
+	}
+
+	/// Checks if this number is strictly negative.
+	///
+	/// The default implementation relies on [`as_f64_lossy`](Self::as_f64_lossy).
+	fn is_negative(&self) -> bool {
+		self.as_f64_lossy() < 0.0
+	}
+
+	/// Checks if this number is neither infinite nor `NaN`.
+	///
+	/// The default implementation relies on [`as_f64_lossy`](Self::as_f64_lossy).
+	fn is_finite(&self) -> bool {
+		self.as_f64_lossy().is_finite()
+	}
 }
 
+/// JSON number that can be built from a primitive number
+/// or from its lexical representation.
+///
+/// Required by [`JsonBuild`](crate::JsonBuild) so that generic code
+/// can create numbers of any backend.
+pub trait NumberFrom: Number + Sized {
+	/// Creates a number from an `u64`.
+	fn from_u64(u: u64) -> Self;
+
+	/// Creates a number from an `i64`.
+	fn from_i64(i: i64) -> Self;
+
+	/// Creates a number from an `f64`.
+	///
+	/// Returns `None` if the number is not finite,
+	/// since `NaN` and infinities are not valid JSON numbers.
+	/// Generic code building values from floats should report the failure
+	/// (or fall back to `null`), as `JsonBuild` offers no infallible path.
+	fn from_f64(f: f64) -> Option<Self>;
+
+	/// Creates a number from its lexical representation.
+	///
+	/// `s` is expected to be a valid JSON number.
+	/// Returns `None` if the number cannot be represented.
+	///
+	/// The default implementation goes through an `i64`, an `u64`,
+	/// or the nearest `f64`.
+	/// Number types storing arbitrary precision numbers should override it
+	/// to keep the number exactly.
+	fn from_lexical(s: &str) -> Option<Self> {
+		if let Ok(i) = s.parse() {
+			Some(Self::from_i64(i))
+		} else if let Ok(u) = s.parse() {
+			Some(Self::from_u64(u))
+		} else {
+			Self::from_f64(s.parse().ok()?)
+		}
+	}
+}
+
+/// Error returned when a number cannot be converted into an `f64`
+/// without losing precision.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PrecisionLost {
+	lexical: String,
+	nearest: f64,
+}
+
+impl PrecisionLost {
+	/// Returns the lexical representation of the original number.
+	pub fn lexical(&self) -> &str {
+		&self.lexical
+	}
+
+	/// Returns the nearest `f64` to the original number.
+	pub fn nearest(&self) -> f64 {
+		self.nearest
+	}
+}
+
+impl fmt::Display for PrecisionLost {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"`{}` cannot be represented as an `f64` without losing precision",
+			self.lexical
+		)
+	}
+}
+
+impl std::error::Error for PrecisionLost {}
+
 /// Zero number.
 ///
 /// This is a dummy number type that can only represent the value `0.0`.
@@ -63,4 +273,399 @@ impl Number for Zero {
 	fn as_f64_lossy(&self) -> f64 {
 		0.0
 	}
+
+	fn is_integer(&self) -> bool {
+		true
+	}
+
+	fn is_negative(&self) -> bool {
+		false
+	}
+
+	fn is_finite(&self) -> bool {
+		true
+	}
+}
+
+/// Owned number stored in its lexical (decimal) representation.
+///
+/// The number is converted on demand.
+/// Two numbers are equal if they have the same lexical representation.
+/// Numbers are ordered by value (see [`Number::total_cmp`]),
+/// and numbers of equal value by lexical representation.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NumberBuf(String);
+
+impl PartialOrd for NumberBuf {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for NumberBuf {
+	fn cmp(&self, other: &Self) -> Ordering {
+		Number::total_cmp(self, other).then_with(|| self.0.cmp(&other.0))
+	}
+}
+
+impl NumberBuf {
+	/// Creates a number from its lexical representation.
+	///
+	/// Returns the input string back if it is not a valid JSON number.
+	pub fn new(s: String) -> Result<Self, String> {
+		if is_valid(&s) {
+			Ok(Self(s))
+		} else {
+			Err(s)
+		}
+	}
+
+	/// Creates a number from an `f64`.
+	///
+	/// Returns `None` if the number is not finite.
+	pub fn from_f64(f: f64) -> Option<Self> {
+		if f.is_finite() {
+			Some(Self(format!("{:?}", f)))
+		} else {
+			None
+		}
+	}
+
+	/// Returns the lexical representation of the number.
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	/// Turns this number into its lexical representation.
+	pub fn into_string(self) -> String {
+		self.0
+	}
+}
+
+macro_rules! number_buf_from_int {
+	($($ty:ty),*) => {
+		$(
+			impl From<$ty> for NumberBuf {
+				fn from(n: $ty) -> Self {
+					Self(n.to_string())
+				}
+			}
+		)*
+	};
+}
+
+number_buf_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl NumberFrom for NumberBuf {
+	fn from_u64(u: u64) -> Self {
+		u.into()
+	}
+
+	fn from_i64(i: i64) -> Self {
+		i.into()
+	}
+
+	fn from_f64(f: f64) -> Option<Self> {
+		Self::from_f64(f)
+	}
+
+	fn from_lexical(s: &str) -> Option<Self> {
+		Self::new(s.to_string()).ok()
+	}
+}
+
+impl FromStr for NumberBuf {
+	type Err = String;
+
+	/// Parses a number from its lexical representation.
+	///
+	/// Returns the input string back if it is not a valid JSON number.
+	fn from_str(s: &str) -> Result<Self, String> {
+		Self::new(s.to_string())
+	}
+}
+
+impl fmt::Display for NumberBuf {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl Number for NumberBuf {
+	fn as_u32(&self) -> Option<u32> {
+		self.0.parse().ok()
+	}
+
+	fn as_u64(&self) -> Option<u64> {
+		self.0.parse().ok()
+	}
+
+	fn as_i32(&self) -> Option<i32> {
+		self.0.parse().ok()
+	}
+
+	fn as_i64(&self) -> Option<i64> {
+		self.0.parse().ok()
+	}
+
+	fn as_f32(&self) -> Option<f32> {
+		lexical_as_f32(&self.0)
+	}
+
+	fn as_f32_lossy(&self) -> f32 {
+		lexical_as_f64_lossy(&self.0) as f32
+	}
+
+	fn as_f64(&self) -> Option<f64> {
+		lexical_as_f64(&self.0)
+	}
+
+	fn as_f64_lossy(&self) -> f64 {
+		lexical_as_f64_lossy(&self.0)
+	}
+
+	fn as_f64_checked(&self) -> Result<f64, PrecisionLost> {
+		lexical_as_f64_checked(&self.0)
+	}
+
+	fn as_str(&self) -> Option<&str> {
+		Some(&self.0)
+	}
+
+	fn is_integer(&self) -> bool {
+		lexical_is_integer(&self.0)
+	}
+
+	fn is_negative(&self) -> bool {
+		lexical_is_negative(&self.0)
+	}
+
+	fn is_finite(&self) -> bool {
+		true
+	}
+}
+
+/// Checks if a valid lexical number is an integer,
+/// without fractional part nor exponent.
+pub(crate) fn lexical_is_integer(s: &str) -> bool {
+	!s.contains(['.', 'e', 'E'])
+}
+
+/// Checks if a valid lexical number is strictly negative.
+pub(crate) fn lexical_is_negative(s: &str) -> bool {
+	let mantissa = s.split(['e', 'E']).next().unwrap_or(s);
+	s.starts_with('-') && mantissa.contains(['1', '2', '3', '4', '5', '6', '7', '8', '9'])
+}
+
+/// Converts a valid lexical number into an `f64`, potentially losing precision.
+pub(crate) fn lexical_as_f64_lossy(s: &str) -> f64 {
+	s.parse().unwrap()
+}
+
+/// Converts a valid lexical number into an `f64`.
+///
+/// Returns `None` if the number is out of the `f64` range.
+pub(crate) fn lexical_as_f64(s: &str) -> Option<f64> {
+	let f = lexical_as_f64_lossy(s);
+	if f.is_finite() {
+		Some(f)
+	} else {
+		None
+	}
+}
+
+/// Converts a valid lexical number into an `f64`,
+/// checking that the result designates the same decimal number.
+pub(crate) fn lexical_as_f64_checked(s: &str) -> Result<f64, PrecisionLost> {
+	let f = lexical_as_f64_lossy(s);
+	if f.is_finite() && normalize_decimal(s) == normalize_decimal(&format!("{:e}", f)) {
+		Ok(f)
+	} else {
+		Err(PrecisionLost {
+			lexical: s.to_string(),
+			nearest: f,
+		})
+	}
+}
+
+/// Decomposes a valid lexical number into its sign, significant digits and exponent,
+/// such that two representations of the same decimal number give the same result.
+///
+/// Returns `None` if the exponent overflows.
+fn normalize_decimal(s: &str) -> Option<(bool, String, i64)> {
+	let (negative, s) = match s.strip_prefix('-') {
+		Some(s) => (true, s),
+		None => (false, s),
+	};
+
+	let (mantissa, exponent) = match s.find(['e', 'E']) {
+		Some(i) => (&s[..i], s[i + 1..].parse::<i64>().ok()?),
+		None => (s, 0),
+	};
+
+	let (integer, fraction) = match mantissa.find('.') {
+		Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+		None => (mantissa, ""),
+	};
+
+	let digits = format!("{}{}", integer, fraction);
+	let trimmed = digits.trim_start_matches('0');
+	let leading_zeros = digits.len() - trimmed.len();
+	let significant = trimmed.trim_end_matches('0');
+	if significant.is_empty() {
+		return Some((false, String::new(), 0));
+	}
+
+	// Exponent of the first significant digit.
+	let exponent = exponent.checked_add(integer.len() as i64 - leading_zeros as i64)?;
+	Some((negative, significant.to_string(), exponent))
+}
+
+/// Converts a valid lexical number into an `f32`.
+///
+/// Returns `None` if the number is out of the `f32` range,
+/// or cannot be represented as an `f32` as precisely as an `f64`.
+pub(crate) fn lexical_as_f32(s: &str) -> Option<f32> {
+	lexical_as_f64(s).and_then(f64_as_f32)
+}
+
+/// Converts an `f64` into an `f32` if it can be exactly represented as such.
+pub(crate) fn f64_as_f32(f: f64) -> Option<f32> {
+	let g = f as f32;
+	if g as f64 == f {
+		Some(g)
+	} else {
+		None
+	}
+}
+
+/// Returns the decimal representation of the given number.
+///
+/// Integers are represented exactly.
+/// Other numbers are represented using the shortest decimal representation
+/// of their (potentially lossy) `f64` conversion.
+pub(crate) fn to_decimal_string<N: Number>(n: &N) -> String {
+	match n.as_i64() {
+		Some(i) => i.to_string(),
+		None => match n.as_u64() {
+			Some(u) => u.to_string(),
+			None => n.as_f64_lossy().to_string(),
+		},
+	}
+}
+
+/// Returns the JSON representation of the given number.
+///
+/// The lexical representation of the number is used if available
+/// (see [`Number::as_str`]).
+/// Otherwise integers are represented exactly, and other numbers using
+/// the shortest representation of their `f64` conversion.
+///
+/// Returns `None` if the number has no lexical representation and is not finite.
+pub(crate) fn to_json_string<N: Number>(n: &N) -> Option<String> {
+	match (n.as_str(), n.as_i64(), n.as_u64()) {
+		(Some(s), _, _) => Some(s.to_string()),
+		(None, Some(i), _) => Some(i.to_string()),
+		(None, None, Some(u)) => Some(u.to_string()),
+		(None, None, None) => {
+			let f = n.as_f64_lossy();
+			if f.is_finite() {
+				Some(format!("{:?}", f))
+			} else {
+				None
+			}
+		}
+	}
+}
+
+/// Returns the given number as an `i128` if it is an integer
+/// that can be exactly represented as an `i64` or `u64`.
+pub(crate) fn as_i128<N: Number>(n: &N) -> Option<i128> {
+	n.as_i64()
+		.map(i128::from)
+		.or_else(|| n.as_u64().map(i128::from))
+}
+
+/// Compares two numbers, possibly of different types, by value.
+///
+/// Integers are compared exactly.
+/// An integer and a non-integer number are equal if the latter
+/// has no fractional part and designates the same integer.
+/// Other numbers are compared through their (potentially lossy) `f64` conversion.
+pub(crate) fn numeric_eq<A: Number, B: Number>(a: &A, b: &B) -> bool {
+	fn int_float_eq(i: i128, f: f64) -> bool {
+		f.fract() == 0.0 && f >= i128::MIN as f64 && f < i128::MAX as f64 && f as i128 == i
+	}
+
+	match (as_i128(a), as_i128(b)) {
+		(Some(a), Some(b)) => a == b,
+		(Some(a), None) => int_float_eq(a, b.as_f64_lossy()),
+		(None, Some(b)) => int_float_eq(b, a.as_f64_lossy()),
+		(None, None) => a.as_f64_lossy() == b.as_f64_lossy(),
+	}
+}
+
+/// Checks that the given string is a valid JSON number,
+/// following the grammar of [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259#section-6).
+pub fn is_valid(s: &str) -> bool {
+	let bytes = s.as_bytes();
+	let mut i = 0;
+
+	let digits = |i: &mut usize| {
+		let start = *i;
+		while *i < bytes.len() && bytes[*i].is_ascii_digit() {
+			*i += 1
+		}
+		*i - start
+	};
+
+	if bytes.get(i) == Some(&b'-') {
+		i += 1
+	}
+
+	match bytes.get(i) {
+		Some(b'0') => i += 1,
+		Some(b'1'..=b'9') => {
+			digits(&mut i);
+		}
+		_ => return false,
+	}
+
+	if bytes.get(i) == Some(&b'.') {
+		i += 1;
+		if digits(&mut i) == 0 {
+			return false;
+		}
+	}
+
+	if matches!(bytes.get(i), Some(b'e' | b'E')) {
+		i += 1;
+		if matches!(bytes.get(i), Some(b'+' | b'-')) {
+			i += 1
+		}
+
+		if digits(&mut i) == 0 {
+			return false;
+		}
+	}
+
+	i == bytes.len()
+}
+
+/// Creates a number value from its decimal representation.
+///
+/// Returns `None` if `s` is not a valid JSON number,
+/// or if the number cannot be represented by the backend.
+/// The conversion is lossless as long as the backend number type
+/// stores it losslessly (see [`NumberFrom::from_lexical`]), for instance
+/// [`NumberBuf`] or `serde_json` with the `arbitrary_precision` feature.
+pub fn number_from_str<T: Json>(s: &str) -> Option<Value<T>>
+where
+	T::Number: NumberFrom,
+{
+	if is_valid(s) {
+		T::Number::from_lexical(s).map(Value::Number)
+	} else {
+		None
+	}
 }
@@ -0,0 +1,97 @@
+use std::fmt;
+
+#[cfg(feature = "nightly")]
+use crate::{strip::convert_number, Json, JsonBuild, Key, Value, ValueRef};
+#[cfg(feature = "nightly")]
+use cc_traits::{Get, Iter, Len, MapIter};
+
+/// Error returned by [`Json::pairs_to_object`](crate::Json::pairs_to_object).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PairsError {
+	/// The value is not an array.
+	NotAnArray,
+
+	/// The element at the given index is not a two-element array.
+	NotAPair(usize),
+
+	/// The first element of the pair at the given index is not a string.
+	NonStringKey(usize),
+
+	/// The value of the pair at the given index contains a number
+	/// that cannot be represented by the target type.
+	InvalidNumber(usize),
+}
+
+impl fmt::Display for PairsError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::NotAnArray => write!(f, "not an array"),
+			Self::NotAPair(i) => write!(f, "element {} is not a pair", i),
+			Self::NonStringKey(i) => write!(f, "key of pair {} is not a string", i),
+			Self::InvalidNumber(i) => write!(f, "pair {} contains an invalid number", i),
+		}
+	}
+}
+
+impl std::error::Error for PairsError {}
+
+/// Converts a value into another JSON type, keeping its metadata.
+///
+/// Numbers are converted exactly when both types can represent them,
+/// and through the nearest `f64` otherwise.
+/// Returns `None` if a number cannot be represented by the target type.
+#[cfg(feature = "nightly")]
+pub(crate) fn convert<S: Json, T>(value: &S) -> Option<T>
+where
+	T: JsonBuild<MetaData = S::MetaData>,
+{
+	let (value, metadata) = value.as_pair();
+	let value = match value {
+		ValueRef::Null => Value::Null,
+		ValueRef::Boolean(b) => Value::Boolean(b),
+		ValueRef::Number(n) => Value::Number(convert_number(n)?),
+		ValueRef::String(s) => Value::String((**s).into()),
+		ValueRef::Array(a) => Value::Array(
+			Iter::iter(a)
+				.map(|item| convert(&*item))
+				.collect::<Option<_>>()?,
+		),
+		ValueRef::Object(o) => Value::Object(
+			MapIter::iter(o)
+				.map(|(key, item)| {
+					let key = T::new_key(&key, key.metadata().clone());
+					Some((key, convert(&*item)?))
+				})
+				.collect::<Option<_>>()?,
+		),
+	};
+
+	Some(T::new(value, metadata.clone()))
+}
+
+#[cfg(feature = "nightly")]
+pub(crate) fn pairs_to_object<S: Json, T>(value: &S) -> Result<T, PairsError>
+where
+	T: JsonBuild<MetaData = S::MetaData>,
+{
+	let a = value.as_array().ok_or(PairsError::NotAnArray)?;
+	let object = Iter::iter(a)
+		.enumerate()
+		.map(|(i, pair)| {
+			let pair = match pair.as_array() {
+				Some(pair) if pair.len() == 2 => pair,
+				_ => return Err(PairsError::NotAPair(i)),
+			};
+
+			let key = pair.get(0).unwrap();
+			let key = T::new_key(
+				key.as_str().ok_or(PairsError::NonStringKey(i))?,
+				key.metadata().clone(),
+			);
+			let item = convert(&*pair.get(1).unwrap()).ok_or(PairsError::InvalidNumber(i))?;
+			Ok((key, item))
+		})
+		.collect::<Result<_, _>>()?;
+
+	Ok(T::new(Value::Object(object), value.metadata().clone()))
+}
@@ -0,0 +1,561 @@
+use crate::{number, trace, ValueKind};
+#[cfg(feature = "nightly")]
+use crate::{JsonBuild, NumberFrom, Value};
+#[cfg(feature = "nightly")]
+use std::marker::PhantomData;
+use std::{borrow::Cow, fmt};
+
+/// Parsing options.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ParseOptions {
+	/// Maximum nesting depth of arrays and objects.
+	///
+	/// Defaults to 128.
+	pub max_depth: usize,
+
+	/// Maximum number of elements of an array.
+	///
+	/// Unlimited by default.
+	pub max_array_elements: usize,
+
+	/// Maximum number of members of an object.
+	///
+	/// Unlimited by default.
+	pub max_object_members: usize,
+}
+
+impl Default for ParseOptions {
+	fn default() -> Self {
+		Self {
+			max_depth: 128,
+			max_array_elements: usize::MAX,
+			max_object_members: usize::MAX,
+		}
+	}
+}
+
+/// Parse error kind.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ParseErrorKind {
+	/// The input ended unexpectedly.
+	UnexpectedEnd,
+
+	/// Unexpected character.
+	UnexpectedChar(char),
+
+	/// Invalid number, or number that cannot be represented.
+	InvalidNumber,
+
+	/// Invalid string escape sequence.
+	InvalidEscape,
+
+	/// Unescaped control character in a string.
+	ControlCharacter,
+
+	/// String that is not valid UTF-8.
+	InvalidUtf8,
+
+	/// Arrays and objects are nested too deeply.
+	DepthLimitExceeded,
+
+	/// An array has too many elements.
+	ArrayLimitExceeded,
+
+	/// An object has too many members.
+	ObjectLimitExceeded,
+
+	/// Unexpected characters after the parsed value.
+	TrailingCharacters,
+}
+
+impl fmt::Display for ParseErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::UnexpectedEnd => write!(f, "unexpected end of input"),
+			Self::UnexpectedChar(c) => write!(f, "unexpected character `{}`", c.escape_debug()),
+			Self::InvalidNumber => write!(f, "invalid number"),
+			Self::InvalidEscape => write!(f, "invalid escape sequence"),
+			Self::ControlCharacter => write!(f, "unescaped control character in string"),
+			Self::InvalidUtf8 => write!(f, "invalid UTF-8 string"),
+			Self::DepthLimitExceeded => write!(f, "depth limit exceeded"),
+			Self::ArrayLimitExceeded => write!(f, "array elements limit exceeded"),
+			Self::ObjectLimitExceeded => write!(f, "object members limit exceeded"),
+			Self::TrailingCharacters => write!(f, "trailing characters"),
+		}
+	}
+}
+
+/// Parse error.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ParseError {
+	kind: ParseErrorKind,
+	position: usize,
+}
+
+impl ParseError {
+	pub(crate) fn new(kind: ParseErrorKind, position: usize) -> Self {
+		Self { kind, position }
+	}
+
+	/// Returns the kind of error.
+	pub fn kind(&self) -> ParseErrorKind {
+		self.kind
+	}
+
+	/// Returns the byte offset in the input where the error occurred.
+	pub fn position(&self) -> usize {
+		self.position
+	}
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} at byte {}", self.kind, self.position)
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+/// Returns the kind of the top-level value of the given JSON document,
+/// without parsing it.
+///
+/// Only the leading whitespace and the first character of the value are read:
+/// the rest of the document is not validated.
+/// Returns an error if the document is empty (or only contains whitespace),
+/// or if it does not start with a valid value.
+pub fn peek_kind(input: &str) -> Result<ValueKind, ParseError> {
+	let position = input.len() - input.trim_start_matches([' ', '\t', '\n', '\r']).len();
+	let kind = match input[position..].chars().next() {
+		Some('n') => ValueKind::Null,
+		Some('t' | 'f') => ValueKind::Boolean,
+		Some('-' | '0'..='9') => ValueKind::Number,
+		Some('"') => ValueKind::String,
+		Some('[') => ValueKind::Array,
+		Some('{') => ValueKind::Object,
+		Some(c) => return Err(ParseError::new(ParseErrorKind::UnexpectedChar(c), position)),
+		None => return Err(ParseError::new(ParseErrorKind::UnexpectedEnd, position)),
+	};
+
+	Ok(kind)
+}
+
+/// Parses the first JSON value of `input`,
+/// and returns it with the rest of the input.
+///
+/// The whitespace following the value is consumed,
+/// so that the rest of the input starts with the next value, if any.
+/// Every value and key is given the default metadata.
+///
+/// ```
+/// # #[cfg(feature = "serde_json-impl")]
+/// # {
+/// use generic_json::parse_prefix;
+///
+/// let mut input = "1 2\n 3";
+/// let mut values = Vec::new();
+/// while !input.is_empty() {
+///     let (value, rest): (serde_json::Value, _) = parse_prefix(input).unwrap();
+///     values.push(value);
+///     input = rest
+/// }
+///
+/// assert_eq!(values, [1, 2, 3]);
+/// # }
+/// ```
+#[cfg(feature = "nightly")]
+pub fn parse_prefix<T>(input: &str) -> Result<(T, &str), ParseError>
+where
+	T: JsonBuild,
+	T::MetaData: Default,
+{
+	Parser::new(input, ParseOptions::default()).parse_prefix(&mut JsonBuilder(PhantomData))
+}
+
+/// Builds values from the components found by the [`Parser`].
+pub(crate) trait Builder<'a> {
+	/// Built value type.
+	type Value;
+
+	fn null(&mut self) -> Self::Value;
+
+	fn boolean(&mut self, b: bool) -> Self::Value;
+
+	/// Builds a number from its (valid) lexical representation.
+	///
+	/// Returns `None` if the number cannot be represented.
+	fn number(&mut self, n: &'a str) -> Option<Self::Value>;
+
+	fn string(&mut self, s: Cow<'a, str>) -> Self::Value;
+
+	fn array(&mut self, items: Vec<Self::Value>) -> Self::Value;
+
+	fn object(&mut self, entries: Vec<(Cow<'a, str>, Self::Value)>) -> Self::Value;
+}
+
+/// Builder of values of type `T`, with the default metadata.
+#[cfg(feature = "nightly")]
+struct JsonBuilder<T>(PhantomData<T>);
+
+#[cfg(feature = "nightly")]
+impl<'a, T> Builder<'a> for JsonBuilder<T>
+where
+	T: JsonBuild,
+	T::MetaData: Default,
+{
+	type Value = T;
+
+	fn null(&mut self) -> T {
+		T::null(T::MetaData::default())
+	}
+
+	fn boolean(&mut self, b: bool) -> T {
+		T::boolean(b, T::MetaData::default())
+	}
+
+	fn number(&mut self, n: &'a str) -> Option<T> {
+		Some(T::number(
+			T::Number::from_lexical(n)?,
+			T::MetaData::default(),
+		))
+	}
+
+	fn string(&mut self, s: Cow<'a, str>) -> T {
+		T::string((*s).into(), T::MetaData::default())
+	}
+
+	fn array(&mut self, items: Vec<T>) -> T {
+		T::new(
+			Value::Array(items.into_iter().collect()),
+			T::MetaData::default(),
+		)
+	}
+
+	fn object(&mut self, entries: Vec<(Cow<'a, str>, T)>) -> T {
+		T::new(
+			Value::Object(
+				entries
+					.into_iter()
+					.map(|(key, item)| (T::new_key(&key, T::MetaData::default()), item))
+					.collect(),
+			),
+			T::MetaData::default(),
+		)
+	}
+}
+
+/// JSON parser.
+pub(crate) struct Parser<'a> {
+	input: &'a str,
+	position: usize,
+	options: ParseOptions,
+}
+
+impl<'a> Parser<'a> {
+	pub fn new(input: &'a str, options: ParseOptions) -> Self {
+		Self {
+			input,
+			position: 0,
+			options,
+		}
+	}
+
+	fn error(&self, kind: ParseErrorKind) -> ParseError {
+		ParseError {
+			kind,
+			position: self.position,
+		}
+	}
+
+	fn peek(&self) -> Option<u8> {
+		self.input.as_bytes().get(self.position).copied()
+	}
+
+	/// Returns an error about the next character.
+	fn unexpected(&self) -> ParseError {
+		match self.input[self.position..].chars().next() {
+			Some(c) => self.error(ParseErrorKind::UnexpectedChar(c)),
+			None => self.error(ParseErrorKind::UnexpectedEnd),
+		}
+	}
+
+	fn skip_whitespace(&mut self) {
+		while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+			self.position += 1
+		}
+	}
+
+	fn expect(&mut self, b: u8) -> Result<(), ParseError> {
+		if self.peek() == Some(b) {
+			self.position += 1;
+			Ok(())
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	/// Parses the whole input as a single value, surrounded by optional whitespace.
+	pub fn parse_document<B: Builder<'a>>(
+		&mut self,
+		builder: &mut B,
+	) -> Result<B::Value, ParseError> {
+		let _operation = trace::operation!("parse", size = self.input.len());
+		let value = self.parse_value(builder, 0)?;
+		self.skip_whitespace();
+		if self.position < self.input.len() {
+			Err(self.error(ParseErrorKind::TrailingCharacters))
+		} else {
+			Ok(value)
+		}
+	}
+
+	/// Parses the first value of the input, surrounded by optional whitespace,
+	/// and returns it with the rest of the input.
+	#[cfg(feature = "nightly")]
+	pub fn parse_prefix<B: Builder<'a>>(
+		&mut self,
+		builder: &mut B,
+	) -> Result<(B::Value, &'a str), ParseError> {
+		let _operation = trace::operation!("parse", size = self.input.len());
+		let value = self.parse_value(builder, 0)?;
+		self.skip_whitespace();
+		Ok((value, &self.input[self.position..]))
+	}
+
+	/// Parses a value, preceded by optional whitespace.
+	///
+	/// `depth` is the number of arrays and objects enclosing the value.
+	pub fn parse_value<B: Builder<'a>>(
+		&mut self,
+		builder: &mut B,
+		depth: usize,
+	) -> Result<B::Value, ParseError> {
+		self.skip_whitespace();
+		match self.peek() {
+			Some(b'n') => self.parse_literal("null").map(|_| builder.null()),
+			Some(b't') => self.parse_literal("true").map(|_| builder.boolean(true)),
+			Some(b'f') => self.parse_literal("false").map(|_| builder.boolean(false)),
+			Some(b'-' | b'0'..=b'9') => {
+				let start = self.position;
+				let n = self.parse_number()?;
+				builder.number(n).ok_or(ParseError {
+					kind: ParseErrorKind::InvalidNumber,
+					position: start,
+				})
+			}
+			Some(b'"') => self.parse_string().map(|s| builder.string(s)),
+			Some(b'[') => {
+				let depth = self.enter(depth)?;
+				let mut items = Vec::new();
+				self.skip_whitespace();
+				if self.peek() == Some(b']') {
+					self.position += 1
+				} else {
+					loop {
+						self.skip_whitespace();
+						if items.len() == self.options.max_array_elements {
+							return Err(self.error(ParseErrorKind::ArrayLimitExceeded));
+						}
+
+						items.push(self.parse_value(builder, depth)?);
+						self.skip_whitespace();
+						match self.peek() {
+							Some(b',') => self.position += 1,
+							Some(b']') => {
+								self.position += 1;
+								break;
+							}
+							_ => return Err(self.unexpected()),
+						}
+					}
+				}
+
+				Ok(builder.array(items))
+			}
+			Some(b'{') => {
+				let depth = self.enter(depth)?;
+				let mut entries = Vec::new();
+				self.skip_whitespace();
+				if self.peek() == Some(b'}') {
+					self.position += 1
+				} else {
+					loop {
+						self.skip_whitespace();
+						if self.peek() != Some(b'"') {
+							return Err(self.unexpected());
+						}
+
+						if entries.len() == self.options.max_object_members {
+							return Err(self.error(ParseErrorKind::ObjectLimitExceeded));
+						}
+
+						let key = self.parse_string()?;
+						self.skip_whitespace();
+						self.expect(b':')?;
+						entries.push((key, self.parse_value(builder, depth)?));
+						self.skip_whitespace();
+						match self.peek() {
+							Some(b',') => self.position += 1,
+							Some(b'}') => {
+								self.position += 1;
+								break;
+							}
+							_ => return Err(self.unexpected()),
+						}
+					}
+				}
+
+				Ok(builder.object(entries))
+			}
+			_ => Err(self.unexpected()),
+		}
+	}
+
+	/// Enters an array or object, checking the depth limit.
+	fn enter(&mut self, depth: usize) -> Result<usize, ParseError> {
+		if depth >= self.options.max_depth {
+			Err(self.error(ParseErrorKind::DepthLimitExceeded))
+		} else {
+			self.position += 1;
+			Ok(depth + 1)
+		}
+	}
+
+	fn parse_literal(&mut self, literal: &str) -> Result<(), ParseError> {
+		for b in literal.bytes() {
+			self.expect(b)?
+		}
+
+		Ok(())
+	}
+
+	/// Parses a number, returning its lexical representation.
+	fn parse_number(&mut self) -> Result<&'a str, ParseError> {
+		let start = self.position;
+		while matches!(
+			self.peek(),
+			Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')
+		) {
+			self.position += 1
+		}
+
+		let n = &self.input[start..self.position];
+		if number::is_valid(n) {
+			Ok(n)
+		} else {
+			Err(ParseError {
+				kind: ParseErrorKind::InvalidNumber,
+				position: start,
+			})
+		}
+	}
+
+	/// Parses a string, borrowing it from the input if it contains no escape sequence.
+	fn parse_string(&mut self) -> Result<Cow<'a, str>, ParseError> {
+		self.expect(b'"')?;
+		let start = self.position;
+		let mut escaped = false;
+
+		loop {
+			match self.peek() {
+				None => {
+					return Err(ParseError {
+						kind: ParseErrorKind::UnexpectedEnd,
+						position: self.input.len(),
+					})
+				}
+				Some(b'"') => break,
+				Some(b'\\') => {
+					escaped = true;
+					self.position += 2
+				}
+				Some(b) if b < 0x20 => return Err(self.error(ParseErrorKind::ControlCharacter)),
+				Some(_) => self.position += 1,
+			}
+		}
+
+		let raw = &self.input[start..self.position];
+		self.position += 1;
+
+		if escaped {
+			unescape(raw)
+				.map(Cow::Owned)
+				.map_err(|(kind, offset)| ParseError {
+					kind,
+					position: start + offset,
+				})
+		} else {
+			Ok(Cow::Borrowed(raw))
+		}
+	}
+}
+
+/// Decodes the escape sequences of the given string content,
+/// found between quotes.
+///
+/// On error, returns the error kind and the byte offset of the error in `raw`.
+pub(crate) fn unescape(raw: &str) -> Result<String, (ParseErrorKind, usize)> {
+	let bytes = raw.as_bytes();
+	let hex4 = |i: usize| {
+		raw.get(i..i + 4)
+			.filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+			.map(|hex| u16::from_str_radix(hex, 16).unwrap())
+	};
+
+	let mut result = String::with_capacity(raw.len());
+	let mut chunk_start = 0;
+	let mut i = 0;
+	while i < bytes.len() {
+		if bytes[i] != b'\\' {
+			i += 1;
+			continue;
+		}
+
+		result.push_str(&raw[chunk_start..i]);
+		let invalid = (ParseErrorKind::InvalidEscape, i);
+		let c = match bytes.get(i + 1) {
+			Some(b'"') => '"',
+			Some(b'\\') => '\\',
+			Some(b'/') => '/',
+			Some(b'b') => '\u{8}',
+			Some(b'f') => '\u{c}',
+			Some(b'n') => '\n',
+			Some(b'r') => '\r',
+			Some(b't') => '\t',
+			Some(b'u') => {
+				let high = hex4(i + 2).ok_or(invalid)?;
+				let code_point = match high {
+					0xd800..=0xdbff => {
+						let low = if raw[i + 6..].starts_with("\\u") {
+							hex4(i + 8)
+						} else {
+							None
+						};
+
+						match low {
+							Some(low @ 0xdc00..=0xdfff) => {
+								i += 6;
+								let high = (high as u32 - 0xd800) << 10;
+								0x10000 + high + (low as u32 - 0xdc00)
+							}
+							_ => return Err(invalid),
+						}
+					}
+					0xdc00..=0xdfff => return Err(invalid),
+					_ => high as u32,
+				};
+
+				i += 4;
+				// The code point is not a surrogate.
+				char::from_u32(code_point).unwrap()
+			}
+			_ => return Err(invalid),
+		};
+
+		result.push(c);
+		i += 2;
+		chunk_start = i
+	}
+
+	result.push_str(&raw[chunk_start..]);
+	Ok(result)
+}
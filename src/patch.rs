@@ -0,0 +1,474 @@
+use crate::{
+	compare, pointer, strip::to_default_value, CompareOptions, Json, JsonBuild, JsonClone, JsonMut,
+	PointerError, Value, ValueMut, ValueRef,
+};
+use cc_traits::{Get, GetMut, IterMut, Len, MapInsert, MapIter, PopBack, PushBack, Remove};
+use std::fmt;
+
+/// JSON Patch operation, as defined by [RFC 6902](https://www.rfc-editor.org/rfc/rfc6902).
+///
+/// Paths are JSON Pointers.
+pub enum PatchOp<T: Json> {
+	/// Adds a value to an object, inserts it into an array,
+	/// or replaces the whole document.
+	Add { path: String, value: Value<T> },
+
+	/// Removes the designated value.
+	Remove { path: String },
+
+	/// Replaces the designated value.
+	Replace { path: String, value: Value<T> },
+
+	/// Removes the value at `from` and adds it at `path`.
+	Move { from: String, path: String },
+
+	/// Copies the value at `from` and adds it at `path`.
+	Copy { from: String, path: String },
+
+	/// Checks that the designated value is equal to the given value.
+	///
+	/// Numbers are compared by value, and objects regardless of
+	/// the order of their members.
+	Test { path: String, value: Value<T> },
+}
+
+/// JSON Patch error kind.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PatchErrorKind {
+	/// The path cannot be resolved.
+	///
+	/// The pointer error is the [`source`](std::error::Error::source) of the [`PatchError`].
+	Pointer(PointerError),
+
+	/// The path does not designate an existing value.
+	NotFound(String),
+
+	/// A value cannot be moved into one of its children.
+	MoveIntoChild(String),
+
+	/// The designated value is not equal to the tested value.
+	TestFailed(String),
+}
+
+impl fmt::Display for PatchErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Pointer(e) => write!(f, "cannot resolve `{}`", e.pointer()),
+			Self::NotFound(p) => write!(f, "`{}` not found", p),
+			Self::MoveIntoChild(p) => write!(f, "cannot move `{}` into one of its children", p),
+			Self::TestFailed(p) => write!(f, "test failed on `{}`", p),
+		}
+	}
+}
+
+impl From<PointerError> for PatchErrorKind {
+	fn from(e: PointerError) -> Self {
+		Self::Pointer(e)
+	}
+}
+
+/// JSON Patch error.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatchError {
+	kind: PatchErrorKind,
+	operation: usize,
+}
+
+impl PatchError {
+	/// Returns the kind of error.
+	pub fn kind(&self) -> &PatchErrorKind {
+		&self.kind
+	}
+
+	/// Returns the index of the failing operation in the patch.
+	pub fn operation(&self) -> usize {
+		self.operation
+	}
+}
+
+impl fmt::Display for PatchError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} (operation {})", self.kind, self.operation)
+	}
+}
+
+impl std::error::Error for PatchError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match &self.kind {
+			PatchErrorKind::Pointer(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+/// Splits the given non-empty pointer into its parent pointer
+/// and its (unescaped) last reference token.
+fn split(path: &str) -> Result<(&str, String), PatchErrorKind> {
+	let invalid = || PointerError::Invalid(path.to_string()).into();
+	if !path.starts_with('/') || !path[1..].split('/').all(|t| pointer::unescape(t).is_some()) {
+		return Err(invalid());
+	}
+
+	let i = path.rfind('/').unwrap();
+	Ok((&path[..i], pointer::unescape(&path[i + 1..]).unwrap()))
+}
+
+/// Moves the last element of the given array to the given index,
+/// shifting the following elements.
+fn move_last_to<T: JsonMut>(a: &mut T::Array, i: usize) {
+	let mut items: Vec<_> = a.iter_mut().collect();
+	for j in (i + 1..items.len()).rev() {
+		let (left, right) = items.split_at_mut(j);
+		std::mem::swap(&mut *left[j - 1], &mut *right[0])
+	}
+}
+
+/// Moves the element at the given index to the end of the given array,
+/// shifting the following elements.
+fn move_to_last<T: JsonMut>(a: &mut T::Array, i: usize) {
+	let mut items: Vec<_> = a.iter_mut().collect();
+	for j in i + 1..items.len() {
+		let (left, right) = items.split_at_mut(j);
+		std::mem::swap(&mut *left[j - 1], &mut *right[0])
+	}
+}
+
+/// Adds the given value at the given path.
+///
+/// The value is given back on error, the document being left unchanged.
+fn add<T: JsonMut + JsonBuild>(
+	root: &mut T,
+	path: &str,
+	value: Value<T>,
+) -> Result<(), (PatchErrorKind, Value<T>)> {
+	if path.is_empty() {
+		pointer::replace(root, value);
+		return Ok(());
+	}
+
+	let (parent_path, token) = match split(path) {
+		Ok(split) => split,
+		Err(kind) => return Err((kind, value)),
+	};
+
+	let mut value = Some(value);
+	let result = pointer::with_pointer_mut(root, parent_path, |parent| {
+		let metadata = parent.metadata().clone();
+		match parent.as_value_mut() {
+			ValueMut::Array(a) => {
+				let len = a.len();
+				let index = if token == "-" {
+					Some(len)
+				} else {
+					pointer::parse_index(&token).filter(|i| *i <= len)
+				};
+
+				match index {
+					Some(i) => {
+						a.push_back(T::new(value.take().unwrap(), metadata));
+						move_last_to::<T>(a, i);
+						Ok(())
+					}
+					None => Err(PointerError::InvalidIndex(path.to_string()).into()),
+				}
+			}
+			ValueMut::Object(o) => {
+				o.insert(
+					T::new_key(&token, metadata.clone()),
+					T::new(value.take().unwrap(), metadata),
+				);
+				Ok(())
+			}
+			_ => Err(PointerError::NotAContainer(parent_path.to_string()).into()),
+		}
+	})
+	.unwrap_or_else(|| Err(PointerError::ParentNotFound(parent_path.to_string()).into()));
+
+	result.map_err(|kind| (kind, value.unwrap()))
+}
+
+/// Removes the value at the given path, and returns it.
+fn remove<T: JsonMut>(root: &mut T, path: &str) -> Result<Value<T>, PatchErrorKind> {
+	let (parent_path, token) = split(path)?;
+	pointer::with_pointer_mut(root, parent_path, |parent| match parent.as_value_mut() {
+		ValueMut::Array(a) => {
+			let len = a.len();
+			match pointer::parse_index(&token).filter(|i| *i < len) {
+				Some(i) => {
+					move_to_last::<T>(a, i);
+					Ok(a.pop_back().unwrap().into_value())
+				}
+				None => Err(PointerError::InvalidIndex(path.to_string()).into()),
+			}
+		}
+		ValueMut::Object(o) => o
+			.remove(token.as_str())
+			.map(Json::into_value)
+			.ok_or_else(|| PatchErrorKind::NotFound(path.to_string())),
+		_ => Err(PointerError::NotAContainer(parent_path.to_string()).into()),
+	})
+	.unwrap_or_else(|| Err(PointerError::ParentNotFound(parent_path.to_string()).into()))
+}
+
+/// Returns a copy of the value at the given path.
+fn get<T: JsonClone>(root: &T, path: &str) -> Result<Value<T>, PatchErrorKind> {
+	if !path.is_empty() {
+		split(path)?;
+	}
+
+	pointer::with_pointer(root, path, |target| target.as_value_ref().cloned())
+		.ok_or_else(|| PatchErrorKind::NotFound(path.to_string()))
+}
+
+fn apply<T: JsonMut + JsonBuild + JsonClone>(
+	root: &mut T,
+	op: &PatchOp<T>,
+) -> Result<(), PatchErrorKind> {
+	match op {
+		PatchOp::Add { path, value } => add(root, path, value.clone()).map_err(|(kind, _)| kind),
+		PatchOp::Remove { path } => remove(root, path).map(|_| ()),
+		PatchOp::Replace { path, value } => {
+			if !path.is_empty() {
+				split(path)?;
+			}
+
+			pointer::with_pointer_mut(root, path, |target| {
+				pointer::replace(target, value.clone());
+			})
+			.ok_or_else(|| PatchErrorKind::NotFound(path.to_string()))
+		}
+		PatchOp::Move { from, path } => {
+			if from == path {
+				return get(root, from).map(|_| ());
+			}
+
+			if path.starts_with(from.as_str()) && path[from.len()..].starts_with('/') {
+				return Err(PatchErrorKind::MoveIntoChild(from.to_string()));
+			}
+
+			let value = remove(root, from)?;
+			add(root, path, value).map_err(|(kind, value)| {
+				// Put the value back where it was.
+				let _ = add(root, from, value);
+				kind
+			})
+		}
+		PatchOp::Copy { from, path } => {
+			let value = get(root, from)?;
+			add(root, path, value).map_err(|(kind, _)| kind)
+		}
+		PatchOp::Test { path, value } => {
+			let options = CompareOptions {
+				numeric_coercion: true,
+				..Default::default()
+			};
+
+			let equal = pointer::with_pointer(root, path, |target| {
+				let expected = T::new(value.clone(), target.metadata().clone());
+				compare::semantically_eq(target, &expected, &options)
+			})
+			.ok_or_else(|| PatchErrorKind::NotFound(path.to_string()))?;
+
+			if equal {
+				Ok(())
+			} else {
+				Err(PatchErrorKind::TestFailed(path.to_string()))
+			}
+		}
+	}
+}
+
+/// Applies the given JSON Patch ([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902))
+/// to `value`.
+///
+/// Operations are applied in order, in place.
+/// Added values are given the metadata of their parent.
+///
+/// If an operation fails, the returned error gives its index in the patch.
+/// The operations preceding it remain applied,
+/// and the value is left as it was before the failing operation:
+/// each operation is applied entirely or not at all.
+pub fn apply_patch<T: JsonMut + JsonBuild + JsonClone>(
+	value: &mut T,
+	patch: &[PatchOp<T>],
+) -> Result<(), PatchError> {
+	for (i, op) in patch.iter().enumerate() {
+		apply(value, op).map_err(|kind| PatchError { kind, operation: i })?
+	}
+
+	Ok(())
+}
+
+/// Position of the keys added to an object.
+///
+/// Only relevant for backends preserving the insertion order of object members:
+/// other backends order their members on their own.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum KeyOrder {
+	/// New keys are appended after the existing ones.
+	#[default]
+	Append,
+
+	/// New keys are inserted before the first existing key greater than them,
+	/// so that sorted objects remain sorted.
+	Sorted,
+}
+
+/// Options of [`merge_patch_with`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct MergeOptions {
+	/// Position of the keys added to objects.
+	pub key_order: KeyOrder,
+}
+
+/// Inserts the given new member into the given object,
+/// according to the given key order.
+fn insert_member<T: JsonMut + JsonBuild>(o: &mut T::Object, key: T::Key, item: T, order: KeyOrder) {
+	match order {
+		KeyOrder::Append => {
+			o.insert(key, item);
+		}
+		KeyOrder::Sorted => {
+			let mut entries: Vec<_> = std::mem::take(o).into_iter().collect();
+			let i = entries
+				.iter()
+				.position(|(k, _)| **k > *key)
+				.unwrap_or(entries.len());
+			entries.insert(i, (key, item));
+			*o = entries.into_iter().collect()
+		}
+	}
+}
+
+/// Applies the given JSON Merge Patch ([RFC 7386](https://www.rfc-editor.org/rfc/rfc7386))
+/// to `target`.
+///
+/// If `patch` is an object, its members are merged recursively into `target`
+/// (which is first replaced by an empty object if it is not an object):
+/// `null` members remove the matching keys, and other members are merged
+/// into the matching values, or added.
+/// Otherwise `patch` replaces `target`.
+///
+/// Untouched values and the merged objects keep their metadata.
+/// Added and replaced values take the metadata of the patch.
+/// Added keys are appended: see [`merge_patch_with`] to choose their position.
+pub fn merge_patch<T: JsonMut + JsonBuild + JsonClone>(target: &mut T, patch: &T) {
+	merge_patch_with(target, patch, &MergeOptions::default())
+}
+
+/// Applies the given JSON Merge Patch ([RFC 7386](https://www.rfc-editor.org/rfc/rfc7386))
+/// to `target`, with the given options.
+///
+/// See [`merge_patch`] for details.
+pub fn merge_patch_with<T: JsonMut + JsonBuild + JsonClone>(
+	target: &mut T,
+	patch: &T,
+	options: &MergeOptions,
+) {
+	match patch.as_value_ref() {
+		ValueRef::Object(p) => {
+			if !target.is_object() {
+				*target = T::empty_object(patch.metadata().clone())
+			}
+
+			if let ValueMut::Object(o) = target.as_value_mut() {
+				for (key, patch_item) in MapIter::iter(p) {
+					if patch_item.is_null() {
+						o.remove(&**key);
+					} else if o.get(&**key).is_some() {
+						merge_patch_with(&mut *o.get_mut(&**key).unwrap(), &*patch_item, options)
+					} else {
+						let mut item = T::null(patch_item.metadata().clone());
+						merge_patch_with(&mut item, &*patch_item, options);
+						insert_member(o, (*key).clone(), item, options.key_order)
+					}
+				}
+			}
+		}
+		_ => *target = patch.clone(),
+	}
+}
+
+/// Merges the array `other` into the array `target`,
+/// matching their object elements by the value of the member `key`.
+///
+/// Each element of `other` holding `key` is merged into the first element
+/// of `target` holding an equal value for `key`, as a JSON Merge Patch
+/// (see [`merge_patch`]). Unmatched elements, and elements lacking `key`,
+/// are appended to `target`.
+/// Nothing happens if either value is not an array.
+pub fn merge_array_by_key<T: JsonMut + JsonBuild + JsonClone>(
+	target: &mut T,
+	other: &T,
+	key: &str,
+) {
+	let other = match other.as_array() {
+		Some(other) => other,
+		None => return,
+	};
+
+	if let ValueMut::Array(a) = target.as_value_mut() {
+		for other_item in cc_traits::Iter::iter(other) {
+			let id = other_item.as_object().and_then(|o| o.get(key));
+			let merged = match id {
+				Some(id) => {
+					let matching = a.iter_mut().find(|item| {
+						item.as_object()
+							.and_then(|o| o.get(key))
+							.is_some_and(|item_id| *item_id == *id)
+					});
+
+					match matching {
+						Some(mut item) => {
+							merge_patch(&mut *item, &*other_item);
+							true
+						}
+						None => false,
+					}
+				}
+				None => false,
+			};
+
+			if !merged {
+				a.push_back((*other_item).clone());
+			}
+		}
+	}
+}
+
+/// Returns the JSON Merge Patch turning `source` into `target`.
+///
+/// See [`Json::merge_patch_diff`](crate::Json::merge_patch_diff).
+pub(crate) fn merge_patch_diff<S: Json, U: Json, T: JsonBuild>(source: &S, target: &U) -> Value<T>
+where
+	T::MetaData: Default,
+{
+	match (source.as_value_ref(), target.as_value_ref()) {
+		(ValueRef::Object(s), ValueRef::Object(t)) => {
+			let removed = MapIter::iter(s)
+				.filter(|(key, _)| t.get(&**key).is_none())
+				.map(|(key, _)| {
+					(
+						T::new_key(&key, T::MetaData::default()),
+						T::null(T::MetaData::default()),
+					)
+				});
+
+			let changed = MapIter::iter(t).filter_map(|(key, t_item)| {
+				let patch = match s.get(&*key) {
+					Some(s_item) if compare::json_eq(&*s_item, &*t_item) => return None,
+					Some(s_item) => merge_patch_diff(&*s_item, &*t_item),
+					None => to_default_value(&*t_item),
+				};
+
+				Some((
+					T::new_key(&key, T::MetaData::default()),
+					patch.with_default(),
+				))
+			});
+
+			Value::Object(removed.chain(changed).collect())
+		}
+		_ => to_default_value(target),
+	}
+}
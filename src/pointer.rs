@@ -0,0 +1,339 @@
+use crate::{Json, ValueRef};
+use cc_traits::{CollectionRef, Iter, MapIter};
+use std::fmt;
+
+#[cfg(feature = "nightly")]
+use crate::{JsonBuild, JsonMut, Value, ValueMut};
+#[cfg(feature = "nightly")]
+use cc_traits::{CollectionMut, Get, GetMut, IterMut, Len, MapInsert, PushBack};
+
+/// JSON Pointer resolution error.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PointerError {
+	/// The pointer is not a valid JSON Pointer.
+	Invalid(String),
+
+	/// The parent of the designated node does not exist.
+	ParentNotFound(String),
+
+	/// The parent of the designated node is neither an array nor an object.
+	NotAContainer(String),
+
+	/// The last reference token of the pointer is not a valid index
+	/// in the parent array.
+	InvalidIndex(String),
+}
+
+impl PointerError {
+	/// Returns the pointer (or parent pointer) the error is about.
+	pub fn pointer(&self) -> &str {
+		match self {
+			Self::Invalid(p)
+			| Self::ParentNotFound(p)
+			| Self::NotAContainer(p)
+			| Self::InvalidIndex(p) => p,
+		}
+	}
+}
+
+impl fmt::Display for PointerError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Invalid(p) => write!(f, "invalid JSON Pointer `{}`", p),
+			Self::ParentNotFound(p) => write!(f, "parent `{}` not found", p),
+			Self::NotAContainer(p) => write!(f, "parent `{}` is not an array or object", p),
+			Self::InvalidIndex(p) => write!(f, "invalid array index in `{}`", p),
+		}
+	}
+}
+
+impl std::error::Error for PointerError {}
+
+/// Appends the given reference token to a JSON Pointer,
+/// escaping `~` and `/` as defined by [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901).
+pub(crate) fn push_token(pointer: &mut String, token: &str) {
+	pointer.push('/');
+	for c in token.chars() {
+		match c {
+			'~' => pointer.push_str("~0"),
+			'/' => pointer.push_str("~1"),
+			c => pointer.push(c),
+		}
+	}
+}
+
+/// Calls `f` on every node of the given value in pre-order,
+/// with the JSON Pointer to the node.
+///
+/// `pointer` is the pointer to `value`.
+pub(crate) fn walk_with_path<T: Json, F: FnMut(&str, ValueRef<T>)>(
+	value: &T,
+	pointer: &mut String,
+	f: &mut F,
+) {
+	f(pointer, value.as_value_ref());
+
+	let len = pointer.len();
+	match value.as_value_ref() {
+		ValueRef::Array(a) => {
+			for (i, item) in Iter::iter(a).enumerate() {
+				push_token(pointer, &i.to_string());
+				walk_with_path(&*item, pointer, f);
+				pointer.truncate(len)
+			}
+		}
+		ValueRef::Object(o) => {
+			for (key, item) in MapIter::iter(o) {
+				push_token(pointer, &key);
+				walk_with_path(&*item, pointer, f);
+				pointer.truncate(len)
+			}
+		}
+		_ => (),
+	}
+}
+
+/// Parses a reference token into an array index.
+///
+/// Leading zeros are not allowed.
+pub(crate) fn parse_index(token: &str) -> Option<usize> {
+	if token.len() > 1 && token.starts_with('0') {
+		None
+	} else if token.bytes().all(|b| b.is_ascii_digit()) {
+		token.parse().ok()
+	} else {
+		None
+	}
+}
+
+/// Unescapes a reference token.
+pub(crate) fn unescape(token: &str) -> Option<String> {
+	let mut result = String::with_capacity(token.len());
+	let mut chars = token.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'~' => match chars.next() {
+				Some('0') => result.push('~'),
+				Some('1') => result.push('/'),
+				_ => return None,
+			},
+			c => result.push(c),
+		}
+	}
+
+	Some(result)
+}
+
+/// Resolves the given [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901)
+/// against `value`.
+///
+/// The empty pointer designates `value` itself.
+/// Returns `None` if the pointer is malformed (it does not start with `/`
+/// or contains an invalid `~` escape), or if it does not resolve
+/// (missing key, out-of-range or invalid index, or scalar parent).
+pub fn pointer<'a, T: Json>(value: &'a T, pointer: &str) -> Option<ValueRef<'a, T>>
+where
+	<T::Array as CollectionRef>::ItemRef<'a>: Into<ValueRef<'a, T>>,
+	<T::Object as CollectionRef>::ItemRef<'a>: Into<ValueRef<'a, T>>,
+{
+	let mut current = value.as_value_ref();
+	if !pointer.is_empty() {
+		for token in pointer.strip_prefix('/')?.split('/') {
+			current = if current.is_array() {
+				current.get_index(parse_index(token)?)?
+			} else {
+				current.get_key(&unescape(token)?)?
+			}
+		}
+	}
+
+	Some(current)
+}
+
+/// Returns the pointer to the deepest node of `value` containing the nodes
+/// designated by the pointers `a` and `b`, along with this node.
+///
+/// Returns `None` if `a` or `b` does not resolve.
+#[cfg(feature = "nightly")]
+pub(crate) fn common_ancestor<'a, T: Json>(
+	value: &'a T,
+	a: &str,
+	b: &str,
+) -> Option<(String, ValueRef<'a, T>)>
+where
+	<T::Array as CollectionRef>::ItemRef<'a>: Into<ValueRef<'a, T>>,
+	<T::Object as CollectionRef>::ItemRef<'a>: Into<ValueRef<'a, T>>,
+{
+	pointer(value, a)?;
+	pointer(value, b)?;
+
+	// Both pointers are valid, and escape sequences are unique,
+	// so that the reference tokens can be compared without unescaping them.
+	let mut ancestor = String::new();
+	for (a, b) in a.split('/').zip(b.split('/')).skip(1) {
+		if a != b {
+			break;
+		}
+
+		ancestor.push('/');
+		ancestor.push_str(a)
+	}
+
+	let node = pointer(value, &ancestor)?;
+	Some((ancestor, node))
+}
+
+/// Resolves the given [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901)
+/// against `value`, mutably.
+///
+/// See [`pointer`] for details.
+#[cfg(feature = "nightly")]
+pub fn pointer_mut<'a, T: JsonMut>(value: &'a mut T, pointer: &str) -> Option<ValueMut<'a, T>>
+where
+	T::Array: GetMut<usize>,
+	<T::Array as CollectionMut>::ItemMut<'a>: Into<ValueMut<'a, T>>,
+	<T::Object as CollectionMut>::ItemMut<'a>: Into<ValueMut<'a, T>>,
+{
+	let mut current = value.as_value_mut();
+	if !pointer.is_empty() {
+		for token in pointer.strip_prefix('/')?.split('/') {
+			current = if current.is_array() {
+				current.get_mut_index(parse_index(token)?)?
+			} else {
+				current.get_mut_key(&unescape(token)?)?
+			}
+		}
+	}
+
+	Some(current)
+}
+
+/// Calls `f` on the value designated by the given JSON Pointer,
+/// and returns its result.
+///
+/// Unlike [`pointer`], this works for any [`Json`] type,
+/// including types whose arrays and objects compute their items on access,
+/// such as [`StructJson`](crate::StructJson).
+/// Returns `None` if the pointer is invalid or does not resolve.
+#[cfg(feature = "nightly")]
+pub fn with_pointer<T: Json, R>(value: &T, pointer: &str, f: impl FnOnce(&T) -> R) -> Option<R> {
+	if pointer.is_empty() {
+		return Some(f(value));
+	}
+
+	let rest = pointer.strip_prefix('/')?;
+	let (token, rest) = match rest.find('/') {
+		Some(i) => (&rest[..i], &rest[i..]),
+		None => (rest, ""),
+	};
+
+	match value.as_value_ref() {
+		ValueRef::Array(a) => {
+			let item = a.get(parse_index(token)?)?;
+			with_pointer(&*item, rest, f)
+		}
+		ValueRef::Object(o) => {
+			let item = o.get(unescape(token)?.as_str())?;
+			with_pointer(&*item, rest, f)
+		}
+		_ => None,
+	}
+}
+
+/// Calls `f` on the value designated by the given JSON Pointer, mutably.
+///
+/// Returns `None` if the pointer is invalid or does not resolve.
+#[cfg(feature = "nightly")]
+pub(crate) fn with_pointer_mut<T: JsonMut, R>(
+	value: &mut T,
+	pointer: &str,
+	f: impl FnOnce(&mut T) -> R,
+) -> Option<R> {
+	if pointer.is_empty() {
+		return Some(f(value));
+	}
+
+	let rest = pointer.strip_prefix('/')?;
+	let (token, rest) = match rest.find('/') {
+		Some(i) => (&rest[..i], &rest[i..]),
+		None => (rest, ""),
+	};
+
+	match value.as_value_mut() {
+		ValueMut::Array(a) => {
+			let mut item = a.iter_mut().nth(parse_index(token)?)?;
+			with_pointer_mut(&mut *item, rest, f)
+		}
+		ValueMut::Object(o) => {
+			let mut item = o.get_mut(unescape(token)?.as_str())?;
+			with_pointer_mut(&mut *item, rest, f)
+		}
+		_ => None,
+	}
+}
+
+/// Replaces the given value, keeping its metadata,
+/// and returns the replaced value.
+#[cfg(feature = "nightly")]
+pub(crate) fn replace<T: JsonBuild>(target: &mut T, value: Value<T>) -> Value<T> {
+	let metadata = target.metadata().clone();
+	std::mem::replace(target, T::new(value, metadata)).into_value()
+}
+
+#[cfg(feature = "nightly")]
+pub(crate) fn set_pointer<T: JsonMut + JsonBuild>(
+	value: &mut T,
+	pointer: &str,
+	new_value: Value<T>,
+) -> Result<Option<Value<T>>, PointerError> {
+	if pointer.is_empty() {
+		return Ok(Some(replace(value, new_value)));
+	}
+
+	let valid = pointer.starts_with('/') && pointer[1..].split('/').all(|t| unescape(t).is_some());
+	if !valid {
+		return Err(PointerError::Invalid(pointer.to_string()));
+	}
+
+	let i = pointer.rfind('/').unwrap();
+	let (parent_pointer, token) = (&pointer[..i], &pointer[i + 1..]);
+	with_pointer_mut(value, parent_pointer, |parent| {
+		let metadata = parent.metadata().clone();
+		match parent.as_value_mut() {
+			ValueMut::Array(a) => {
+				let len = a.len();
+				let index = if token == "-" {
+					Some(len)
+				} else {
+					parse_index(token).filter(|i| *i <= len)
+				};
+
+				match index {
+					Some(i) if i < len => {
+						Ok(Some(replace(&mut *a.iter_mut().nth(i).unwrap(), new_value)))
+					}
+					Some(_) => {
+						a.push_back(T::new(new_value, metadata));
+						Ok(None)
+					}
+					None => Err(PointerError::InvalidIndex(pointer.to_string())),
+				}
+			}
+			ValueMut::Object(o) => {
+				let key = unescape(token).unwrap();
+				if o.get(key.as_str()).is_some() {
+					let mut item = o.get_mut(key.as_str()).unwrap();
+					Ok(Some(replace(&mut *item, new_value)))
+				} else {
+					o.insert(
+						T::new_key(&key, metadata.clone()),
+						T::new(new_value, metadata),
+					);
+					Ok(None)
+				}
+			}
+			_ => Err(PointerError::NotAContainer(parent_pointer.to_string())),
+		}
+	})
+	.unwrap_or_else(|| Err(PointerError::ParentNotFound(parent_pointer.to_string())))
+}
@@ -0,0 +1,57 @@
+use crate::{number, Json, ValueRef};
+use cc_traits::{Iter, MapIter};
+use std::fmt;
+
+/// Multi-line `Debug` view of a JSON value.
+///
+/// Renders arrays and objects over multiple indented lines,
+/// even when formatted with `{:?}`, so that failed equality assertions
+/// (such as `assert_eq!`) show a readable difference.
+/// Only the [`Json`] accessors are used:
+/// the value components are not required to implement `Debug`.
+///
+/// Numbers are rendered through their `i64`, `u64` or `f64` representation.
+pub struct PrettyDebug<'a, T>(pub &'a T);
+
+impl<'a, T: Json> PartialEq for PrettyDebug<'a, T>
+where
+	T: PartialEq,
+{
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl<'a, T: Json> fmt::Debug for PrettyDebug<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{:#?}", Node(self.0))
+	}
+}
+
+/// Value node, rendered over multiple lines with the alternate `Debug` format.
+struct Node<'a, T>(&'a T);
+
+impl<'a, T: Json> fmt::Debug for Node<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.0.as_value_ref() {
+			ValueRef::Null => f.write_str("null"),
+			ValueRef::Boolean(b) => write!(f, "{}", b),
+			ValueRef::Number(n) => f.write_str(&number::to_decimal_string(n)),
+			ValueRef::String(s) => write!(f, "{:?}", &**s),
+			ValueRef::Array(a) => {
+				let mut list = f.debug_list();
+				for item in Iter::iter(a) {
+					list.entry(&Node(&*item));
+				}
+				list.finish()
+			}
+			ValueRef::Object(o) => {
+				let mut map = f.debug_map();
+				for (key, item) in MapIter::iter(o) {
+					map.entry(&&**key, &Node(&*item));
+				}
+				map.finish()
+			}
+		}
+	}
+}
@@ -0,0 +1,303 @@
+use crate::{number, Json, ValueRef};
+use cc_traits::{Iter, MapIter};
+use std::fmt;
+
+#[cfg(feature = "nightly")]
+use crate::{JsonBuild, Value};
+
+/// Appends the `application/x-www-form-urlencoded` encoding of `s` to `out`.
+fn percent_encode(s: &str, out: &mut String) {
+	for b in s.bytes() {
+		match b {
+			b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'*' | b'-' | b'.' | b'_' => {
+				out.push(b as char)
+			}
+			b' ' => out.push('+'),
+			b => {
+				const HEX: &[u8; 16] = b"0123456789ABCDEF";
+				out.push('%');
+				out.push(HEX[(b >> 4) as usize] as char);
+				out.push(HEX[(b & 0xf) as usize] as char);
+			}
+		}
+	}
+}
+
+/// Appends the `key=value` pairs of the scalar leaves of `value` to `out`.
+///
+/// `key` is the already encoded key of `value`.
+fn flatten<T: Json>(value: &T, key: &mut String, out: &mut String) {
+	let len = key.len();
+	match value.as_value_ref() {
+		ValueRef::Array(a) => {
+			for (i, item) in Iter::iter(a).enumerate() {
+				key.push('[');
+				key.push_str(&i.to_string());
+				key.push(']');
+				flatten(&*item, key, out);
+				key.truncate(len)
+			}
+		}
+		ValueRef::Object(o) => {
+			for (k, item) in MapIter::iter(o) {
+				key.push('[');
+				percent_encode(&k, key);
+				key.push(']');
+				flatten(&*item, key, out);
+				key.truncate(len)
+			}
+		}
+		scalar => {
+			if !out.is_empty() {
+				out.push('&')
+			}
+
+			out.push_str(key);
+			out.push('=');
+			match scalar {
+				ValueRef::Boolean(true) => out.push_str("true"),
+				ValueRef::Boolean(false) => out.push_str("false"),
+				ValueRef::Number(n) => out.push_str(&number::to_decimal_string(n)),
+				ValueRef::String(s) => percent_encode(s, out),
+				_ => (),
+			}
+		}
+	}
+}
+
+/// Encodes the given value as an `application/x-www-form-urlencoded` string.
+pub(crate) fn to_query_string<T: Json>(value: &T) -> String {
+	let mut out = String::new();
+	let mut key = String::new();
+
+	match value.as_value_ref() {
+		ValueRef::Array(a) => {
+			for (i, item) in Iter::iter(a).enumerate() {
+				key.push_str(&i.to_string());
+				flatten(&*item, &mut key, &mut out);
+				key.clear()
+			}
+		}
+		ValueRef::Object(o) => {
+			for (k, item) in MapIter::iter(o) {
+				percent_encode(&k, &mut key);
+				flatten(&*item, &mut key, &mut out);
+				key.clear()
+			}
+		}
+		_ => (),
+	}
+
+	out
+}
+
+/// Query string parsing error.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum QueryParseError {
+	/// Invalid percent-encoded sequence, or percent-encoded bytes not forming a valid UTF-8 string.
+	InvalidEncoding(String),
+
+	/// Malformed key, such as a key with unbalanced brackets.
+	InvalidKey(String),
+
+	/// The key designates a value incompatible with a previous key.
+	///
+	/// This happens when a key is used both for a scalar and a nested value,
+	/// both for an array and an object, or when an array index
+	/// skips over some elements.
+	Conflict(String),
+}
+
+impl fmt::Display for QueryParseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::InvalidEncoding(s) => write!(f, "invalid percent-encoding in `{}`", s),
+			Self::InvalidKey(key) => write!(f, "invalid key `{}`", key),
+			Self::Conflict(key) => write!(f, "conflicting key `{}`", key),
+		}
+	}
+}
+
+impl std::error::Error for QueryParseError {}
+
+/// Decodes an `application/x-www-form-urlencoded` string.
+#[cfg(feature = "nightly")]
+fn percent_decode(s: &str) -> Result<String, QueryParseError> {
+	let bytes = s.as_bytes();
+	let mut decoded = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+	while i < bytes.len() {
+		match bytes[i] {
+			b'+' => decoded.push(b' '),
+			b'%' => {
+				// `from_str_radix` would accept a sign.
+				let hex = bytes
+					.get(i + 1..i + 3)
+					.filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+					.and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).unwrap(), 16).ok());
+				match hex {
+					Some(b) => decoded.push(b),
+					None => return Err(QueryParseError::InvalidEncoding(s.to_string())),
+				}
+				i += 2
+			}
+			b => decoded.push(b),
+		}
+		i += 1
+	}
+
+	String::from_utf8(decoded).map_err(|_| QueryParseError::InvalidEncoding(s.to_string()))
+}
+
+/// Splits a raw key into its (decoded) bracket-delimited segments.
+#[cfg(feature = "nightly")]
+fn key_segments(key: &str) -> Result<Vec<String>, QueryParseError> {
+	let invalid = || QueryParseError::InvalidKey(key.to_string());
+	let (head, mut rest) = match key.find('[') {
+		Some(i) => key.split_at(i),
+		None => (key, ""),
+	};
+
+	if head.is_empty() || head.contains(']') {
+		return Err(invalid());
+	}
+
+	let mut segments = vec![percent_decode(head)?];
+	while !rest.is_empty() {
+		let inner = rest.strip_prefix('[').ok_or_else(invalid)?;
+		let end = inner.find(']').ok_or_else(invalid)?;
+		let segment = &inner[..end];
+		if segment.contains('[') {
+			return Err(invalid());
+		}
+
+		segments.push(percent_decode(segment)?);
+		rest = &inner[end + 1..]
+	}
+
+	Ok(segments)
+}
+
+/// Intermediate query string tree.
+#[cfg(feature = "nightly")]
+enum Node {
+	Leaf(String),
+	Array(Vec<Node>),
+	Object(Vec<(String, Node)>),
+}
+
+#[cfg(feature = "nightly")]
+impl Node {
+	fn container_for(segment: &str) -> Self {
+		if segment.is_empty() || segment.parse::<usize>().is_ok() {
+			Self::Array(Vec::new())
+		} else {
+			Self::Object(Vec::new())
+		}
+	}
+
+	/// Inserts the given value at the given path.
+	fn insert(&mut self, path: &[String], value: String, key: &str) -> Result<(), QueryParseError> {
+		let conflict = || QueryParseError::Conflict(key.to_string());
+		match path.split_first() {
+			None => match self {
+				Self::Leaf(leaf) => {
+					*leaf = value;
+					Ok(())
+				}
+				_ => Err(conflict()),
+			},
+			Some((segment, rest)) => {
+				let child = match self {
+					Self::Leaf(_) => return Err(conflict()),
+					Self::Array(items) => {
+						let index = if segment.is_empty() {
+							items.len()
+						} else {
+							segment.parse::<usize>().map_err(|_| conflict())?
+						};
+
+						if index == items.len() {
+							items.push(match rest.first() {
+								Some(next) => Self::container_for(next),
+								None => Self::Leaf(String::new()),
+							})
+						}
+
+						items.get_mut(index).ok_or_else(conflict)?
+					}
+					Self::Object(members) => {
+						let index = match members.iter().position(|(k, _)| k == segment) {
+							Some(index) => index,
+							None => {
+								let child = match rest.first() {
+									Some(next) => Self::container_for(next),
+									None => Self::Leaf(String::new()),
+								};
+								members.push((segment.clone(), child));
+								members.len() - 1
+							}
+						};
+
+						&mut members[index].1
+					}
+				};
+
+				child.insert(rest, value, key)
+			}
+		}
+	}
+
+	fn build<T: JsonBuild>(self) -> T
+	where
+		T::MetaData: Default,
+	{
+		let value = match self {
+			Self::Leaf(s) => Value::String(s.as_str().into()),
+			Self::Array(items) => Value::Array(items.into_iter().map(Self::build).collect()),
+			Self::Object(members) => Value::Object(
+				members
+					.into_iter()
+					.map(|(k, v)| (T::new_key(&k, T::MetaData::default()), v.build()))
+					.collect(),
+			),
+		};
+
+		T::new(value, T::MetaData::default())
+	}
+}
+
+/// Parses an `application/x-www-form-urlencoded` string into a JSON object.
+///
+/// This is the inverse of [`Json::to_query_string`].
+/// Keys using the bracket convention (e.g. `a[b][0]=1`) are parsed into nested values.
+/// A nested value is an array if its first key segment is
+/// numeric (`a[0]`) or empty (`a[]`, appending a new element), and an object otherwise.
+/// Array indexes must be given in order, without gaps.
+/// If the same key appears more than once, the last value is kept.
+///
+/// Keys and values are percent-decoded.
+/// No type inference is performed on values: every leaf is a string,
+/// including `true`, `null` or numbers.
+///
+/// The result is always an object: the keys of a top-level array
+/// encoded by [`Json::to_query_string`] (`0=a&1=b`) are parsed as object keys
+/// (`{"0": "a", "1": "b"}`).
+#[cfg(feature = "nightly")]
+pub fn from_query_string<T: JsonBuild>(s: &str) -> Result<T, QueryParseError>
+where
+	T::MetaData: Default,
+{
+	let mut root = Node::Object(Vec::new());
+	for pair in s.split('&').filter(|pair| !pair.is_empty()) {
+		let (key, value) = match pair.find('=') {
+			Some(i) => (&pair[..i], &pair[i + 1..]),
+			None => (pair, ""),
+		};
+
+		let segments = key_segments(key)?;
+		root.insert(&segments, percent_decode(value)?, key)?
+	}
+
+	Ok(root.build())
+}
@@ -1,4 +1,11 @@
-use crate::{Json, Number, Value};
+use crate::{compare, Json, Number, PrecisionLost, Value, ValueKind};
+use cc_traits::{CollectionMut, CollectionRef, Get, GetMut, Len};
+use std::ops::Index;
+
+#[cfg(feature = "nightly")]
+use crate::JsonMut;
+#[cfg(feature = "nightly")]
+use cc_traits::{MapIter, PopBack, PushBack, Remove};
 
 /// JSON value reference.
 pub enum ValueRef<'a, T: Json> {
@@ -10,6 +17,14 @@ pub enum ValueRef<'a, T: Json> {
 	Object(&'a T::Object),
 }
 
+impl<'a, T: Json> Clone for ValueRef<'a, T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, T: Json> Copy for ValueRef<'a, T> {}
+
 /// Mutable JSON value reference.
 pub enum ValueMut<'a, T: Json> {
 	Null,
@@ -24,6 +39,18 @@ macro_rules! common_impls {
 	($($ty:ident),*) => {
 		$(
 			impl<'a, T: Json> $ty<'a, T> {
+				/// Returns the kind of this value.
+				pub fn kind(&self) -> ValueKind {
+					match self {
+						Self::Null => ValueKind::Null,
+						Self::Boolean(_) => ValueKind::Boolean,
+						Self::Number(_) => ValueKind::Number,
+						Self::String(_) => ValueKind::String,
+						Self::Array(_) => ValueKind::Array,
+						Self::Object(_) => ValueKind::Object,
+					}
+				}
+
 				/// Returns `true` if the value is a `Null`. Returns `false` otherwise.
 				pub fn is_null(&self) -> bool {
 					matches!(self, Self::Null)
@@ -85,6 +112,14 @@ macro_rules! common_impls {
                     self.as_number().map(Number::as_f64_lossy)
                 }
 
+                /// Returns this number as an `f64` if it is a number,
+                /// or an error if the conversion loses precision.
+                ///
+                /// See [`Number::as_f64_checked`].
+                pub fn as_f64_checked(&self) -> Option<Result<f64, PrecisionLost>> {
+                    self.as_number().map(Number::as_f64_checked)
+                }
+
 				/// Returns `true` if the value is a string.
 				/// Returns `false` otherwise.
 				///
@@ -94,6 +129,15 @@ macro_rules! common_impls {
 					matches!(self, Self::String(_))
 				}
 
+				/// If the value is a string, returns its length in UTF-16 code units.
+				/// Returns `None` otherwise.
+				///
+				/// This is the length of the string as seen by JavaScript (`String.length`),
+				/// where characters outside the Basic Multilingual Plane count as two units.
+				pub fn utf16_len(&self) -> Option<usize> {
+					self.as_str().map(|s| s.encode_utf16().count())
+				}
+
 				/// Returns `true` if the value is an array.
 				/// Returns `false` otherwise.
 				///
@@ -111,6 +155,25 @@ macro_rules! common_impls {
 				pub fn is_object(&self) -> bool {
 					matches!(self, Self::Object(_))
 				}
+
+				/// Checks if the value is truthy according to JavaScript rules.
+				///
+				/// `false`, `null`, `""` and numbers equal to zero
+				/// (including `-0` and `0.0`) are falsy, as well as `NaN`
+				/// for backends able to hold it.
+				/// Every other value is truthy, including empty arrays and objects.
+				pub fn is_js_truthy(&self) -> bool {
+					match self {
+						Self::Null => false,
+						Self::Boolean(b) => *b,
+						Self::Number(n) => {
+							let f = n.as_f64_lossy();
+							f != 0.0 && !f.is_nan()
+						}
+						Self::String(s) => !s.is_empty(),
+						_ => true,
+					}
+				}
 			}
 		)*
 	};
@@ -164,6 +227,35 @@ impl<'a, T: Json> ValueRef<'a, T> {
 		}
 	}
 
+	/// Returns the [`str`] associated to this string value,
+	/// without checking that the value is a string.
+	///
+	/// # Safety
+	///
+	/// The value must be a string.
+	/// Calling this method on any other value is undefined behavior.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use generic_json::{Json, ValueRef};
+	///
+	/// fn first_char<T: Json>(value: ValueRef<T>) -> Option<char> {
+	///     if value.is_string() {
+	///         // SAFETY: the value is a string.
+	///         unsafe { value.as_str_unchecked() }.chars().next()
+	///     } else {
+	///         None
+	///     }
+	/// }
+	/// ```
+	pub unsafe fn as_str_unchecked(&self) -> &'a str {
+		match self {
+			Self::String(s) => s.as_ref(),
+			_ => std::hint::unreachable_unchecked(),
+		}
+	}
+
 	/// If the value is an array, returns a reference to it.
 	/// Returns `None` otherwise.
 	pub fn as_array(&self) -> Option<&'a T::Array> {
@@ -182,6 +274,24 @@ impl<'a, T: Json> ValueRef<'a, T> {
 		}
 	}
 
+	/// If the value is an array, returns a reference to the element at the given index.
+	/// Returns `None` otherwise, or if there is no such element.
+	pub fn get_index(self, i: usize) -> Option<ValueRef<'a, T>>
+	where
+		<T::Array as CollectionRef>::ItemRef<'a>: Into<ValueRef<'a, T>>,
+	{
+		self.as_array().and_then(|a| a.get(i)).map(Into::into)
+	}
+
+	/// If the value is an object, returns a reference to the value associated to the given key.
+	/// Returns `None` otherwise, or if there is no such key.
+	pub fn get_key(self, key: &str) -> Option<ValueRef<'a, T>>
+	where
+		<T::Object as CollectionRef>::ItemRef<'a>: Into<ValueRef<'a, T>>,
+	{
+		self.as_object().and_then(|o| o.get(key)).map(Into::into)
+	}
+
 	/// Creates a new value by cloning the referenced value.
 	pub fn cloned(&self) -> Value<T>
 	where
@@ -310,6 +420,30 @@ impl<'a, T: Json> ValueMut<'a, T> {
 		}
 	}
 
+	/// If the value is an array, returns a mutable reference to the element at the given index.
+	/// Returns `None` otherwise, or if there is no such element.
+	pub fn get_mut_index(self, i: usize) -> Option<ValueMut<'a, T>>
+	where
+		T::Array: GetMut<usize>,
+		<T::Array as CollectionMut>::ItemMut<'a>: Into<ValueMut<'a, T>>,
+	{
+		self.into_array_mut()
+			.and_then(|a| a.get_mut(i))
+			.map(Into::into)
+	}
+
+	/// If the value is an object, returns a mutable reference to the value associated to the given key.
+	/// Returns `None` otherwise, or if there is no such key.
+	pub fn get_mut_key(self, key: &str) -> Option<ValueMut<'a, T>>
+	where
+		T::Object: for<'k> GetMut<&'k str>,
+		<T::Object as CollectionMut>::ItemMut<'a>: Into<ValueMut<'a, T>>,
+	{
+		self.into_object_mut()
+			.and_then(|o| o.get_mut(key))
+			.map(Into::into)
+	}
+
 	/// Creates a new value by cloning the referenced value.
 	pub fn cloned(&self) -> Value<T>
 	where
@@ -328,3 +462,130 @@ impl<'a, T: Json> ValueMut<'a, T> {
 		}
 	}
 }
+
+#[cfg(feature = "nightly")]
+impl<'a, T: JsonMut> ValueMut<'a, T> {
+	/// If the value is an array, removes the elements for which `f` returns `false`,
+	/// preserving the order of the remaining elements.
+	///
+	/// Returns `false` if the value is not an array, leaving it untouched.
+	///
+	/// ```
+	/// # #[cfg(feature = "serde_json-impl")]
+	/// # {
+	/// use generic_json::Json;
+	/// use serde_json::json;
+	///
+	/// let mut value = json!([1, null, "a", null, [null]]);
+	/// assert!(value.as_value_mut().retain_array(|item| !item.is_null()));
+	/// assert_eq!(value, json!([1, "a", [null]]));
+	/// # }
+	/// ```
+	pub fn retain_array<F: FnMut(ValueRef<T>) -> bool>(&mut self, mut f: F) -> bool {
+		match self {
+			Self::Array(a) => {
+				let mut items = Vec::with_capacity(a.len());
+				while let Some(item) = a.pop_back() {
+					items.push(item)
+				}
+
+				for item in items.into_iter().rev() {
+					if f(item.as_value_ref()) {
+						a.push_back(item);
+					}
+				}
+
+				true
+			}
+			_ => false,
+		}
+	}
+
+	/// If the value is an object, removes the entries for which `f` returns `false`.
+	///
+	/// The keys to remove are collected before any entry is removed,
+	/// so `f` sees every entry of the original object.
+	/// Returns `false` if the value is not an object, leaving it untouched.
+	///
+	/// ```
+	/// # #[cfg(feature = "serde_json-impl")]
+	/// # {
+	/// use generic_json::Json;
+	/// use serde_json::json;
+	///
+	/// let mut value = json!({ "_id": 1, "name": "a", "_rev": 2, "nested": { "_id": 3 } });
+	/// assert!(value.as_value_mut().retain_object(|key, _| !key.starts_with('_')));
+	/// assert_eq!(value, json!({ "name": "a", "nested": { "_id": 3 } }));
+	/// # }
+	/// ```
+	pub fn retain_object<F: FnMut(&str, ValueRef<T>) -> bool>(&mut self, mut f: F) -> bool {
+		match self {
+			Self::Object(o) => {
+				let removed: Vec<String> = MapIter::iter(&**o)
+					.filter(|(key, item)| !f(key, item.as_value_ref()))
+					.map(|(key, _)| key.to_string())
+					.collect();
+
+				for key in removed {
+					o.remove(key.as_str());
+				}
+
+				true
+			}
+			_ => false,
+		}
+	}
+}
+
+impl<'a, T: Json> Index<usize> for ValueRef<'a, T>
+where
+	T::Array: Index<usize, Output = T>,
+{
+	type Output = T;
+
+	/// Returns the element of the array at the given index.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an array, or if the index is out of bounds.
+	/// See [`get_index`](Self::get_index) for a non-panicking alternative.
+	fn index(&self, i: usize) -> &T {
+		match self {
+			Self::Array(a) if i < a.len() => &a[i],
+			Self::Array(_) => panic!("index {} out of bounds", i),
+			_ => panic!("cannot index {} with an integer", self.kind()),
+		}
+	}
+}
+
+impl<'a, 'q, T: Json> Index<&'q str> for ValueRef<'a, T>
+where
+	T::Object: Index<&'q str, Output = T>,
+{
+	type Output = T;
+
+	/// Returns the value associated to the given key in the object.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an object, or if there is no such key.
+	/// See [`get_key`](Self::get_key) for a non-panicking alternative.
+	fn index(&self, key: &'q str) -> &T {
+		match self {
+			Self::Object(o) if o.get(key).is_some() => &o[key],
+			Self::Object(_) => panic!("no such key `{}`", key),
+			_ => panic!("cannot index {} with a string", self.kind()),
+		}
+	}
+}
+
+impl<'a, T: Json, U: Json> PartialEq<Value<U>> for ValueRef<'a, T> {
+	/// Compares this value with a value of any type, structurally,
+	/// as by [`json_eq`](crate::json_eq).
+	///
+	/// Numbers are compared by value (`1` and `1.0` are equal),
+	/// and objects by key lookup, regardless of the order of their members.
+	fn eq(&self, other: &Value<U>) -> bool {
+		compare::value_refs_json_eq(*self, other.as_value_ref())
+	}
+}
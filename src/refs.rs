@@ -0,0 +1,197 @@
+use crate::{pointer, Json, ValueRef};
+use cc_traits::{Get, Iter, MapIter};
+use std::fmt;
+
+#[cfg(feature = "nightly")]
+use crate::{pairs, JsonBuild, JsonMut, ValueMut};
+#[cfg(feature = "nightly")]
+use cc_traits::{IterMut, MapIterMut};
+#[cfg(feature = "nightly")]
+use std::collections::{hash_map::Entry, HashMap};
+
+/// Reference resolution error.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RefError {
+	/// The reference target does not exist.
+	Dangling(String),
+
+	/// The reference (transitively) refers to itself.
+	Cycle(String),
+
+	/// The reference target contains a number that cannot be copied.
+	InvalidNumber(String),
+}
+
+impl fmt::Display for RefError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Dangling(target) => write!(f, "dangling reference `{}`", target),
+			Self::Cycle(target) => write!(f, "cyclic reference `{}`", target),
+			Self::InvalidNumber(target) => {
+				write!(
+					f,
+					"reference target `{}` contains an invalid number",
+					target
+				)
+			}
+		}
+	}
+}
+
+impl std::error::Error for RefError {}
+
+/// Appends every `$ref` found in `value` to `refs`.
+///
+/// `path` is the JSON Pointer to `value`.
+fn collect<T: Json>(value: &T, path: &mut String, refs: &mut Vec<(String, String)>) {
+	let len = path.len();
+	match value.as_value_ref() {
+		ValueRef::Array(a) => {
+			for (i, item) in Iter::iter(a).enumerate() {
+				pointer::push_token(path, &i.to_string());
+				collect(&*item, path, refs);
+				path.truncate(len)
+			}
+		}
+		ValueRef::Object(o) => {
+			if let Some(target) = o.get("$ref") {
+				if let Some(target) = target.as_str() {
+					refs.push((path.clone(), target.to_string()))
+				}
+			}
+
+			for (key, item) in MapIter::iter(o) {
+				pointer::push_token(path, &key);
+				collect(&*item, path, refs);
+				path.truncate(len)
+			}
+		}
+		_ => (),
+	}
+}
+
+/// Collects every `$ref` in the given value.
+pub(crate) fn collect_refs<T: Json>(value: &T) -> Vec<(String, String)> {
+	let mut refs = Vec::new();
+	collect(value, &mut String::new(), &mut refs);
+	refs
+}
+
+/// Decodes the percent-encoded fragment of a URI reference.
+#[cfg(feature = "nightly")]
+fn decode_fragment(fragment: &str) -> Option<String> {
+	let bytes = fragment.as_bytes();
+	let mut decoded = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+	while i < bytes.len() {
+		if bytes[i] == b'%' {
+			let hex = bytes.get(i + 1..i + 3)?;
+			if !hex.iter().all(u8::is_ascii_hexdigit) {
+				return None;
+			}
+
+			decoded.push(u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()?);
+			i += 3
+		} else {
+			decoded.push(bytes[i]);
+			i += 1
+		}
+	}
+
+	String::from_utf8(decoded).ok()
+}
+
+/// Returns the JSON Pointer designated by the given reference target,
+/// if it is a local reference.
+#[cfg(feature = "nightly")]
+fn local_pointer(target: &str) -> Option<String> {
+	let ptr = decode_fragment(target.strip_prefix('#')?)?;
+	if ptr.is_empty() || ptr.starts_with('/') {
+		Some(ptr)
+	} else {
+		None
+	}
+}
+
+/// Copies of the local reference targets, indexed by reference target.
+#[cfg(feature = "nightly")]
+type Targets<T> = HashMap<String, Result<T, RefError>>;
+
+/// Replaces every local reference object in `value`
+/// by a copy of its target in `targets`.
+///
+/// `stack` contains the references being resolved, used to detect cycles.
+#[cfg(feature = "nightly")]
+fn resolve<T: JsonMut + JsonBuild>(
+	value: &mut T,
+	targets: &Targets<T>,
+	stack: &mut Vec<String>,
+) -> Result<(), RefError> {
+	let local_ref = value
+		.as_object()
+		.and_then(|o| o.get("$ref"))
+		.and_then(|target| target.as_str().map(str::to_string))
+		.filter(|target| targets.contains_key(target));
+
+	match local_ref {
+		Some(target) => {
+			if stack.iter().any(|t| *t == target) {
+				return Err(RefError::Cycle(target));
+			}
+
+			let mut replacement =
+				targets[&target]
+					.as_ref()
+					.map_err(Clone::clone)
+					.and_then(|copy| {
+						pairs::convert(copy).ok_or_else(|| RefError::InvalidNumber(target.clone()))
+					})?;
+
+			stack.push(target);
+			resolve(&mut replacement, targets, stack)?;
+			stack.pop();
+
+			*value = replacement
+		}
+		None => match value.as_value_mut() {
+			ValueMut::Array(a) => {
+				for mut item in a.iter_mut() {
+					resolve(&mut *item, targets, stack)?
+				}
+			}
+			ValueMut::Object(o) => {
+				for (_, mut item) in o.iter_mut() {
+					resolve(&mut *item, targets, stack)?
+				}
+			}
+			_ => (),
+		},
+	}
+
+	Ok(())
+}
+
+/// Replaces every local reference object in `value`
+/// by a copy of its target.
+///
+/// Only the referenced targets are copied from the original value,
+/// before any replacement.
+#[cfg(feature = "nightly")]
+pub(crate) fn resolve_local_refs<T: JsonMut + JsonBuild>(value: &mut T) -> Result<(), RefError> {
+	let mut targets = Targets::new();
+	for (_, target) in collect_refs(value) {
+		if let Some(ptr) = local_pointer(&target) {
+			if let Entry::Vacant(entry) = targets.entry(target) {
+				let copy = match pointer::with_pointer(value, &ptr, pairs::convert) {
+					Some(Some(copy)) => Ok(copy),
+					Some(None) => Err(RefError::InvalidNumber(entry.key().clone())),
+					None => Err(RefError::Dangling(entry.key().clone())),
+				};
+
+				entry.insert(copy);
+			}
+		}
+	}
+
+	resolve(value, &targets, &mut Vec::new())
+}
@@ -0,0 +1,161 @@
+use crate::{pointer, JsonBuild, JsonMut, Key, ValueMut};
+use cc_traits::{IterMut, MapIter, MapIterMut};
+use std::collections::HashMap;
+
+/// Key case style.
+///
+/// Used by [`Json::recase_keys`](crate::Json::recase_keys).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CaseStyle {
+	/// Every character is lowercased: `FooBar` becomes `foobar`.
+	Lower,
+
+	/// Every character is uppercased: `FooBar` becomes `FOOBAR`.
+	Upper,
+
+	/// Words are joined, each word but the first being capitalized:
+	/// `FooBar` becomes `fooBar`.
+	Camel,
+
+	/// Words are lowercased and joined with `_`: `FooBar` becomes `foo_bar`.
+	Snake,
+}
+
+impl CaseStyle {
+	/// Converts the given key to this case style.
+	///
+	/// For the `Camel` and `Snake` styles, words are separated by
+	/// non-alphanumeric characters (which are dropped),
+	/// by an uppercase letter following a lowercase letter or a digit,
+	/// and before the last letter of a sequence of uppercase letters
+	/// followed by a lowercase letter (`HTTPServer` is `HTTP` and `Server`).
+	pub fn convert(&self, key: &str) -> String {
+		match self {
+			Self::Lower => key.to_lowercase(),
+			Self::Upper => key.to_uppercase(),
+			Self::Camel => {
+				let mut result = String::with_capacity(key.len());
+				for (i, word) in words(key).into_iter().enumerate() {
+					let word = word.to_lowercase();
+					let mut chars = word.chars();
+					if i > 0 {
+						result.extend(chars.next().into_iter().flat_map(char::to_uppercase))
+					}
+
+					result.push_str(chars.as_str())
+				}
+
+				result
+			}
+			Self::Snake => words(key)
+				.into_iter()
+				.map(str::to_lowercase)
+				.collect::<Vec<_>>()
+				.join("_"),
+		}
+	}
+}
+
+/// Splits the given key into words.
+///
+/// See [`CaseStyle::convert`].
+fn words(key: &str) -> Vec<&str> {
+	let chars: Vec<(usize, char)> = key.char_indices().collect();
+	let mut words = Vec::new();
+	let mut start = None;
+	for (j, &(i, c)) in chars.iter().enumerate() {
+		if !c.is_alphanumeric() {
+			if let Some(start) = start.take() {
+				words.push(&key[start..i])
+			}
+
+			continue;
+		}
+
+		match start {
+			Some(s) => {
+				let prev = chars[j - 1].1;
+				let next = chars.get(j + 1).map(|&(_, c)| c);
+				if c.is_uppercase()
+					&& (prev.is_lowercase()
+						|| prev.is_numeric()
+						|| (prev.is_uppercase() && next.is_some_and(char::is_lowercase)))
+				{
+					words.push(&key[s..i]);
+					start = Some(i)
+				}
+			}
+			None => start = Some(i),
+		}
+	}
+
+	if let Some(start) = start {
+		words.push(&key[start..])
+	}
+
+	words
+}
+
+/// Checks if some keys of the given object are renamed by `rename`.
+fn has_renamed_keys<T: JsonMut + JsonBuild>(
+	object: &T::Object,
+	rename: &impl Fn(&str) -> Option<String>,
+) -> bool {
+	MapIter::iter(object).any(|(key, _)| rename(&key).is_some())
+}
+
+/// Recursively renames the object keys of `value`, whose pointer is `path`,
+/// recording the pointers of collisions in `collisions`.
+///
+/// `rename` returns the new key of each renamed key.
+pub(crate) fn rename_keys<T: JsonMut + JsonBuild>(
+	value: &mut T,
+	rename: &impl Fn(&str) -> Option<String>,
+	path: &mut String,
+	collisions: &mut Vec<String>,
+) {
+	let len = path.len();
+	match value.as_value_mut() {
+		ValueMut::Array(a) => {
+			for (i, mut item) in a.iter_mut().enumerate() {
+				pointer::push_token(path, &i.to_string());
+				rename_keys(&mut *item, rename, path, collisions);
+				path.truncate(len)
+			}
+		}
+		ValueMut::Object(o) => {
+			if has_renamed_keys::<T>(o, rename) {
+				let mut entries: Vec<(T::Key, T)> = Vec::new();
+				let mut indexes = HashMap::new();
+				for (key, item) in std::mem::take(o) {
+					let key = match rename(&key) {
+						Some(new_key) => T::new_key(&new_key, key.metadata().clone()),
+						None => key,
+					};
+
+					match indexes.get(&*key) {
+						Some(&i) => {
+							pointer::push_token(path, &key);
+							collisions.push(path.clone());
+							path.truncate(len);
+							entries[i] = (key, item)
+						}
+						None => {
+							indexes.insert((*key).to_owned(), entries.len());
+							entries.push((key, item))
+						}
+					}
+				}
+
+				*o = entries.into_iter().collect()
+			}
+
+			for (key, mut item) in o.iter_mut() {
+				pointer::push_token(path, &key);
+				rename_keys(&mut *item, rename, path, collisions);
+				path.truncate(len)
+			}
+		}
+		_ => (),
+	}
+}
@@ -0,0 +1,47 @@
+use crate::{Json, Number, NumberFrom, Value};
+
+/// Policy used by [`Json::sanitize`] to replace non-finite numbers.
+///
+/// JSON has no representation for `NaN` or infinite numbers,
+/// meaning that a value containing such numbers cannot be serialized.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SanitizePolicy {
+	/// Replace non-finite numbers with `null`.
+	Null,
+
+	/// Replace non-finite numbers with `0`.
+	Zero,
+
+	/// Replace non-finite numbers with their textual representation,
+	/// following the JavaScript convention: `"NaN"`, `"Infinity"` or `"-Infinity"`.
+	String,
+}
+
+impl SanitizePolicy {
+	/// Returns the value replacing the given number, if it is not finite.
+	pub fn replacement<T: Json>(&self, n: &T::Number) -> Option<Value<T>>
+	where
+		T::Number: NumberFrom,
+	{
+		let f = n.as_f64_lossy();
+		if f.is_finite() {
+			None
+		} else {
+			let value = match self {
+				Self::Null => Value::Null,
+				Self::Zero => Value::Number(T::Number::from_u64(0)),
+				Self::String => {
+					if f.is_nan() {
+						Value::String("NaN".into())
+					} else if f.is_sign_positive() {
+						Value::String("Infinity".into())
+					} else {
+						Value::String("-Infinity".into())
+					}
+				}
+			};
+
+			Some(value)
+		}
+	}
+}
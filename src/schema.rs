@@ -0,0 +1,158 @@
+use crate::{Json, ValueRef};
+use cc_traits::{Iter, MapIter};
+use std::collections::BTreeMap;
+
+/// Schema inferred from sample JSON documents.
+///
+/// Records every type observed for a value.
+/// When more than one type has been observed, the schema is a union of these types.
+/// A schema with no type at all describes values never observed,
+/// such as the elements of an empty array.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct InferredSchema {
+	/// `null` has been observed.
+	pub null: bool,
+
+	/// A boolean has been observed.
+	pub boolean: bool,
+
+	/// A number has been observed.
+	pub number: bool,
+
+	/// A string has been observed.
+	pub string: bool,
+
+	/// Schema of the elements of the observed arrays, if any.
+	pub array: Option<Box<InferredSchema>>,
+
+	/// Members of the observed objects, if any.
+	pub object: Option<BTreeMap<String, InferredField>>,
+}
+
+/// Object member of an [`InferredSchema`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InferredField {
+	/// Schema of the member value.
+	pub schema: InferredSchema,
+
+	/// The member is missing from some of the observed objects.
+	pub optional: bool,
+}
+
+impl InferredSchema {
+	/// Infers the schema of a single document.
+	///
+	/// Elements of arrays are merged as with [`merge`](Self::merge).
+	pub fn infer<T: Json>(value: &T) -> Self {
+		let mut schema = Self::default();
+		match value.as_value_ref() {
+			ValueRef::Null => schema.null = true,
+			ValueRef::Boolean(_) => schema.boolean = true,
+			ValueRef::Number(_) => schema.number = true,
+			ValueRef::String(_) => schema.string = true,
+			ValueRef::Array(a) => {
+				let mut items = Self::default();
+				for item in Iter::iter(a) {
+					items.merge(Self::infer(&*item))
+				}
+
+				schema.array = Some(Box::new(items))
+			}
+			ValueRef::Object(o) => {
+				let fields = MapIter::iter(o)
+					.map(|(key, item)| {
+						let field = InferredField {
+							schema: Self::infer(&*item),
+							optional: false,
+						};
+
+						(key.to_string(), field)
+					})
+					.collect();
+
+				schema.object = Some(fields)
+			}
+		}
+
+		schema
+	}
+
+	/// Merges the given schema into this one.
+	///
+	/// The resulting schema is the union of both.
+	/// Object members found in only one of the schemas become optional.
+	pub fn merge(&mut self, other: Self) {
+		self.null |= other.null;
+		self.boolean |= other.boolean;
+		self.number |= other.number;
+		self.string |= other.string;
+
+		if let Some(other_items) = other.array {
+			match &mut self.array {
+				Some(items) => items.merge(*other_items),
+				None => self.array = Some(other_items),
+			}
+		}
+
+		if let Some(mut other_fields) = other.object {
+			match &mut self.object {
+				Some(fields) => {
+					for (key, field) in fields.iter_mut() {
+						match other_fields.remove(key) {
+							Some(other_field) => {
+								field.optional |= other_field.optional;
+								field.schema.merge(other_field.schema)
+							}
+							None => field.optional = true,
+						}
+					}
+
+					for (key, mut field) in other_fields {
+						field.optional = true;
+						fields.insert(key, field);
+					}
+				}
+				None => self.object = Some(other_fields),
+			}
+		}
+	}
+
+	/// Returns the number of types observed.
+	pub fn type_count(&self) -> usize {
+		[
+			self.null,
+			self.boolean,
+			self.number,
+			self.string,
+			self.array.is_some(),
+			self.object.is_some(),
+		]
+		.iter()
+		.filter(|b| **b)
+		.count()
+	}
+
+	/// Checks if no type has been observed.
+	pub fn is_empty(&self) -> bool {
+		self.type_count() == 0
+	}
+
+	/// Checks if more than one type has been observed.
+	pub fn is_union(&self) -> bool {
+		self.type_count() > 1
+	}
+}
+
+/// Infers a schema merging the schemas of every given example.
+///
+/// Object members present in some but not all of the examples are optional,
+/// and values of different types in different examples give a type union.
+/// Returns an empty schema if there are no examples.
+pub fn infer_schema_from<A: Json>(examples: impl IntoIterator<Item = A>) -> InferredSchema {
+	let mut schema = InferredSchema::default();
+	for example in examples {
+		schema.merge(InferredSchema::infer(&example))
+	}
+
+	schema
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "nightly")]
+use crate::{JsonBuild, JsonClone, Key, Value};
+#[cfg(feature = "nightly")]
+use cc_traits::{Iter, MapIter};
+
+/// Selection of paths in a JSON value.
+///
+/// Used by [`Json::project`](crate::Json::project) to extract a subset of a value,
+/// in the spirit of GraphQL selection sets.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum Selection {
+	/// Selects the whole value.
+	All,
+
+	/// Selects some members of an object.
+	Members(BTreeMap<String, Selection>),
+
+	/// Applies a selection to every element of an array.
+	Each(Box<Selection>),
+}
+
+impl Selection {
+	/// Creates a new selection selecting nothing.
+	pub fn empty() -> Self {
+		Self::Members(BTreeMap::new())
+	}
+
+	/// Adds the given path to the selection.
+	///
+	/// Each segment of the path is an object key,
+	/// except for the special `*` segment that selects every element of an array.
+	/// The value found at the end of the path is entirely selected.
+	///
+	/// A path cannot go through a value already entirely selected.
+	pub fn select<'a, P: IntoIterator<Item = &'a str>>(&mut self, path: P) {
+		let mut path = path.into_iter();
+		match path.next() {
+			Some(segment) => {
+				if segment == "*" {
+					match self {
+						Self::Each(s) => s.select(path),
+						Self::All => (),
+						_ => {
+							let mut s = Self::empty();
+							s.select(path);
+							*self = Self::Each(Box::new(s))
+						}
+					}
+				} else {
+					match self {
+						Self::Members(members) => members
+							.entry(segment.to_string())
+							.or_insert_with(Self::empty)
+							.select(path),
+						Self::All => (),
+						_ => {
+							let mut s = Self::empty();
+							s.select(path);
+							let mut members = BTreeMap::new();
+							members.insert(segment.to_string(), s);
+							*self = Self::Members(members)
+						}
+					}
+				}
+			}
+			None => *self = Self::All,
+		}
+	}
+
+	/// Extracts the selected parts of the given value.
+	///
+	/// Object members not selected are omitted.
+	/// Selecting members of a value that is not an object,
+	/// or the elements of a value that is not an array, results in `null`.
+	#[cfg(feature = "nightly")]
+	pub fn project<T>(&self, value: &T) -> Value<T>
+	where
+		T: JsonClone + JsonBuild,
+	{
+		match self {
+			Self::All => value.as_value_ref().cloned(),
+			Self::Members(members) => match value.as_object() {
+				Some(o) => Value::Object(
+					MapIter::iter(o)
+						.filter_map(|(key, item)| {
+							members.get(&**key).map(|s| {
+								let key = T::new_key(&key, key.metadata().clone());
+								(key, T::new(s.project(&*item), item.metadata().clone()))
+							})
+						})
+						.collect(),
+				),
+				None => Value::Null,
+			},
+			Self::Each(s) => match value.as_array() {
+				Some(a) => Value::Array(
+					Iter::iter(a)
+						.map(|item| T::new(s.project(&*item), item.metadata().clone()))
+						.collect(),
+				),
+				None => Value::Null,
+			},
+		}
+	}
+}
+
+/// Clones the given value, keeping only the object members whose key satisfies `f`
+/// if it is an object.
+#[cfg(feature = "nightly")]
+pub(crate) fn filter_members<T, F: Fn(&str) -> bool>(value: &T, f: F) -> Value<T>
+where
+	T: JsonClone + JsonBuild,
+{
+	match value.as_object() {
+		Some(o) => Value::Object(
+			MapIter::iter(o)
+				.filter(|(key, _)| f(key))
+				.map(|(key, item)| (T::new_key(&key, key.metadata().clone()), (*item).clone()))
+				.collect(),
+		),
+		None => value.as_value_ref().cloned(),
+	}
+}
+
+impl<'a, P: IntoIterator<Item = &'a str>> std::iter::FromIterator<P> for Selection {
+	fn from_iter<I: IntoIterator<Item = P>>(paths: I) -> Self {
+		let mut selection = Self::empty();
+		for path in paths {
+			selection.select(path)
+		}
+
+		selection
+	}
+}
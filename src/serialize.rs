@@ -0,0 +1,51 @@
+use crate::{Json, Number, Value, ValueRef};
+use cc_traits::{Iter, Len, MapIter};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+/// Serializable view of a value.
+struct SerializeRef<'a, T: Json>(ValueRef<'a, T>);
+
+impl<'a, T: Json> Serialize for SerializeRef<'a, T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self.0 {
+			ValueRef::Null => serializer.serialize_unit(),
+			ValueRef::Boolean(b) => serializer.serialize_bool(b),
+			ValueRef::Number(n) => match n.as_i64() {
+				Some(i) => serializer.serialize_i64(i),
+				None => match n.as_u64() {
+					Some(u) => serializer.serialize_u64(u),
+					None => {
+						serializer.serialize_f64(n.as_f64().unwrap_or_else(|| n.as_f64_lossy()))
+					}
+				},
+			},
+			ValueRef::String(s) => serializer.serialize_str(s),
+			ValueRef::Array(a) => {
+				let mut seq = serializer.serialize_seq(Some(a.len()))?;
+				for item in Iter::iter(a) {
+					seq.serialize_element(&SerializeRef(item.as_value_ref()))?
+				}
+				seq.end()
+			}
+			ValueRef::Object(o) => {
+				let mut map = serializer.serialize_map(Some(o.len()))?;
+				for (key, item) in MapIter::iter(o) {
+					map.serialize_entry(&**key, &SerializeRef(item.as_value_ref()))?
+				}
+				map.end()
+			}
+		}
+	}
+}
+
+impl<T: Json> Serialize for Value<T> {
+	/// Serializes the value, without its metadata.
+	///
+	/// Numbers are serialized as an `i64` or `u64` when they are exactly
+	/// representable as such, and as an `f64` otherwise.
+	/// Numbers that are neither integers nor exactly representable as an `f64`
+	/// are serialized through [`as_f64_lossy`](Number::as_f64_lossy).
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		SerializeRef(self.as_value_ref()).serialize(serializer)
+	}
+}
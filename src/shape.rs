@@ -0,0 +1,60 @@
+use crate::{Json, ValueRef};
+use cc_traits::{Iter, Len, MapIter};
+use std::hash::{Hash, Hasher};
+
+/// Counts the nodes of the given value at each depth.
+///
+/// `depth` is the depth of `value`.
+fn count_depths<T: Json>(value: &T, depth: usize, histogram: &mut Vec<usize>) {
+	if histogram.len() <= depth {
+		histogram.push(0)
+	}
+
+	histogram[depth] += 1;
+
+	match value.as_value_ref() {
+		ValueRef::Array(a) => {
+			for item in Iter::iter(a) {
+				count_depths(&*item, depth + 1, histogram)
+			}
+		}
+		ValueRef::Object(o) => {
+			for (_, item) in MapIter::iter(o) {
+				count_depths(&*item, depth + 1, histogram)
+			}
+		}
+		_ => (),
+	}
+}
+
+pub(crate) fn depth_histogram<T: Json>(value: &T) -> Vec<usize> {
+	let mut histogram = Vec::new();
+	count_depths(value, 0, &mut histogram);
+	histogram
+}
+
+pub(crate) fn structural_shape_hash<T: Json, H: Hasher>(value: &T, h: &mut H) {
+	match value.as_value_ref() {
+		ValueRef::Null => 0u8.hash(h),
+		ValueRef::Boolean(_) => 1u8.hash(h),
+		ValueRef::Number(_) => 2u8.hash(h),
+		ValueRef::String(_) => 3u8.hash(h),
+		ValueRef::Array(a) => {
+			4u8.hash(h);
+			a.len().hash(h);
+			for item in Iter::iter(a) {
+				structural_shape_hash(&*item, h)
+			}
+		}
+		ValueRef::Object(o) => {
+			5u8.hash(h);
+			o.len().hash(h);
+			let mut members: Vec<_> = MapIter::iter(o).collect();
+			members.sort_by(|(a, _), (b, _)| str::cmp(a, b));
+			for (key, item) in members {
+				str::hash(&key, h);
+				structural_shape_hash(&*item, h)
+			}
+		}
+	}
+}
@@ -0,0 +1,564 @@
+use crate::{Json, JsonNew, Key, NumberBuf, Value, ValueMut, ValueRef};
+use cc_traits::{
+	Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Iter, IterMut, Keyed,
+	KeyedRef, Len, MapInsert, MapIter, MapIterMut, PopBack, PushBack, Remove,
+};
+use std::{
+	borrow::Borrow,
+	cmp::Ordering,
+	collections::{btree_map, BTreeMap, HashSet},
+	fmt,
+	hash::{Hash, Hasher},
+	iter::FromIterator,
+	ops::Deref,
+	sync::Arc,
+};
+
+/// JSON value whose subtrees are shared, with copy-on-write semantics.
+///
+/// Arrays, objects and strings are stored behind an [`Arc`],
+/// so cloning a value is O(1) and does not copy its subtrees.
+/// The clones share their arrays and objects until one of them is mutated:
+/// the mutated array or object is then copied (using [`Arc::make_mut`])
+/// if it is still shared, so that the other clones are left unchanged.
+/// Only the array or object being mutated is copied,
+/// its own children remaining shared.
+///
+/// Each node carries some metadata of type `M`,
+/// ignored when comparing or hashing values.
+/// Object members are sorted by key.
+#[derive(Clone)]
+pub struct SharedValue<M = ()> {
+	value: Shared<M>,
+	metadata: M,
+}
+
+#[derive(Clone)]
+enum Shared<M> {
+	Null,
+	Boolean(bool),
+	Number(NumberBuf),
+	String(Arc<str>),
+	Array(SharedArray<M>),
+	Object(SharedObject<M>),
+}
+
+impl<M> SharedValue<M> {
+	/// Returns a mutable reference to the metadata of this value.
+	pub fn metadata_mut(&mut self) -> &mut M {
+		&mut self.metadata
+	}
+}
+
+impl<M: Clone> SharedValue<M> {
+	/// Makes every equal string value contained in this value share the same storage.
+	///
+	/// Object keys are left untouched.
+	/// Shared arrays and objects traversed by this function are copied first,
+	/// as with any other mutation.
+	pub fn intern_strings(&mut self) {
+		self.intern_strings_with(&mut HashSet::new())
+	}
+
+	fn intern_strings_with(&mut self, pool: &mut HashSet<Arc<str>>) {
+		match &mut self.value {
+			Shared::String(s) => match pool.get(&**s) {
+				Some(shared) => *s = shared.clone(),
+				None => {
+					pool.insert(s.clone());
+				}
+			},
+			Shared::Array(a) => {
+				for item in a.iter_mut() {
+					item.intern_strings_with(pool)
+				}
+			}
+			Shared::Object(o) => {
+				for (_, item) in o.iter_mut() {
+					item.intern_strings_with(pool)
+				}
+			}
+			_ => (),
+		}
+	}
+}
+
+impl<M> PartialEq for SharedValue<M> {
+	fn eq(&self, other: &Self) -> bool {
+		match (&self.value, &other.value) {
+			(Shared::Null, Shared::Null) => true,
+			(Shared::Boolean(a), Shared::Boolean(b)) => a == b,
+			(Shared::Number(a), Shared::Number(b)) => a == b,
+			(Shared::String(a), Shared::String(b)) => a == b,
+			(Shared::Array(a), Shared::Array(b)) => a == b,
+			(Shared::Object(a), Shared::Object(b)) => a == b,
+			_ => false,
+		}
+	}
+}
+
+impl<M> Eq for SharedValue<M> {}
+
+impl<M> Hash for SharedValue<M> {
+	fn hash<H: Hasher>(&self, h: &mut H) {
+		match &self.value {
+			Shared::Null => (),
+			Shared::Boolean(b) => b.hash(h),
+			Shared::Number(n) => n.hash(h),
+			Shared::String(s) => s.hash(h),
+			Shared::Array(a) => a.hash(h),
+			Shared::Object(o) => o.hash(h),
+		}
+	}
+}
+
+impl<M> fmt::Debug for SharedValue<M> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match &self.value {
+			Shared::Null => write!(f, "Null"),
+			Shared::Boolean(b) => write!(f, "Boolean({:?})", b),
+			Shared::Number(n) => write!(f, "Number({})", n),
+			Shared::String(s) => write!(f, "String({:?})", s),
+			Shared::Array(a) => f.debug_list().entries(a.0.iter()).finish(),
+			Shared::Object(o) => f.debug_map().entries(o.0.iter()).finish(),
+		}
+	}
+}
+
+/// Object key of a [`SharedValue`].
+///
+/// The metadata is ignored when comparing or hashing keys.
+#[derive(Clone)]
+pub struct SharedKey<M = ()> {
+	key: Arc<str>,
+	metadata: M,
+}
+
+impl<M> Deref for SharedKey<M> {
+	type Target = str;
+
+	fn deref(&self) -> &str {
+		&self.key
+	}
+}
+
+impl<M> Borrow<str> for SharedKey<M> {
+	fn borrow(&self) -> &str {
+		&self.key
+	}
+}
+
+impl<M> PartialEq for SharedKey<M> {
+	fn eq(&self, other: &Self) -> bool {
+		self.key == other.key
+	}
+}
+
+impl<M> Eq for SharedKey<M> {}
+
+impl<M> PartialOrd for SharedKey<M> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<M> Ord for SharedKey<M> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.key.cmp(&other.key)
+	}
+}
+
+impl<M> Hash for SharedKey<M> {
+	fn hash<H: Hasher>(&self, h: &mut H) {
+		self.key.hash(h)
+	}
+}
+
+impl<M> fmt::Debug for SharedKey<M> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.key.fmt(f)
+	}
+}
+
+impl<M> Key<M> for SharedKey<M> {
+	fn metadata(&self) -> &M {
+		&self.metadata
+	}
+}
+
+/// Array of a [`SharedValue`].
+pub struct SharedArray<M = ()>(Arc<Vec<SharedValue<M>>>);
+
+impl<M> SharedArray<M> {
+	/// Checks if the two arrays share the same storage.
+	pub fn ptr_eq(&self, other: &Self) -> bool {
+		Arc::ptr_eq(&self.0, &other.0)
+	}
+}
+
+impl<M> Clone for SharedArray<M> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<M> Default for SharedArray<M> {
+	fn default() -> Self {
+		Self(Arc::new(Vec::new()))
+	}
+}
+
+impl<M> PartialEq for SharedArray<M> {
+	fn eq(&self, other: &Self) -> bool {
+		self.ptr_eq(other) || self.0 == other.0
+	}
+}
+
+impl<M> Eq for SharedArray<M> {}
+
+impl<M> Hash for SharedArray<M> {
+	fn hash<H: Hasher>(&self, h: &mut H) {
+		self.0.hash(h)
+	}
+}
+
+impl<M> Collection for SharedArray<M> {
+	type Item = SharedValue<M>;
+}
+
+impl<M> CollectionRef for SharedArray<M> {
+	type ItemRef<'a>
+		= &'a SharedValue<M>
+	where
+		M: 'a;
+
+	cc_traits::covariant_item_ref!();
+}
+
+impl<M: Clone> CollectionMut for SharedArray<M> {
+	type ItemMut<'a>
+		= &'a mut SharedValue<M>
+	where
+		M: 'a;
+
+	cc_traits::covariant_item_mut!();
+}
+
+impl<M> Len for SharedArray<M> {
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<M> Get<usize> for SharedArray<M> {
+	fn get(&self, i: usize) -> Option<&SharedValue<M>> {
+		self.0.get(i)
+	}
+}
+
+impl<M: Clone> GetMut<usize> for SharedArray<M> {
+	fn get_mut(&mut self, i: usize) -> Option<&mut SharedValue<M>> {
+		Arc::make_mut(&mut self.0).get_mut(i)
+	}
+}
+
+impl<M> Iter for SharedArray<M> {
+	type Iter<'a>
+		= std::slice::Iter<'a, SharedValue<M>>
+	where
+		M: 'a;
+
+	fn iter(&self) -> Self::Iter<'_> {
+		self.0.iter()
+	}
+}
+
+impl<M: Clone> IterMut for SharedArray<M> {
+	type IterMut<'a>
+		= std::slice::IterMut<'a, SharedValue<M>>
+	where
+		M: 'a;
+
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		Arc::make_mut(&mut self.0).iter_mut()
+	}
+}
+
+impl<M: Clone> PushBack for SharedArray<M> {
+	type Output = ();
+
+	fn push_back(&mut self, item: SharedValue<M>) {
+		Arc::make_mut(&mut self.0).push(item)
+	}
+}
+
+impl<M: Clone> PopBack for SharedArray<M> {
+	fn pop_back(&mut self) -> Option<SharedValue<M>> {
+		Arc::make_mut(&mut self.0).pop()
+	}
+}
+
+/// Moves the items out of the array if it is not shared,
+/// and clones them otherwise.
+impl<M: Clone> IntoIterator for SharedArray<M> {
+	type Item = SharedValue<M>;
+	type IntoIter = std::vec::IntoIter<SharedValue<M>>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		Arc::try_unwrap(self.0)
+			.unwrap_or_else(|a| (*a).clone())
+			.into_iter()
+	}
+}
+
+impl<M> FromIterator<SharedValue<M>> for SharedArray<M> {
+	fn from_iter<I: IntoIterator<Item = SharedValue<M>>>(iter: I) -> Self {
+		Self(Arc::new(iter.into_iter().collect()))
+	}
+}
+
+/// Object of a [`SharedValue`].
+pub struct SharedObject<M = ()>(Arc<BTreeMap<SharedKey<M>, SharedValue<M>>>);
+
+impl<M> SharedObject<M> {
+	/// Checks if the two objects share the same storage.
+	pub fn ptr_eq(&self, other: &Self) -> bool {
+		Arc::ptr_eq(&self.0, &other.0)
+	}
+}
+
+impl<M> Clone for SharedObject<M> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<M> Default for SharedObject<M> {
+	fn default() -> Self {
+		Self(Arc::new(BTreeMap::new()))
+	}
+}
+
+impl<M> PartialEq for SharedObject<M> {
+	fn eq(&self, other: &Self) -> bool {
+		self.ptr_eq(other) || self.0 == other.0
+	}
+}
+
+impl<M> Eq for SharedObject<M> {}
+
+impl<M> Hash for SharedObject<M> {
+	fn hash<H: Hasher>(&self, h: &mut H) {
+		self.0.hash(h)
+	}
+}
+
+impl<M> Collection for SharedObject<M> {
+	type Item = SharedValue<M>;
+}
+
+impl<M> CollectionRef for SharedObject<M> {
+	type ItemRef<'a>
+		= &'a SharedValue<M>
+	where
+		M: 'a;
+
+	cc_traits::covariant_item_ref!();
+}
+
+impl<M: Clone> CollectionMut for SharedObject<M> {
+	type ItemMut<'a>
+		= &'a mut SharedValue<M>
+	where
+		M: 'a;
+
+	cc_traits::covariant_item_mut!();
+}
+
+impl<M> Keyed for SharedObject<M> {
+	type Key = SharedKey<M>;
+}
+
+impl<M> KeyedRef for SharedObject<M> {
+	type KeyRef<'a>
+		= &'a SharedKey<M>
+	where
+		M: 'a;
+
+	cc_traits::covariant_key_ref!();
+}
+
+impl<M> Len for SharedObject<M> {
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'q, M> Get<&'q str> for SharedObject<M> {
+	fn get(&self, key: &'q str) -> Option<&SharedValue<M>> {
+		self.0.get(key)
+	}
+}
+
+impl<'q, M> GetKeyValue<&'q str> for SharedObject<M> {
+	fn get_key_value(&self, key: &'q str) -> Option<(&SharedKey<M>, &SharedValue<M>)> {
+		self.0.get_key_value(key)
+	}
+}
+
+impl<'q, M: Clone> GetMut<&'q str> for SharedObject<M> {
+	fn get_mut(&mut self, key: &'q str) -> Option<&mut SharedValue<M>> {
+		Arc::make_mut(&mut self.0).get_mut(key)
+	}
+}
+
+impl<M> MapIter for SharedObject<M> {
+	type Iter<'a>
+		= btree_map::Iter<'a, SharedKey<M>, SharedValue<M>>
+	where
+		M: 'a;
+
+	fn iter(&self) -> Self::Iter<'_> {
+		self.0.iter()
+	}
+}
+
+impl<M: Clone> MapIterMut for SharedObject<M> {
+	type IterMut<'a>
+		= btree_map::IterMut<'a, SharedKey<M>, SharedValue<M>>
+	where
+		M: 'a;
+
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		Arc::make_mut(&mut self.0).iter_mut()
+	}
+}
+
+impl<M: Clone> MapInsert<SharedKey<M>> for SharedObject<M> {
+	type Output = Option<SharedValue<M>>;
+
+	fn insert(&mut self, key: SharedKey<M>, value: SharedValue<M>) -> Option<SharedValue<M>> {
+		Arc::make_mut(&mut self.0).insert(key, value)
+	}
+}
+
+impl<'q, M: Clone> Remove<&'q str> for SharedObject<M> {
+	fn remove(&mut self, key: &'q str) -> Option<SharedValue<M>> {
+		Arc::make_mut(&mut self.0).remove(key)
+	}
+}
+
+/// Moves the members out of the object if it is not shared,
+/// and clones them otherwise.
+impl<M: Clone> IntoIterator for SharedObject<M> {
+	type Item = (SharedKey<M>, SharedValue<M>);
+	type IntoIter = btree_map::IntoIter<SharedKey<M>, SharedValue<M>>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		Arc::try_unwrap(self.0)
+			.unwrap_or_else(|o| (*o).clone())
+			.into_iter()
+	}
+}
+
+impl<M> FromIterator<(SharedKey<M>, SharedValue<M>)> for SharedObject<M> {
+	fn from_iter<I: IntoIterator<Item = (SharedKey<M>, SharedValue<M>)>>(iter: I) -> Self {
+		Self(Arc::new(iter.into_iter().collect()))
+	}
+}
+
+impl<M: Clone + Send + Sync> Json for SharedValue<M> {
+	type MetaData = M;
+	type Number = NumberBuf;
+	type String = Arc<str>;
+	type Array = SharedArray<M>;
+	type Key = SharedKey<M>;
+	type Object = SharedObject<M>;
+
+	/// Returns a reference to the actual JSON value (without the metadata).
+	fn as_value_ref(&self) -> ValueRef<'_, Self> {
+		self.into()
+	}
+
+	/// Returns a mutable reference to the actual JSON value (without the metadata).
+	fn as_value_mut(&mut self) -> ValueMut<'_, Self> {
+		self.into()
+	}
+
+	/// Transforms this JSON value into a `Value` and `MetaData`.
+	fn into_parts(self) -> (Value<Self>, Self::MetaData) {
+		let value = match self.value {
+			Shared::Null => Value::Null,
+			Shared::Boolean(b) => Value::Boolean(b),
+			Shared::Number(n) => Value::Number(n),
+			Shared::String(s) => Value::String(s),
+			Shared::Array(a) => Value::Array(a),
+			Shared::Object(o) => Value::Object(o),
+		};
+
+		(value, self.metadata)
+	}
+
+	/// Returns a reference to the metadata associated to the JSON value.
+	fn metadata(&self) -> &Self::MetaData {
+		&self.metadata
+	}
+
+	/// Returns a pair containing a mutable reference to the JSON value and a reference to its metadata.
+	fn as_pair_mut(&mut self) -> (ValueMut<'_, Self>, &Self::MetaData) {
+		let value = match &mut self.value {
+			Shared::Null => ValueMut::Null,
+			Shared::Boolean(b) => ValueMut::Boolean(*b),
+			Shared::Number(n) => ValueMut::Number(n),
+			Shared::String(s) => ValueMut::String(s),
+			Shared::Array(a) => ValueMut::Array(a),
+			Shared::Object(o) => ValueMut::Object(o),
+		};
+
+		(value, &self.metadata)
+	}
+}
+
+impl<M: Clone + Send + Sync> JsonNew for SharedValue<M> {
+	fn new(value: Value<Self>, metadata: M) -> Self {
+		let value = match value {
+			Value::Null => Shared::Null,
+			Value::Boolean(b) => Shared::Boolean(b),
+			Value::Number(n) => Shared::Number(n),
+			Value::String(s) => Shared::String(s),
+			Value::Array(a) => Shared::Array(a),
+			Value::Object(o) => Shared::Object(o),
+		};
+
+		Self { value, metadata }
+	}
+
+	fn new_key(key: &str, metadata: M) -> SharedKey<M> {
+		SharedKey {
+			key: key.into(),
+			metadata,
+		}
+	}
+}
+
+impl<'a, M: Clone + Send + Sync> From<&'a SharedValue<M>> for ValueRef<'a, SharedValue<M>> {
+	fn from(value: &'a SharedValue<M>) -> Self {
+		match &value.value {
+			Shared::Null => ValueRef::Null,
+			Shared::Boolean(b) => ValueRef::Boolean(*b),
+			Shared::Number(n) => ValueRef::Number(n),
+			Shared::String(s) => ValueRef::String(s),
+			Shared::Array(a) => ValueRef::Array(a),
+			Shared::Object(o) => ValueRef::Object(o),
+		}
+	}
+}
+
+impl<'a, M: Clone + Send + Sync> From<&'a mut SharedValue<M>> for ValueMut<'a, SharedValue<M>> {
+	fn from(value: &'a mut SharedValue<M>) -> Self {
+		value.as_pair_mut().0
+	}
+}
+
+#[cfg(feature = "serde_json-impl")]
+impl<M: Clone + Send + Sync> crate::AsSerdeJson for SharedValue<M> {}
@@ -0,0 +1,364 @@
+use crate::{
+	compare::MemberMatcher,
+	number,
+	parse::{self, ParseError, ParseErrorKind, ParseOptions},
+	trace, Json, NumberBuf, ValueRef,
+};
+use cc_traits::{Iter, Len};
+use std::io::{self, BufReader, Bytes, Read};
+
+/// JSON parsing event.
+pub(crate) enum Event {
+	Null,
+	Boolean(bool),
+	Number(NumberBuf),
+	String(String),
+	StartArray,
+	EndArray,
+	StartObject,
+	Key(String),
+	EndObject,
+}
+
+#[derive(Clone, Copy)]
+enum Container {
+	Array,
+	Object,
+}
+
+/// What the reader expects next.
+#[derive(Clone, Copy)]
+enum Expect {
+	/// A value.
+	Value,
+
+	/// The first element of an array, or its end.
+	FirstElement,
+
+	/// The first key of an object, or its end.
+	FirstKey,
+
+	/// An object key.
+	Key,
+
+	/// A separator or the end of the enclosing container.
+	Separator,
+
+	/// The end of the input.
+	End,
+}
+
+fn invalid_data(kind: ParseErrorKind, position: usize) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, ParseError::new(kind, position))
+}
+
+/// Streaming JSON parser, reading a document event by event.
+pub(crate) struct EventReader<R> {
+	bytes: Bytes<BufReader<R>>,
+	peeked: Option<u8>,
+	position: usize,
+	stack: Vec<Container>,
+	expect: Expect,
+	options: ParseOptions,
+}
+
+impl<R: Read> EventReader<R> {
+	pub fn new(reader: R, options: ParseOptions) -> Self {
+		Self {
+			bytes: BufReader::new(reader).bytes(),
+			peeked: None,
+			position: 0,
+			stack: Vec::new(),
+			expect: Expect::Value,
+			options,
+		}
+	}
+
+	fn error(&self, kind: ParseErrorKind) -> io::Error {
+		invalid_data(kind, self.position)
+	}
+
+	fn peek(&mut self) -> io::Result<Option<u8>> {
+		if self.peeked.is_none() {
+			self.peeked = self.bytes.next().transpose()?
+		}
+
+		Ok(self.peeked)
+	}
+
+	fn next_byte(&mut self) -> io::Result<Option<u8>> {
+		let b = self.peek()?;
+		if b.is_some() {
+			self.peeked = None;
+			self.position += 1
+		}
+
+		Ok(b)
+	}
+
+	/// Returns an error about the next byte.
+	fn unexpected(&mut self) -> io::Error {
+		match self.peek() {
+			Ok(Some(b)) if b.is_ascii() => self.error(ParseErrorKind::UnexpectedChar(b as char)),
+			Ok(Some(_)) => self.error(ParseErrorKind::UnexpectedChar(char::REPLACEMENT_CHARACTER)),
+			Ok(None) => self.error(ParseErrorKind::UnexpectedEnd),
+			Err(e) => e,
+		}
+	}
+
+	fn skip_whitespace(&mut self) -> io::Result<()> {
+		while matches!(self.peek()?, Some(b' ' | b'\t' | b'\n' | b'\r')) {
+			self.next_byte()?;
+		}
+
+		Ok(())
+	}
+
+	fn expect_byte(&mut self, b: u8) -> io::Result<()> {
+		if self.peek()? == Some(b) {
+			self.next_byte()?;
+			Ok(())
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	/// Returns the next event, or `None` at the end of the document.
+	pub fn next_event(&mut self) -> io::Result<Option<Event>> {
+		loop {
+			self.skip_whitespace()?;
+			match self.expect {
+				Expect::End => {
+					return if self.peek()?.is_none() {
+						Ok(None)
+					} else {
+						Err(self.error(ParseErrorKind::TrailingCharacters))
+					}
+				}
+				Expect::Separator => match self.stack.last().copied() {
+					None => self.expect = Expect::End,
+					Some(container) => {
+						let (end, event) = match container {
+							Container::Array => (b']', Event::EndArray),
+							Container::Object => (b'}', Event::EndObject),
+						};
+
+						match self.peek()? {
+							Some(b',') => {
+								self.next_byte()?;
+								self.expect = match container {
+									Container::Array => Expect::Value,
+									Container::Object => Expect::Key,
+								}
+							}
+							Some(b) if b == end => {
+								self.next_byte()?;
+								self.stack.pop();
+								return Ok(Some(event));
+							}
+							_ => return Err(self.unexpected()),
+						}
+					}
+				},
+				Expect::FirstElement => {
+					if self.peek()? == Some(b']') {
+						self.next_byte()?;
+						self.stack.pop();
+						self.expect = Expect::Separator;
+						return Ok(Some(Event::EndArray));
+					}
+
+					self.expect = Expect::Value
+				}
+				Expect::FirstKey => {
+					if self.peek()? == Some(b'}') {
+						self.next_byte()?;
+						self.stack.pop();
+						self.expect = Expect::Separator;
+						return Ok(Some(Event::EndObject));
+					}
+
+					self.expect = Expect::Key
+				}
+				Expect::Key => {
+					if self.peek()? != Some(b'"') {
+						return Err(self.unexpected());
+					}
+
+					let key = self.read_string()?;
+					self.skip_whitespace()?;
+					self.expect_byte(b':')?;
+					self.expect = Expect::Value;
+					return Ok(Some(Event::Key(key)));
+				}
+				Expect::Value => return self.read_value().map(Some),
+			}
+		}
+	}
+
+	fn read_value(&mut self) -> io::Result<Event> {
+		self.expect = Expect::Separator;
+		match self.peek()? {
+			Some(b'n') => self.read_literal("null").map(|_| Event::Null),
+			Some(b't') => self.read_literal("true").map(|_| Event::Boolean(true)),
+			Some(b'f') => self.read_literal("false").map(|_| Event::Boolean(false)),
+			Some(b'-' | b'0'..=b'9') => self.read_number().map(Event::Number),
+			Some(b'"') => self.read_string().map(Event::String),
+			Some(b'[') => {
+				self.enter(Container::Array)?;
+				self.expect = Expect::FirstElement;
+				Ok(Event::StartArray)
+			}
+			Some(b'{') => {
+				self.enter(Container::Object)?;
+				self.expect = Expect::FirstKey;
+				Ok(Event::StartObject)
+			}
+			_ => Err(self.unexpected()),
+		}
+	}
+
+	/// Enters an array or object, checking the depth limit.
+	fn enter(&mut self, container: Container) -> io::Result<()> {
+		if self.stack.len() >= self.options.max_depth {
+			Err(self.error(ParseErrorKind::DepthLimitExceeded))
+		} else {
+			self.next_byte()?;
+			self.stack.push(container);
+			Ok(())
+		}
+	}
+
+	fn read_literal(&mut self, literal: &str) -> io::Result<()> {
+		for b in literal.bytes() {
+			self.expect_byte(b)?
+		}
+
+		Ok(())
+	}
+
+	fn read_number(&mut self) -> io::Result<NumberBuf> {
+		let start = self.position;
+		let mut n = String::new();
+		while let Some(b @ (b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')) = self.peek()? {
+			n.push(b as char);
+			self.next_byte()?;
+		}
+
+		NumberBuf::new(n).map_err(|_| invalid_data(ParseErrorKind::InvalidNumber, start))
+	}
+
+	fn read_string(&mut self) -> io::Result<String> {
+		self.expect_byte(b'"')?;
+		let start = self.position;
+		let mut bytes = Vec::new();
+		let mut escaped = false;
+
+		loop {
+			match self.next_byte()? {
+				None => return Err(self.error(ParseErrorKind::UnexpectedEnd)),
+				Some(b'"') => break,
+				Some(b'\\') => {
+					escaped = true;
+					bytes.push(b'\\');
+					match self.next_byte()? {
+						Some(b) => bytes.push(b),
+						None => return Err(self.error(ParseErrorKind::UnexpectedEnd)),
+					}
+				}
+				Some(b) if b < 0x20 => {
+					self.position -= 1;
+					return Err(self.error(ParseErrorKind::ControlCharacter));
+				}
+				Some(b) => bytes.push(b),
+			}
+		}
+
+		let raw = String::from_utf8(bytes).map_err(|e| {
+			invalid_data(
+				ParseErrorKind::InvalidUtf8,
+				start + e.utf8_error().valid_up_to(),
+			)
+		})?;
+
+		if escaped {
+			parse::unescape(&raw).map_err(|(kind, offset)| invalid_data(kind, start + offset))
+		} else {
+			Ok(raw)
+		}
+	}
+}
+
+/// Compares the given value with the value starting with the given event.
+///
+/// Stops reading the events at the first difference.
+fn eq_events<T: Json, R: Read>(
+	value: &T,
+	event: Event,
+	events: &mut EventReader<R>,
+) -> io::Result<bool> {
+	match (value.as_value_ref(), event) {
+		(ValueRef::Null, Event::Null) => Ok(true),
+		(ValueRef::Boolean(a), Event::Boolean(b)) => Ok(a == b),
+		(ValueRef::Number(a), Event::Number(b)) => Ok(number::numeric_eq(a, &b)),
+		(ValueRef::String(a), Event::String(b)) => Ok(**a == *b),
+		(ValueRef::Array(a), Event::StartArray) => {
+			let mut items = Iter::iter(a);
+			loop {
+				match events.next_event()? {
+					Some(Event::EndArray) => return Ok(items.next().is_none()),
+					Some(event) => match items.next() {
+						Some(item) => {
+							if !eq_events(&*item, event, events)? {
+								return Ok(false);
+							}
+						}
+						None => return Ok(false),
+					},
+					None => return Ok(false),
+				}
+			}
+		}
+		(ValueRef::Object(o), Event::StartObject) => {
+			let mut matcher = MemberMatcher::<T, String>::new(o);
+			loop {
+				match events.next_event()? {
+					Some(Event::Key(key)) => {
+						let item = match matcher.find(key) {
+							Some(item) => item,
+							None => return Ok(false),
+						};
+
+						let equal = match events.next_event()? {
+							Some(event) => eq_events(&*item, event, events)?,
+							None => false,
+						};
+
+						if !equal {
+							return Ok(false);
+						}
+					}
+					Some(Event::EndObject) => return Ok(matcher.matched(o.len())),
+					_ => return Ok(false),
+				}
+			}
+		}
+		_ => Ok(false),
+	}
+}
+
+pub(crate) fn eq_stream<T: Json, R: Read>(value: &T, reader: R) -> io::Result<bool> {
+	let _operation = trace::operation!("eq_stream");
+	let mut events = EventReader::new(reader, ParseOptions::default());
+	let equal = match events.next_event()? {
+		Some(event) => eq_events(value, event, &mut events)?,
+		None => false,
+	};
+
+	if equal {
+		// Make sure the document is well-formed until the end.
+		events.next_event()?;
+	}
+
+	Ok(equal)
+}
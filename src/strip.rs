@@ -0,0 +1,179 @@
+use crate::{Json, JsonBuild, Number, NumberFrom, Value, ValueRef};
+use cc_traits::{Iter, MapIter};
+
+/// Converts a value into another JSON type, dropping its metadata.
+///
+/// The structure of `value` is rebuilt recursively,
+/// and every value and key of the result is given the default metadata.
+/// Integers representable as an `i64` or `u64` are converted exactly,
+/// and other numbers through their lexical representation
+/// (see [`Number::as_str`]) when both types support it.
+/// Remaining numbers are approximated by the nearest `f64`.
+///
+/// ```
+/// # #[cfg(feature = "serde_json-impl")]
+/// # {
+/// use generic_json::{strip_metadata, JsonNew, MetaKey, MetaValue, NumberBuf};
+/// use std::ops::Range;
+///
+/// type Span = Range<usize>;
+///
+/// // `{"a": [1.5, true]}`
+/// let value: MetaValue<Span> = MetaValue::object(
+///     vec![(
+///         MetaKey::new("a".to_string(), 1..4),
+///         MetaValue::array(
+///             vec![
+///                 MetaValue::number(NumberBuf::new("1.5".to_string()).unwrap(), 7..10),
+///                 MetaValue::boolean(true, 12..16),
+///             ]
+///             .into_iter()
+///             .collect(),
+///             6..17,
+///         ),
+///     )]
+///     .into_iter()
+///     .collect(),
+///     0..18,
+/// );
+///
+/// let value: serde_json::Value = strip_metadata(value);
+/// assert_eq!(value, serde_json::json!({ "a": [1.5, true] }));
+/// # }
+/// ```
+pub fn strip_metadata<T: Json, U: JsonBuild>(value: T) -> U
+where
+	U::MetaData: Default,
+{
+	U::new(to_default_value(&value), U::MetaData::default())
+}
+
+/// Converts a value into another JSON type, consuming it.
+///
+/// This bridges any two backends, for instance `serde_json::Value`
+/// and `ijson::IValue`, without dedicated `From` implementations.
+/// Arrays and objects are taken apart with [`Json::into_value`],
+/// and the result is rebuilt with [`JsonNew`](crate::JsonNew).
+/// As with [`strip_metadata`], every value and key of the result is given
+/// the default metadata.
+///
+/// Integers representable as an `i64` or `u64` are converted exactly.
+/// Other numbers are converted through their lexical representation
+/// (see [`Number::as_str`]) when both types support it,
+/// and approximated by the nearest `f64` otherwise:
+/// big integers and high precision decimals may then lose precision.
+/// Numbers that `B` cannot represent at all are converted into `null`.
+///
+/// ```
+/// # #[cfg(all(feature = "serde_json-impl", feature = "ijson-impl"))]
+/// # {
+/// use generic_json::convert;
+/// use serde_json::json;
+///
+/// let value = json!({
+///   "name": "generic-json",
+///   "numbers": [0, -1, 18446744073709551615u64, 0.1, 1e300],
+///   "nested": { "empty": [], "null": null }
+/// });
+///
+/// let ivalue: ijson::IValue = convert(value.clone());
+/// assert_eq!(ivalue["numbers"][2].as_number().unwrap().to_u64(), Some(u64::MAX));
+///
+/// let back: serde_json::Value = convert(ivalue);
+/// assert_eq!(back, value);
+/// # }
+/// ```
+pub fn convert<A: Json, B: JsonBuild>(a: A) -> B
+where
+	B::MetaData: Default,
+{
+	let value = match a.into_value() {
+		Value::Null => Value::Null,
+		Value::Boolean(b) => Value::Boolean(b),
+		Value::Number(n) => convert_number(&n).map_or(Value::Null, Value::Number),
+		Value::String(s) => Value::String((*s).into()),
+		Value::Array(a) => Value::Array(a.into_iter().map(convert).collect()),
+		Value::Object(o) => Value::Object(
+			o.into_iter()
+				.map(|(key, item)| (B::new_key(&key, B::MetaData::default()), convert(item)))
+				.collect(),
+		),
+	};
+
+	B::new(value, B::MetaData::default())
+}
+
+/// Copies a value into another JSON type.
+///
+/// Unlike [`convert`], the source value is left untouched,
+/// and does not need to implement `Clone`:
+/// the structure of `a` is walked by reference and rebuilt in `B`,
+/// following the same conversion rules.
+/// Every value and key of the result is given the default metadata.
+///
+/// ```
+/// # #[cfg(all(feature = "serde_json-impl", feature = "ijson-impl"))]
+/// # {
+/// use generic_json::{to_backend, TriviaValue};
+/// use serde_json::json;
+///
+/// let value = json!({ "a": [1, -2.5, "x", true], "b": { "c": null } });
+/// let ivalue: ijson::IValue = to_backend(&value);
+/// let back: serde_json::Value = to_backend(&ivalue);
+/// assert_eq!(back, value);
+///
+/// let trivia = TriviaValue::parse(r#"{ "a": [1, -2.5, "x", true], /* comment */ "b": { "c": null } }"#).unwrap();
+/// assert_eq!(to_backend::<_, serde_json::Value>(&trivia), value);
+/// # }
+/// ```
+pub fn to_backend<A: Json, B: JsonBuild>(a: &A) -> B
+where
+	B::MetaData: Default,
+{
+	B::new(to_default_value(a), B::MetaData::default())
+}
+
+/// Converts the given number into a number of type `N`.
+///
+/// Returns `None` if the number cannot be represented, even approximately.
+pub(crate) fn convert_number<M: Number, N: NumberFrom>(n: &M) -> Option<N> {
+	match (n.as_i64(), n.as_u64()) {
+		(Some(i), _) => Some(N::from_i64(i)),
+		(None, Some(u)) => Some(N::from_u64(u)),
+		(None, None) => n
+			.as_str()
+			.and_then(N::from_lexical)
+			.or_else(|| N::from_f64(n.as_f64_lossy())),
+	}
+}
+
+/// Converts the given value into a value of type `T`,
+/// giving every value and key the default metadata.
+///
+/// Numbers that cannot be represented by `T` are converted into `null`.
+pub(crate) fn to_default_value<S: Json, T: JsonBuild>(value: &S) -> Value<T>
+where
+	T::MetaData: Default,
+{
+	match value.as_value_ref() {
+		ValueRef::Null => Value::Null,
+		ValueRef::Boolean(b) => Value::Boolean(b),
+		ValueRef::Number(n) => convert_number(n).map_or(Value::Null, Value::Number),
+		ValueRef::String(s) => Value::String((**s).into()),
+		ValueRef::Array(a) => Value::Array(
+			Iter::iter(a)
+				.map(|item| to_default_value(&*item).with_default())
+				.collect(),
+		),
+		ValueRef::Object(o) => Value::Object(
+			MapIter::iter(o)
+				.map(|(key, item)| {
+					(
+						T::new_key(&key, T::MetaData::default()),
+						to_default_value(&*item).with_default(),
+					)
+				})
+				.collect(),
+		),
+	}
+}
@@ -0,0 +1,351 @@
+use crate::{Json, NumberBuf, Value, ValueMut, ValueRef};
+use cc_traits::{Collection, CollectionRef, Get, GetKeyValue, Keyed, KeyedRef, Len, MapIter};
+use std::{fmt, rc::Rc};
+
+/// Read-only JSON view over Rust values.
+///
+/// Allows a Rust value, such as a struct, to present itself as a JSON value
+/// without being serialized first.
+/// Objects are described by a [`StructObject`] associating each key to
+/// a closure computing the field value on demand.
+///
+/// This type does not implement [`JsonNew`](crate::JsonNew):
+/// values are created from Rust values using the `From` conversions.
+/// Objects are read-only, their fields being computed on access.
+/// For the same reason, [`pointer`](crate::pointer) cannot be used
+/// on these values: use `with_pointer` instead (requires the `nightly` feature).
+///
+/// ```
+/// use cc_traits::Get;
+/// use generic_json::{Json, StructJson, StructObject};
+///
+/// struct Point {
+///     x: i64,
+///     y: i64,
+/// }
+///
+/// impl Point {
+///     fn as_json(&self) -> StructJson<'_> {
+///         StructObject::new()
+///             .field("x", move || self.x)
+///             .field("y", move || self.y)
+///             .into()
+///     }
+/// }
+///
+/// let p = Point { x: 1, y: 2 };
+/// let json = p.as_json();
+/// let y = json.as_object().unwrap().get("y").unwrap();
+/// assert_eq!(y.as_i64(), Some(2));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum StructJson<'a> {
+	Null,
+	Boolean(bool),
+	Number(NumberBuf),
+	String(String),
+	Array(Vec<StructJson<'a>>),
+	Object(StructObject<'a>),
+}
+
+impl<'a> From<bool> for StructJson<'a> {
+	fn from(b: bool) -> Self {
+		Self::Boolean(b)
+	}
+}
+
+impl<'a> From<NumberBuf> for StructJson<'a> {
+	fn from(n: NumberBuf) -> Self {
+		Self::Number(n)
+	}
+}
+
+macro_rules! struct_json_from_int {
+	($($ty:ty),*) => {
+		$(
+			impl<'a> From<$ty> for StructJson<'a> {
+				fn from(n: $ty) -> Self {
+					Self::Number(n.into())
+				}
+			}
+		)*
+	};
+}
+
+struct_json_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Non-finite numbers are converted into `null`.
+impl<'a> From<f64> for StructJson<'a> {
+	fn from(f: f64) -> Self {
+		match NumberBuf::from_f64(f) {
+			Some(n) => Self::Number(n),
+			None => Self::Null,
+		}
+	}
+}
+
+impl<'a, 'b> From<&'b str> for StructJson<'a> {
+	fn from(s: &'b str) -> Self {
+		Self::String(s.to_string())
+	}
+}
+
+impl<'a> From<String> for StructJson<'a> {
+	fn from(s: String) -> Self {
+		Self::String(s)
+	}
+}
+
+impl<'a, T: Into<StructJson<'a>>> From<Option<T>> for StructJson<'a> {
+	fn from(value: Option<T>) -> Self {
+		match value {
+			Some(value) => value.into(),
+			None => Self::Null,
+		}
+	}
+}
+
+impl<'a, T: Into<StructJson<'a>>> From<Vec<T>> for StructJson<'a> {
+	fn from(items: Vec<T>) -> Self {
+		Self::Array(items.into_iter().map(Into::into).collect())
+	}
+}
+
+impl<'a> From<StructObject<'a>> for StructJson<'a> {
+	fn from(o: StructObject<'a>) -> Self {
+		Self::Object(o)
+	}
+}
+
+type Field<'a> = Rc<dyn 'a + Fn() -> StructJson<'a>>;
+
+/// Object of a [`StructJson`] value.
+///
+/// Each field value is computed by a closure every time it is accessed.
+/// Fields are iterated in the order they have been added.
+#[derive(Clone, Default)]
+pub struct StructObject<'a> {
+	fields: Vec<(String, Field<'a>)>,
+}
+
+impl<'a> StructObject<'a> {
+	/// Creates a new object with no field.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a field to the object, whose value is computed by `f`.
+	///
+	/// If the object already has a field with the same key,
+	/// it is replaced.
+	pub fn field<K: Into<String>, V: Into<StructJson<'a>>, F: 'a + Fn() -> V>(
+		mut self,
+		key: K,
+		f: F,
+	) -> Self {
+		let key = key.into();
+		let field: Field<'a> = Rc::new(move || f().into());
+		match self.fields.iter_mut().find(|(k, _)| *k == key) {
+			Some(entry) => entry.1 = field,
+			None => self.fields.push((key, field)),
+		}
+
+		self
+	}
+
+	fn get_field(&self, key: &str) -> Option<&(String, Field<'a>)> {
+		self.fields.iter().find(|(k, _)| k == key)
+	}
+}
+
+impl<'a> PartialEq for StructObject<'a> {
+	/// Compares the computed values of the fields, regardless of their order.
+	fn eq(&self, other: &Self) -> bool {
+		self.fields.len() == other.fields.len()
+			&& self
+				.fields
+				.iter()
+				.all(|(key, f)| match other.get_field(key) {
+					Some((_, g)) => f() == g(),
+					None => false,
+				})
+	}
+}
+
+impl<'a> Eq for StructObject<'a> {}
+
+impl<'a> fmt::Debug for StructObject<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_map()
+			.entries(self.fields.iter().map(|(key, field)| (key, field())))
+			.finish()
+	}
+}
+
+impl<'a> Collection for StructObject<'a> {
+	type Item = StructJson<'a>;
+}
+
+impl<'a> CollectionRef for StructObject<'a> {
+	type ItemRef<'r>
+		= Rc<StructJson<'a>>
+	where
+		Self: 'r;
+
+	fn upcast_item_ref<'short, 'long: 'short>(r: Rc<StructJson<'a>>) -> Rc<StructJson<'a>>
+	where
+		Self: 'long,
+	{
+		r
+	}
+}
+
+impl<'a> Keyed for StructObject<'a> {
+	type Key = String;
+}
+
+impl<'a> KeyedRef for StructObject<'a> {
+	type KeyRef<'r>
+		= &'r String
+	where
+		Self: 'r;
+
+	fn upcast_key_ref<'short, 'long: 'short>(r: &'long String) -> &'short String
+	where
+		Self: 'long,
+	{
+		r
+	}
+}
+
+impl<'a> Len for StructObject<'a> {
+	fn len(&self) -> usize {
+		self.fields.len()
+	}
+}
+
+impl<'a, 'k> Get<&'k str> for StructObject<'a> {
+	fn get(&self, key: &'k str) -> Option<Rc<StructJson<'a>>> {
+		self.get_field(key).map(|(_, f)| Rc::new(f()))
+	}
+}
+
+impl<'a, 'k> GetKeyValue<&'k str> for StructObject<'a> {
+	fn get_key_value(&self, key: &'k str) -> Option<(&String, Rc<StructJson<'a>>)> {
+		self.get_field(key).map(|(k, f)| (k, Rc::new(f())))
+	}
+}
+
+impl<'a> MapIter for StructObject<'a> {
+	type Iter<'r>
+		= Fields<'r, 'a>
+	where
+		Self: 'r;
+
+	fn iter(&self) -> Fields<'_, 'a> {
+		Fields(self.fields.iter())
+	}
+}
+
+impl<'a> IntoIterator for StructObject<'a> {
+	type Item = (String, StructJson<'a>);
+	type IntoIter = IntoFields<'a>;
+
+	fn into_iter(self) -> IntoFields<'a> {
+		IntoFields(self.fields.into_iter())
+	}
+}
+
+/// Iterator over the fields of a [`StructObject`].
+pub struct Fields<'r, 'a>(std::slice::Iter<'r, (String, Field<'a>)>);
+
+impl<'r, 'a> Iterator for Fields<'r, 'a> {
+	type Item = (&'r String, Rc<StructJson<'a>>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|(key, f)| (key, Rc::new(f())))
+	}
+}
+
+/// Iterator over the fields of a [`StructObject`], computing their values.
+pub struct IntoFields<'a>(std::vec::IntoIter<(String, Field<'a>)>);
+
+impl<'a> Iterator for IntoFields<'a> {
+	type Item = (String, StructJson<'a>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|(key, f)| (key, f()))
+	}
+}
+
+impl<'a> Json for StructJson<'a> {
+	type MetaData = ();
+	type Number = NumberBuf;
+	type String = String;
+	type Array = Vec<Self>;
+	type Key = String;
+	type Object = StructObject<'a>;
+
+	/// Returns a reference to the actual JSON value (without the metadata).
+	fn as_value_ref(&self) -> ValueRef<'_, Self> {
+		self.into()
+	}
+
+	/// Returns a mutable reference to the actual JSON value (without the metadata).
+	fn as_value_mut(&mut self) -> ValueMut<'_, Self> {
+		self.into()
+	}
+
+	/// Transforms this JSON value into a `Value` and `MetaData`.
+	fn into_parts(self) -> (Value<Self>, Self::MetaData) {
+		let value = match self {
+			Self::Null => Value::Null,
+			Self::Boolean(b) => Value::Boolean(b),
+			Self::Number(n) => Value::Number(n),
+			Self::String(s) => Value::String(s),
+			Self::Array(a) => Value::Array(a),
+			Self::Object(o) => Value::Object(o),
+		};
+
+		(value, ())
+	}
+
+	/// Returns a reference to the metadata associated to the JSON value.
+	fn metadata(&self) -> &Self::MetaData {
+		&()
+	}
+
+	/// Returns a pair containing a mutable reference to the JSON value and a reference to its metadata.
+	fn as_pair_mut(&mut self) -> (ValueMut<'_, Self>, &Self::MetaData) {
+		(self.as_value_mut(), &())
+	}
+}
+
+impl<'b, 'a> From<&'b StructJson<'a>> for ValueRef<'b, StructJson<'a>> {
+	fn from(value: &'b StructJson<'a>) -> Self {
+		match value {
+			StructJson::Null => ValueRef::Null,
+			StructJson::Boolean(b) => ValueRef::Boolean(*b),
+			StructJson::Number(n) => ValueRef::Number(n),
+			StructJson::String(s) => ValueRef::String(s),
+			StructJson::Array(a) => ValueRef::Array(a),
+			StructJson::Object(o) => ValueRef::Object(o),
+		}
+	}
+}
+
+impl<'b, 'a> From<&'b mut StructJson<'a>> for ValueMut<'b, StructJson<'a>> {
+	fn from(value: &'b mut StructJson<'a>) -> Self {
+		match value {
+			StructJson::Null => ValueMut::Null,
+			StructJson::Boolean(b) => ValueMut::Boolean(*b),
+			StructJson::Number(n) => ValueMut::Number(n),
+			StructJson::String(s) => ValueMut::String(s),
+			StructJson::Array(a) => ValueMut::Array(a),
+			StructJson::Object(o) => ValueMut::Object(o),
+		}
+	}
+}
+
+#[cfg(feature = "serde_json-impl")]
+impl<'a> crate::AsSerdeJson for StructJson<'a> {}
@@ -0,0 +1,54 @@
+/// Instrumented operation, completed when dropped.
+///
+/// When the `tracing` feature is enabled, the operation runs inside a `DEBUG` span
+/// and emits an event with its duration when it completes.
+/// Otherwise it does nothing.
+pub(crate) struct Operation {
+	#[cfg(feature = "tracing")]
+	_span: tracing::span::EnteredSpan,
+
+	#[cfg(feature = "tracing")]
+	start: std::time::Instant,
+}
+
+impl Operation {
+	#[cfg(feature = "tracing")]
+	pub fn new(span: tracing::Span) -> Self {
+		Self {
+			_span: span.entered(),
+			start: std::time::Instant::now(),
+		}
+	}
+
+	#[cfg(not(feature = "tracing"))]
+	pub fn new() -> Self {
+		Self {}
+	}
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for Operation {
+	fn drop(&mut self) {
+		let elapsed = self.start.elapsed();
+		tracing::debug!(elapsed_us = elapsed.as_micros() as u64, "done")
+	}
+}
+
+/// Starts an instrumented operation with the given name and fields.
+///
+/// The operation lasts until the returned value is dropped.
+#[cfg(feature = "tracing")]
+macro_rules! operation {
+	($name:literal $(, $field:ident = $value:expr)*) => {
+		$crate::trace::Operation::new(tracing::debug_span!($name $(, $field = $value)*))
+	};
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! operation {
+	($name:literal $(, $field:ident = $value:expr)*) => {
+		$crate::trace::Operation::new()
+	};
+}
+
+pub(crate) use operation;
@@ -0,0 +1,192 @@
+use crate::{pointer, Json, JsonIntoRef, ValueRef};
+use cc_traits::{Get, KeyedRef, MapIter};
+use std::{fmt, rc::Rc};
+
+/// Component of a [`JsonPath`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PathComponent<'a> {
+	/// Array index.
+	Index(usize),
+
+	/// Object key.
+	Key(&'a str),
+}
+
+/// Path from the root of a JSON value to one of its nodes.
+///
+/// Paths are immutable lists sharing their prefix with the path of the parent node,
+/// making them cheap to clone and extend.
+/// They are displayed as JSON Pointers.
+#[derive(Clone, Default)]
+pub struct JsonPath<'a>(Option<Rc<PathNode<'a>>>);
+
+struct PathNode<'a> {
+	parent: JsonPath<'a>,
+	component: PathComponent<'a>,
+	depth: usize,
+}
+
+impl<'a> JsonPath<'a> {
+	/// Creates the path to the root.
+	pub fn root() -> Self {
+		Self::default()
+	}
+
+	/// Checks if this is the path to the root.
+	pub fn is_root(&self) -> bool {
+		self.0.is_none()
+	}
+
+	/// Returns the number of components of this path.
+	pub fn depth(&self) -> usize {
+		self.0.as_ref().map(|node| node.depth).unwrap_or(0)
+	}
+
+	/// Returns the last component of this path, if any.
+	pub fn last(&self) -> Option<PathComponent<'a>> {
+		self.0.as_ref().map(|node| node.component)
+	}
+
+	/// Returns the path to the parent node, if any.
+	pub fn parent(&self) -> Option<&Self> {
+		self.0.as_ref().map(|node| &node.parent)
+	}
+
+	/// Returns the path extended with the given component.
+	pub fn join(&self, component: PathComponent<'a>) -> Self {
+		Self(Some(Rc::new(PathNode {
+			parent: self.clone(),
+			component,
+			depth: self.depth() + 1,
+		})))
+	}
+
+	/// Returns the components of this path, starting from the root.
+	pub fn components(&self) -> Vec<PathComponent<'a>> {
+		let mut components = Vec::with_capacity(self.depth());
+		let mut path = self;
+		while let Some(node) = &path.0 {
+			components.push(node.component);
+			path = &node.parent
+		}
+
+		components.reverse();
+		components
+	}
+}
+
+impl<'a> Drop for JsonPath<'a> {
+	fn drop(&mut self) {
+		// Drop long paths iteratively to avoid overflowing the stack.
+		let mut next = self.0.take();
+		while let Some(node) = next {
+			next = match Rc::try_unwrap(node) {
+				Ok(mut node) => node.parent.0.take(),
+				Err(_) => None,
+			}
+		}
+	}
+}
+
+impl<'a> PartialEq for JsonPath<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.depth() == other.depth() && self.components() == other.components()
+	}
+}
+
+impl<'a> Eq for JsonPath<'a> {}
+
+impl<'a> fmt::Display for JsonPath<'a> {
+	/// Formats the path as a JSON Pointer.
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let mut ptr = String::new();
+		for component in self.components() {
+			match component {
+				PathComponent::Index(i) => pointer::push_token(&mut ptr, &i.to_string()),
+				PathComponent::Key(key) => pointer::push_token(&mut ptr, key),
+			}
+		}
+
+		f.write_str(&ptr)
+	}
+}
+
+impl<'a> fmt::Debug for JsonPath<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "JsonPath({})", self)
+	}
+}
+
+/// Children of a container being visited by a [`TreeIter`].
+enum Frame<'a, T: Json>
+where
+	T::Object: 'a,
+{
+	Array(JsonPath<'a>, &'a T::Array, usize),
+	Object(JsonPath<'a>, <T::Object as MapIter>::Iter<'a>),
+}
+
+/// Pre-order iterator over the nodes of a JSON value.
+///
+/// Returned by [`Json::iter_tree`](crate::Json::iter_tree).
+/// The nodes are visited lazily using an explicit stack,
+/// so arbitrarily deep values do not overflow the call stack.
+/// Object members are visited in the iteration order of the backend.
+pub struct TreeIter<'a, T: Json>
+where
+	T::Object: 'a,
+{
+	next: Option<(JsonPath<'a>, ValueRef<'a, T>)>,
+	stack: Vec<Frame<'a, T>>,
+}
+
+impl<'a, T: Json> TreeIter<'a, T> {
+	pub(crate) fn new(value: &'a T) -> Self {
+		Self {
+			next: Some((JsonPath::root(), value.as_value_ref())),
+			stack: Vec::new(),
+		}
+	}
+}
+
+impl<'a, T: JsonIntoRef> Iterator for TreeIter<'a, T>
+where
+	T::Key: 'a,
+	T::Object: KeyedRef<KeyRef<'a> = &'a T::Key>,
+{
+	type Item = (JsonPath<'a>, ValueRef<'a, T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (path, value) = match self.next.take() {
+			Some(node) => node,
+			None => loop {
+				let child = match self.stack.last_mut()? {
+					Frame::Array(path, a, i) => a.get(*i).map(|item| {
+						*i += 1;
+						(path.join(PathComponent::Index(*i - 1)), item.into())
+					}),
+					Frame::Object(path, members) => members
+						.next()
+						.map(|(key, item)| (path.join(PathComponent::Key(key)), item.into())),
+				};
+
+				match child {
+					Some(child) => break child,
+					None => {
+						self.stack.pop();
+					}
+				}
+			},
+		};
+
+		match value {
+			ValueRef::Array(a) => self.stack.push(Frame::Array(path.clone(), a, 0)),
+			ValueRef::Object(o) => self
+				.stack
+				.push(Frame::Object(path.clone(), MapIter::iter(o))),
+			_ => (),
+		}
+
+		Some((path, value))
+	}
+}
@@ -0,0 +1,361 @@
+use crate::{
+	number,
+	parse::{self, ParseError, ParseErrorKind, ParseOptions},
+	write, Json, JsonNew, Key, MetaKey, MetaObject, MetaValue, NumberBuf, Value, ValueRef,
+};
+use cc_traits::{Iter, Len, MapInsert, MapIter};
+use std::fmt;
+
+/// Trivia surrounding a JSON value or object key:
+/// whitespace and comments.
+///
+/// Used as metadata by [`TriviaValue`].
+#[derive(Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct Trivia {
+	/// Trivia preceding the value or key.
+	pub before: String,
+
+	/// Trivia following the value or key
+	/// (before the `:` following a key).
+	pub after: String,
+
+	/// Trivia found between the brackets of an empty array or object.
+	pub inner: String,
+
+	/// Original literal of a string value or key, including the quotes.
+	///
+	/// It is used to preserve escape sequences when serializing
+	/// the string, as long as it has not been modified.
+	pub raw: Option<String>,
+}
+
+/// JSON value preserving the whitespace and comments of the parsed document.
+///
+/// Parsing a document with [`TriviaValue::parse`] and formatting the result
+/// with [`Display`](fmt::Display) gives back the original document byte for byte,
+/// as long as the value has not been modified.
+/// Modified values are formatted using their remaining trivia,
+/// and added values are formatted compactly.
+///
+/// Comments are either line comments (`// ...`) or block comments (`/* ... */`).
+pub type TriviaValue = MetaValue<Trivia>;
+
+impl MetaValue<Trivia> {
+	/// Parses the given JSON document, that may contain comments,
+	/// keeping its trivia.
+	pub fn parse(input: &str) -> Result<Self, ParseError> {
+		Self::parse_with(input, ParseOptions::default())
+	}
+
+	/// Parses the given JSON document, that may contain comments,
+	/// keeping its trivia, with the given options.
+	pub fn parse_with(input: &str, options: ParseOptions) -> Result<Self, ParseError> {
+		let mut parser = TriviaParser {
+			input,
+			position: 0,
+			options,
+		};
+
+		let before = parser.trivia()?;
+		let value = parser.element(before, 0)?;
+		if parser.position < input.len() {
+			Err(parser.error(ParseErrorKind::TrailingCharacters))
+		} else {
+			Ok(value)
+		}
+	}
+}
+
+struct TriviaParser<'a> {
+	input: &'a str,
+	position: usize,
+	options: ParseOptions,
+}
+
+impl<'a> TriviaParser<'a> {
+	fn error(&self, kind: ParseErrorKind) -> ParseError {
+		ParseError::new(kind, self.position)
+	}
+
+	fn peek(&self) -> Option<u8> {
+		self.input.as_bytes().get(self.position).copied()
+	}
+
+	/// Returns an error about the next character.
+	fn unexpected(&self) -> ParseError {
+		match self.input[self.position..].chars().next() {
+			Some(c) => self.error(ParseErrorKind::UnexpectedChar(c)),
+			None => self.error(ParseErrorKind::UnexpectedEnd),
+		}
+	}
+
+	fn expect(&mut self, b: u8) -> Result<(), ParseError> {
+		if self.peek() == Some(b) {
+			self.position += 1;
+			Ok(())
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	/// Parses whitespace and comments.
+	fn trivia(&mut self) -> Result<String, ParseError> {
+		let start = self.position;
+		loop {
+			let rest = &self.input[self.position..];
+			if rest.starts_with([' ', '\t', '\n', '\r']) {
+				self.position += 1
+			} else if rest.starts_with("//") {
+				self.position += rest.find('\n').unwrap_or(rest.len())
+			} else if let Some(comment) = rest.strip_prefix("/*") {
+				match comment.find("*/") {
+					Some(i) => self.position += i + 4,
+					None => {
+						self.position = self.input.len();
+						return Err(self.error(ParseErrorKind::UnexpectedEnd));
+					}
+				}
+			} else {
+				break Ok(self.input[start..self.position].to_string());
+			}
+		}
+	}
+
+	/// Parses a value preceded by the given trivia, and the trivia following it.
+	///
+	/// `depth` is the number of arrays and objects enclosing the value.
+	fn element(&mut self, before: String, depth: usize) -> Result<TriviaValue, ParseError> {
+		let mut value = self.value(depth)?;
+		let trivia = value.metadata_mut();
+		trivia.before = before;
+		trivia.after = self.trivia()?;
+		Ok(value)
+	}
+
+	fn value(&mut self, depth: usize) -> Result<TriviaValue, ParseError> {
+		let mut trivia = Trivia::default();
+		let value = match self.peek() {
+			Some(b'n') => self.literal("null").map(|_| Value::Null)?,
+			Some(b't') => self.literal("true").map(|_| Value::Boolean(true))?,
+			Some(b'f') => self.literal("false").map(|_| Value::Boolean(false))?,
+			Some(b'-' | b'0'..=b'9') => Value::Number(self.number()?),
+			Some(b'"') => {
+				let (raw, s) = self.string()?;
+				trivia.raw = Some(raw);
+				Value::String(s)
+			}
+			Some(b'[') => {
+				let depth = self.enter(depth)?;
+				let mut items = Vec::new();
+				let mut before = self.trivia()?;
+				if self.peek() == Some(b']') {
+					trivia.inner = before
+				} else {
+					loop {
+						if items.len() == self.options.max_array_elements {
+							return Err(self.error(ParseErrorKind::ArrayLimitExceeded));
+						}
+
+						items.push(self.element(before, depth)?);
+						match self.peek() {
+							Some(b',') => {
+								self.position += 1;
+								before = self.trivia()?
+							}
+							Some(b']') => break,
+							_ => return Err(self.unexpected()),
+						}
+					}
+				}
+
+				self.position += 1;
+				Value::Array(items)
+			}
+			Some(b'{') => {
+				let depth = self.enter(depth)?;
+				let mut object = MetaObject::new();
+				let mut before = self.trivia()?;
+				if self.peek() == Some(b'}') {
+					trivia.inner = before
+				} else {
+					loop {
+						if self.peek() != Some(b'"') {
+							return Err(self.unexpected());
+						}
+
+						if object.len() == self.options.max_object_members {
+							return Err(self.error(ParseErrorKind::ObjectLimitExceeded));
+						}
+
+						let (raw, key) = self.string()?;
+						let key_trivia = Trivia {
+							before,
+							after: self.trivia()?,
+							inner: String::new(),
+							raw: Some(raw),
+						};
+
+						self.expect(b':')?;
+						let before_value = self.trivia()?;
+						let value = self.element(before_value, depth)?;
+						object.insert(MetaKey::new(key, key_trivia), value);
+						match self.peek() {
+							Some(b',') => {
+								self.position += 1;
+								before = self.trivia()?
+							}
+							Some(b'}') => break,
+							_ => return Err(self.unexpected()),
+						}
+					}
+				}
+
+				self.position += 1;
+				Value::Object(object)
+			}
+			_ => return Err(self.unexpected()),
+		};
+
+		Ok(TriviaValue::new(value, trivia))
+	}
+
+	/// Enters an array or object, checking the depth limit.
+	///
+	/// Returns the depth of the elements of the array or object.
+	fn enter(&mut self, depth: usize) -> Result<usize, ParseError> {
+		if depth >= self.options.max_depth {
+			Err(self.error(ParseErrorKind::DepthLimitExceeded))
+		} else {
+			self.position += 1;
+			Ok(depth + 1)
+		}
+	}
+
+	fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+		for b in literal.bytes() {
+			self.expect(b)?
+		}
+
+		Ok(())
+	}
+
+	fn number(&mut self) -> Result<NumberBuf, ParseError> {
+		let start = self.position;
+		while let Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E') = self.peek() {
+			self.position += 1
+		}
+
+		let lexical = &self.input[start..self.position];
+		if number::is_valid(lexical) {
+			Ok(NumberBuf::new(lexical.to_string()).unwrap())
+		} else {
+			Err(ParseError::new(ParseErrorKind::InvalidNumber, start))
+		}
+	}
+
+	/// Parses a string, returning its literal (including the quotes) and its value.
+	fn string(&mut self) -> Result<(String, String), ParseError> {
+		let start = self.position;
+		self.position += 1;
+		let mut escaped = false;
+		loop {
+			match self.peek() {
+				None => return Err(self.error(ParseErrorKind::UnexpectedEnd)),
+				Some(b'"') => break,
+				Some(b'\\') => {
+					escaped = true;
+					self.position += 2
+				}
+				Some(b) if b < 0x20 => return Err(self.error(ParseErrorKind::ControlCharacter)),
+				Some(_) => self.position += 1,
+			}
+		}
+
+		self.position += 1;
+		let raw = &self.input[start..self.position];
+		let content = &raw[1..raw.len() - 1];
+		let value = if escaped {
+			parse::unescape(content)
+				.map_err(|(kind, offset)| ParseError::new(kind, start + 1 + offset))?
+		} else {
+			content.to_string()
+		};
+
+		Ok((raw.to_string(), value))
+	}
+}
+
+/// Writes the given string literal,
+/// using its original literal if it still designates the same string.
+fn write_string(f: &mut fmt::Formatter, s: &str, raw: Option<&str>) -> fmt::Result {
+	if let Some(raw) = raw {
+		let content = &raw[1..raw.len() - 1];
+		let unchanged = if content.contains('\\') {
+			parse::unescape(content).is_ok_and(|decoded| decoded == s)
+		} else {
+			content == s
+		};
+
+		if unchanged {
+			return f.write_str(raw);
+		}
+	}
+
+	write::write_string(s, f)
+}
+
+fn write_element(f: &mut fmt::Formatter, value: &TriviaValue) -> fmt::Result {
+	let trivia = value.metadata();
+	f.write_str(&trivia.before)?;
+	match value.as_value_ref() {
+		ValueRef::Null => f.write_str("null")?,
+		ValueRef::Boolean(b) => write!(f, "{}", b)?,
+		ValueRef::Number(n) => f.write_str(n.as_str())?,
+		ValueRef::String(s) => write_string(f, s, trivia.raw.as_deref())?,
+		ValueRef::Array(a) => {
+			f.write_str("[")?;
+			if a.is_empty() {
+				f.write_str(&trivia.inner)?
+			}
+
+			for (i, item) in Iter::iter(a).enumerate() {
+				if i > 0 {
+					f.write_str(",")?
+				}
+
+				write_element(f, item)?
+			}
+
+			f.write_str("]")?
+		}
+		ValueRef::Object(o) => {
+			f.write_str("{")?;
+			if o.is_empty() {
+				f.write_str(&trivia.inner)?
+			}
+
+			for (i, (key, item)) in MapIter::iter(o).enumerate() {
+				if i > 0 {
+					f.write_str(",")?
+				}
+
+				let key_trivia = key.metadata();
+				f.write_str(&key_trivia.before)?;
+				write_string(f, key, key_trivia.raw.as_deref())?;
+				f.write_str(&key_trivia.after)?;
+				f.write_str(":")?;
+				write_element(f, item)?
+			}
+
+			f.write_str("}")?
+		}
+	}
+
+	f.write_str(&trivia.after)
+}
+
+impl fmt::Display for MetaValue<Trivia> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write_element(f, self)
+	}
+}
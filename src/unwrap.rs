@@ -0,0 +1,42 @@
+use crate::{JsonMut, ValueMut};
+use cc_traits::{IterMut, Len, MapIterMut, PopBack};
+
+/// Recursively replaces the single-element arrays of `value`,
+/// whose object path is `path`, by their element when `should_unwrap` allows it.
+pub(crate) fn unwrap_single_arrays<T: JsonMut, F: FnMut(&[&str]) -> bool>(
+	value: &mut T,
+	path: &mut Vec<String>,
+	should_unwrap: &mut F,
+) {
+	let element = match value.as_value_mut() {
+		ValueMut::Array(a) if a.len() == 1 => {
+			let keys: Vec<&str> = path.iter().map(String::as_str).collect();
+			if should_unwrap(&keys) {
+				a.pop_back()
+			} else {
+				None
+			}
+		}
+		_ => None,
+	};
+
+	if let Some(element) = element {
+		*value = element
+	}
+
+	match value.as_value_mut() {
+		ValueMut::Array(a) => {
+			for mut item in a.iter_mut() {
+				unwrap_single_arrays(&mut *item, path, should_unwrap)
+			}
+		}
+		ValueMut::Object(o) => {
+			for (key, mut item) in o.iter_mut() {
+				path.push((**key).to_string());
+				unwrap_single_arrays(&mut *item, path, should_unwrap);
+				path.pop();
+			}
+		}
+		_ => (),
+	}
+}
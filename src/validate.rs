@@ -0,0 +1,157 @@
+use crate::{compare, pointer, CompareOptions, Json, Number, ValueKind, ValueRef};
+use cc_traits::{Get, Iter, MapIter};
+use std::fmt;
+
+/// Schema validation error.
+///
+/// Returned by [`Json::validate_schema`](crate::Json::validate_schema).
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SchemaError {
+	pointer: String,
+	keyword: &'static str,
+}
+
+impl SchemaError {
+	/// Returns the JSON Pointer to the invalid value in the instance.
+	pub fn pointer(&self) -> &str {
+		&self.pointer
+	}
+
+	/// Returns the schema keyword that failed.
+	pub fn keyword(&self) -> &'static str {
+		self.keyword
+	}
+}
+
+impl fmt::Display for SchemaError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "`{}` failed at `{}`", self.keyword, self.pointer)
+	}
+}
+
+impl std::error::Error for SchemaError {}
+
+/// Checks that the given value has the given JSON Schema type name.
+fn has_type<T: Json>(value: &T, name: &str) -> bool {
+	match (value.as_value_ref(), name) {
+		(ValueRef::Number(n), "integer") => {
+			n.is_integer() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+		}
+		(value, name) => {
+			let kind = match value.kind() {
+				ValueKind::Null => "null",
+				ValueKind::Boolean => "boolean",
+				ValueKind::Number => "number",
+				ValueKind::String => "string",
+				ValueKind::Array => "array",
+				ValueKind::Object => "object",
+			};
+
+			kind == name
+		}
+	}
+}
+
+/// Validates `value` against `schema`, appending the errors to `errors`.
+///
+/// `path` is the JSON Pointer to `value`.
+pub(crate) fn validate<T: Json, S: Json>(
+	value: &T,
+	schema: &S,
+	path: &mut String,
+	errors: &mut Vec<SchemaError>,
+) {
+	let schema = match schema.as_object() {
+		Some(schema) => schema,
+		None => return,
+	};
+
+	let mut fail = |keyword| {
+		errors.push(SchemaError {
+			pointer: path.clone(),
+			keyword,
+		})
+	};
+
+	if let Some(ty) = schema.get("type") {
+		let valid = match ty.as_value_ref() {
+			ValueRef::String(name) => has_type(value, name),
+			ValueRef::Array(names) => Iter::iter(names)
+				.any(|name| name.as_str().is_some_and(|name| has_type(value, name))),
+			_ => true,
+		};
+
+		if !valid {
+			fail("type")
+		}
+	}
+
+	if let Some(values) = schema.get("enum") {
+		if let Some(values) = values.as_array() {
+			let options = CompareOptions {
+				numeric_coercion: true,
+				..Default::default()
+			};
+
+			if !Iter::iter(values).any(|v| compare::semantically_eq(value, &*v, &options)) {
+				fail("enum")
+			}
+		}
+	}
+
+	if let ValueRef::Number(n) = value.as_value_ref() {
+		let n = n.as_f64_lossy();
+		if let Some(min) = schema.get("minimum") {
+			if min.as_number().is_some_and(|min| n < min.as_f64_lossy()) {
+				fail("minimum")
+			}
+		}
+
+		if let Some(max) = schema.get("maximum") {
+			if max.as_number().is_some_and(|max| n > max.as_f64_lossy()) {
+				fail("maximum")
+			}
+		}
+	}
+
+	let len = path.len();
+	match value.as_value_ref() {
+		ValueRef::Array(a) => {
+			if let Some(items) = schema.get("items") {
+				for (i, item) in Iter::iter(a).enumerate() {
+					pointer::push_token(path, &i.to_string());
+					validate(&*item, &*items, path, errors);
+					path.truncate(len)
+				}
+			}
+		}
+		ValueRef::Object(o) => {
+			if let Some(required) = schema.get("required") {
+				for key in required.as_array().into_iter().flat_map(Iter::iter) {
+					if let Some(key) = key.as_str() {
+						if o.get(key).is_none() {
+							pointer::push_token(path, key);
+							errors.push(SchemaError {
+								pointer: path.clone(),
+								keyword: "required",
+							});
+							path.truncate(len)
+						}
+					}
+				}
+			}
+
+			if let Some(properties) = schema.get("properties") {
+				let properties = properties.as_object();
+				for (key, item) in MapIter::iter(o) {
+					if let Some(property) = properties.and_then(|p| p.get(&**key)) {
+						pointer::push_token(path, &key);
+						validate(&*item, &*property, path, errors);
+						path.truncate(len)
+					}
+				}
+			}
+		}
+		_ => (),
+	}
+}
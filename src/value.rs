@@ -1,10 +1,12 @@
-use crate::{Json, JsonNew, ValueMut, ValueRef};
+use crate::{Json, JsonNew, Number, ValueMut, ValueRef};
+use cc_traits::{Get, Iter, Len, MapIter};
 use std::{
 	borrow::Cow,
 	cmp::Ordering,
 	fmt,
 	hash::{Hash, Hasher},
 	iter::{FromIterator, IntoIterator},
+	ops::{Index, IndexMut},
 };
 
 /// Any JSON value.
@@ -28,7 +30,54 @@ pub enum Value<T: Json> {
 	Object(T::Object),
 }
 
+/// Kind of JSON value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum ValueKind {
+	Null,
+	Boolean,
+	Number,
+	String,
+	Array,
+	Object,
+}
+
+impl fmt::Display for ValueKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Null => write!(f, "null"),
+			Self::Boolean => write!(f, "boolean"),
+			Self::Number => write!(f, "number"),
+			Self::String => write!(f, "string"),
+			Self::Array => write!(f, "array"),
+			Self::Object => write!(f, "object"),
+		}
+	}
+}
+
 impl<T: Json> Value<T> {
+	/// Returns the kind of this value.
+	pub fn kind(&self) -> ValueKind {
+		match self {
+			Self::Null => ValueKind::Null,
+			Self::Boolean(_) => ValueKind::Boolean,
+			Self::Number(_) => ValueKind::Number,
+			Self::String(_) => ValueKind::String,
+			Self::Array(_) => ValueKind::Array,
+			Self::Object(_) => ValueKind::Object,
+		}
+	}
+
+	/// Compares this value with `other`, following a total order.
+	///
+	/// Values of different kinds are ordered as with `PartialOrd`:
+	/// `null`, booleans, numbers, strings, arrays then objects.
+	/// Numbers are compared with [`Number::total_cmp`],
+	/// arrays lexicographically, and objects as lists of members sorted by key.
+	/// Metadata is ignored.
+	pub fn total_cmp(&self, other: &Self) -> Ordering {
+		total_cmp(self.as_value_ref(), other.as_value_ref())
+	}
+
 	/// Returns `true` if the value is a `Null`. Returns `false` otherwise.
 	pub fn is_null(&self) -> bool {
 		matches!(self, Self::Null)
@@ -140,7 +189,7 @@ impl<T: Json> Value<T> {
 		}
 	}
 
-	pub fn as_value_ref(&self) -> ValueRef<T> {
+	pub fn as_value_ref(&self) -> ValueRef<'_, T> {
 		match self {
 			Self::Null => ValueRef::Null,
 			Self::Boolean(b) => ValueRef::Boolean(*b),
@@ -151,7 +200,7 @@ impl<T: Json> Value<T> {
 		}
 	}
 
-	pub fn as_value_mut(&mut self) -> ValueMut<T> {
+	pub fn as_value_mut(&mut self) -> ValueMut<'_, T> {
 		match self {
 			Self::Null => ValueMut::Null,
 			Self::Boolean(b) => ValueMut::Boolean(*b),
@@ -168,6 +217,17 @@ impl<T: Json> Value<T> {
 		std::mem::swap(&mut value, self);
 		value
 	}
+
+	/// Turns this value into an iterator over the members of the object,
+	/// without their metadata.
+	///
+	/// The iterator is empty if the value is not an object.
+	pub fn into_object_iter(self) -> IntoObjectIter<T> {
+		match self {
+			Self::Object(o) => IntoObjectIter(Some(o.into_iter())),
+			_ => IntoObjectIter(None),
+		}
+	}
 }
 
 impl<T: JsonNew> Value<T>
@@ -278,7 +338,7 @@ impl<'a, T: Json> PartialEq<&'a str> for Value<T> {
 	}
 }
 
-impl<'a, T: Json> PartialEq<String> for Value<T> {
+impl<T: Json> PartialEq<String> for Value<T> {
 	fn eq(&self, other: &String) -> bool {
 		match self {
 			Self::String(s) => s.as_ref() == *other,
@@ -349,6 +409,133 @@ where
 	}
 }
 
+/// Compares two values following the total order of [`Value::total_cmp`].
+pub(crate) fn total_cmp<T: Json>(a: ValueRef<T>, b: ValueRef<T>) -> Ordering {
+	match (a, b) {
+		(ValueRef::Null, ValueRef::Null) => Ordering::Equal,
+		(ValueRef::Boolean(a), ValueRef::Boolean(b)) => a.cmp(&b),
+		(ValueRef::Number(a), ValueRef::Number(b)) => a.total_cmp(b),
+		(ValueRef::String(a), ValueRef::String(b)) => str::cmp(a, b),
+		(ValueRef::Array(a), ValueRef::Array(b)) => {
+			let mut b_items = Iter::iter(b);
+			for a_item in Iter::iter(a) {
+				match b_items.next() {
+					Some(b_item) => match total_cmp(a_item.as_value_ref(), b_item.as_value_ref()) {
+						Ordering::Equal => (),
+						ordering => return ordering,
+					},
+					None => return Ordering::Greater,
+				}
+			}
+
+			if b_items.next().is_some() {
+				Ordering::Less
+			} else {
+				Ordering::Equal
+			}
+		}
+		(ValueRef::Object(a), ValueRef::Object(b)) => {
+			let mut a_members: Vec<_> = MapIter::iter(a).collect();
+			a_members.sort_by(|(a, _), (b, _)| str::cmp(a, b));
+			let mut b_members: Vec<_> = MapIter::iter(b).collect();
+			b_members.sort_by(|(a, _), (b, _)| str::cmp(a, b));
+			for ((a_key, a_item), (b_key, b_item)) in a_members.iter().zip(&b_members) {
+				match str::cmp(a_key, b_key)
+					.then_with(|| total_cmp(a_item.as_value_ref(), b_item.as_value_ref()))
+				{
+					Ordering::Equal => (),
+					ordering => return ordering,
+				}
+			}
+
+			a_members.len().cmp(&b_members.len())
+		}
+		(a, b) => a.kind().cmp(&b.kind()),
+	}
+}
+
+impl<T: Json> Index<usize> for Value<T>
+where
+	T::Array: Index<usize, Output = T>,
+{
+	type Output = T;
+
+	/// Returns the element of the array at the given index.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an array, or if the index is out of bounds.
+	/// See [`ValueRef::get_index`] for a non-panicking alternative.
+	fn index(&self, i: usize) -> &T {
+		match self {
+			Self::Array(a) if i < a.len() => &a[i],
+			Self::Array(_) => panic!("index {} out of bounds", i),
+			_ => panic!("cannot index {} with an integer", self.kind()),
+		}
+	}
+}
+
+impl<'q, T: Json> Index<&'q str> for Value<T>
+where
+	T::Object: Index<&'q str, Output = T>,
+{
+	type Output = T;
+
+	/// Returns the value associated to the given key in the object.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an object, or if there is no such key.
+	/// See [`ValueRef::get_key`] for a non-panicking alternative.
+	fn index(&self, key: &'q str) -> &T {
+		match self {
+			Self::Object(o) if o.get(key).is_some() => &o[key],
+			Self::Object(_) => panic!("no such key `{}`", key),
+			_ => panic!("cannot index {} with a string", self.kind()),
+		}
+	}
+}
+
+impl<T: Json> IndexMut<usize> for Value<T>
+where
+	T::Array: IndexMut<usize, Output = T>,
+{
+	/// Returns the element of the array at the given index.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an array, or if the index is out of bounds.
+	/// See [`ValueMut::get_mut_index`] for a non-panicking alternative.
+	fn index_mut(&mut self, i: usize) -> &mut T {
+		let kind = self.kind();
+		match self {
+			Self::Array(a) if i < a.len() => &mut a[i],
+			Self::Array(_) => panic!("index {} out of bounds", i),
+			_ => panic!("cannot index {} with an integer", kind),
+		}
+	}
+}
+
+impl<'q, T: Json> IndexMut<&'q str> for Value<T>
+where
+	T::Object: IndexMut<&'q str, Output = T>,
+{
+	/// Returns the value associated to the given key in the object.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is not an object, or if there is no such key.
+	/// See [`ValueMut::get_mut_key`] for a non-panicking alternative.
+	fn index_mut(&mut self, key: &'q str) -> &mut T {
+		let kind = self.kind();
+		match self {
+			Self::Object(o) if o.get(key).is_some() => &mut o[key],
+			Self::Object(_) => panic!("no such key `{}`", key),
+			_ => panic!("cannot index {} with a string", kind),
+		}
+	}
+}
+
 impl<T: Json> From<()> for Value<T> {
 	fn from(_: ()) -> Self {
 		Self::Null
@@ -464,3 +651,45 @@ where
 		Self::Array(T::Array::from_iter(iter.into_iter().map(Into::into)))
 	}
 }
+
+/// Iterates over the elements of an array value, without their metadata.
+///
+/// The iterator is empty if the value is not an array.
+impl<T: Json> IntoIterator for Value<T> {
+	type Item = Value<T>;
+	type IntoIter = IntoArrayIter<T>;
+
+	fn into_iter(self) -> IntoArrayIter<T> {
+		match self {
+			Self::Array(a) => IntoArrayIter(Some(a.into_iter())),
+			_ => IntoArrayIter(None),
+		}
+	}
+}
+
+/// Consuming iterator over the elements of an array [`Value`].
+pub struct IntoArrayIter<T: Json>(Option<<T::Array as IntoIterator>::IntoIter>);
+
+impl<T: Json> Iterator for IntoArrayIter<T> {
+	type Item = Value<T>;
+
+	fn next(&mut self) -> Option<Value<T>> {
+		self.0.as_mut()?.next().map(Json::into_value)
+	}
+}
+
+/// Consuming iterator over the members of an object [`Value`].
+///
+/// See [`Value::into_object_iter`].
+pub struct IntoObjectIter<T: Json>(Option<<T::Object as IntoIterator>::IntoIter>);
+
+impl<T: Json> Iterator for IntoObjectIter<T> {
+	type Item = (String, Value<T>);
+
+	fn next(&mut self) -> Option<(String, Value<T>)> {
+		self.0
+			.as_mut()?
+			.next()
+			.map(|(key, value)| ((*key).to_owned(), value.into_value()))
+	}
+}
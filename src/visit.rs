@@ -0,0 +1,95 @@
+use crate::{Json, ValueRef};
+
+/// JSON value visitor.
+///
+/// Dispatches on the kind of a value through [`ValueRef::accept`].
+/// Every method defaults to [`visit_default`](Self::visit_default),
+/// so that only the relevant cases have to be implemented.
+///
+/// ```
+/// # #[cfg(feature = "serde_json-impl")]
+/// # {
+/// use generic_json::{Json, Visit};
+///
+/// /// Counts the strings of a value.
+/// struct CountStrings;
+///
+/// impl<T: Json> Visit<T> for CountStrings {
+///     type Output = usize;
+///
+///     fn visit_default(&mut self) -> usize {
+///         0
+///     }
+///
+///     fn visit_string(&mut self, _s: &T::String) -> usize {
+///         1
+///     }
+///
+///     fn visit_array(&mut self, a: &T::Array) -> usize {
+///         cc_traits::Iter::iter(a)
+///             .map(|item| item.as_value_ref().accept(self))
+///             .sum()
+///     }
+///
+///     fn visit_object(&mut self, o: &T::Object) -> usize {
+///         cc_traits::MapIter::iter(o)
+///             .map(|(_, item)| item.as_value_ref().accept(self))
+///             .sum()
+///     }
+/// }
+///
+/// let value = serde_json::json!({ "a": ["x", 1, { "b": "y", "c": null }], "d": "z" });
+/// assert_eq!(value.as_value_ref().accept(&mut CountStrings), 3);
+/// # }
+/// ```
+pub trait Visit<T: Json> {
+	/// Result of the visit.
+	type Output;
+
+	/// Visits a value not handled by a more specific method.
+	fn visit_default(&mut self) -> Self::Output;
+
+	/// Visits `null`.
+	fn visit_null(&mut self) -> Self::Output {
+		self.visit_default()
+	}
+
+	/// Visits a boolean.
+	fn visit_bool(&mut self, _b: bool) -> Self::Output {
+		self.visit_default()
+	}
+
+	/// Visits a number.
+	fn visit_number(&mut self, _n: &T::Number) -> Self::Output {
+		self.visit_default()
+	}
+
+	/// Visits a string.
+	fn visit_string(&mut self, _s: &T::String) -> Self::Output {
+		self.visit_default()
+	}
+
+	/// Visits an array.
+	fn visit_array(&mut self, _a: &T::Array) -> Self::Output {
+		self.visit_default()
+	}
+
+	/// Visits an object.
+	fn visit_object(&mut self, _o: &T::Object) -> Self::Output {
+		self.visit_default()
+	}
+}
+
+impl<'a, T: Json> ValueRef<'a, T> {
+	/// Calls the method of `visitor` matching the kind of this value.
+	pub fn accept<V: Visit<T>>(self, visitor: &mut V) -> V::Output {
+		match self {
+			Self::Null => visitor.visit_null(),
+			Self::Boolean(b) => visitor.visit_bool(b),
+			Self::Number(n) => visitor.visit_number(n),
+			Self::String(s) => visitor.visit_string(s),
+			Self::Array(a) => visitor.visit_array(a),
+			Self::Object(o) => visitor.visit_object(o),
+		}
+	}
+}
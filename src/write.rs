@@ -0,0 +1,203 @@
+use crate::{escape, number, Json, Value, ValueRef};
+use cc_traits::{Iter, Len, MapIter};
+use std::{fmt, io};
+
+/// Pretty-printing configuration.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PrettyConfig {
+	/// Number of spaces per indentation level.
+	///
+	/// Defaults to 2.
+	pub indent: usize,
+}
+
+impl Default for PrettyConfig {
+	fn default() -> Self {
+		Self { indent: 2 }
+	}
+}
+
+/// JSON value formatted as a JSON document.
+///
+/// Returned by [`display`] and [`display_pretty`].
+///
+/// Numbers are written using their lexical representation when the backend stores it.
+/// Formatting fails if the value contains a non-finite number,
+/// which has no JSON representation.
+pub struct JsonDisplay<'a, T> {
+	value: &'a T,
+	pretty: Option<PrettyConfig>,
+}
+
+/// Formats the given value as a compact JSON document.
+pub fn display<T: Json>(value: &T) -> JsonDisplay<'_, T> {
+	JsonDisplay {
+		value,
+		pretty: None,
+	}
+}
+
+/// Formats the given value as a pretty-printed JSON document,
+/// with one array element or object member per line.
+pub fn display_pretty<T: Json>(value: &T, config: PrettyConfig) -> JsonDisplay<'_, T> {
+	JsonDisplay {
+		value,
+		pretty: Some(config),
+	}
+}
+
+impl<'a, T: Json> fmt::Display for JsonDisplay<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write_value(self.value.as_value_ref(), f, self.pretty.as_ref(), 0)
+	}
+}
+
+impl<T: Json> fmt::Display for Value<T> {
+	/// Formats the value as a compact JSON document.
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write_value(self.as_value_ref(), f, None, 0)
+	}
+}
+
+/// Writes the given string as a JSON string literal.
+///
+/// The characters `"` and `\` and the control characters are escaped.
+pub(crate) fn write_string<W: fmt::Write>(s: &str, writer: &mut W) -> fmt::Result {
+	let mut rest = s;
+	writer.write_char('"')?;
+	loop {
+		// The escaped characters are ASCII, so `i` is a char boundary.
+		let i = escape::next_escape(rest.as_bytes());
+		writer.write_str(&rest[..i])?;
+		match rest.as_bytes().get(i) {
+			Some(&b) => {
+				match b {
+					b'"' => writer.write_str("\\\"")?,
+					b'\\' => writer.write_str("\\\\")?,
+					b'\n' => writer.write_str("\\n")?,
+					b'\r' => writer.write_str("\\r")?,
+					b'\t' => writer.write_str("\\t")?,
+					0x08 => writer.write_str("\\b")?,
+					0x0c => writer.write_str("\\f")?,
+					b => write!(writer, "\\u{:04x}", b)?,
+				}
+
+				rest = &rest[i + 1..]
+			}
+			None => break,
+		}
+	}
+	writer.write_char('"')
+}
+
+/// Writes a new line followed by the indentation of the given depth.
+fn write_indent<W: fmt::Write>(writer: &mut W, config: &PrettyConfig, depth: usize) -> fmt::Result {
+	writer.write_char('\n')?;
+	for _ in 0..(config.indent * depth) {
+		writer.write_char(' ')?
+	}
+
+	Ok(())
+}
+
+/// Writes the given value.
+///
+/// `depth` is the number of arrays and objects enclosing the value,
+/// used for pretty-printing.
+fn write_value<T: Json, W: fmt::Write>(
+	value: ValueRef<T>,
+	writer: &mut W,
+	pretty: Option<&PrettyConfig>,
+	depth: usize,
+) -> fmt::Result {
+	match value {
+		ValueRef::Null => writer.write_str("null"),
+		ValueRef::Boolean(true) => writer.write_str("true"),
+		ValueRef::Boolean(false) => writer.write_str("false"),
+		ValueRef::Number(n) => writer.write_str(&number::to_json_string(n).ok_or(fmt::Error)?),
+		ValueRef::String(s) => write_string(s, writer),
+		ValueRef::Array(a) => {
+			writer.write_char('[')?;
+			for (i, item) in Iter::iter(a).enumerate() {
+				if i > 0 {
+					writer.write_char(',')?
+				}
+
+				if let Some(config) = pretty {
+					write_indent(writer, config, depth + 1)?
+				}
+
+				write_value(item.as_value_ref(), writer, pretty, depth + 1)?
+			}
+
+			if let Some(config) = pretty {
+				if !a.is_empty() {
+					write_indent(writer, config, depth)?
+				}
+			}
+
+			writer.write_char(']')
+		}
+		ValueRef::Object(o) => {
+			writer.write_char('{')?;
+			for (i, (key, item)) in MapIter::iter(o).enumerate() {
+				if i > 0 {
+					writer.write_char(',')?
+				}
+
+				if let Some(config) = pretty {
+					write_indent(writer, config, depth + 1)?
+				}
+
+				write_string(&key, writer)?;
+				writer.write_char(':')?;
+				if pretty.is_some() {
+					writer.write_char(' ')?
+				}
+
+				write_value(item.as_value_ref(), writer, pretty, depth + 1)?
+			}
+
+			if let Some(config) = pretty {
+				if !o.is_empty() {
+					write_indent(writer, config, depth)?
+				}
+			}
+
+			writer.write_char('}')
+		}
+	}
+}
+
+/// Adapter writing formatted text to an `io::Write`, keeping the I/O error.
+struct IoAdapter<W> {
+	inner: W,
+	error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<W> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.inner.write_all(s.as_bytes()).map_err(|e| {
+			self.error = Some(e);
+			fmt::Error
+		})
+	}
+}
+
+/// Writes the given value as a compact JSON document.
+///
+/// Returns an error of kind [`InvalidData`](io::ErrorKind::InvalidData)
+/// if the value contains a non-finite number.
+pub(crate) fn write<T: Json, W: io::Write>(value: &T, writer: W) -> io::Result<()> {
+	let mut adapter = IoAdapter {
+		inner: writer,
+		error: None,
+	};
+
+	write_value(value.as_value_ref(), &mut adapter, None, 0).map_err(|_| {
+		adapter
+			.error
+			.take()
+			.unwrap_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "non-finite number"))
+	})
+}
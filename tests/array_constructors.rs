@@ -0,0 +1,60 @@
+#![cfg(feature = "nightly")]
+use cc_traits::{Iter, Len};
+use generic_json::{Json, JsonBuild, MetaValue};
+
+fn check<T: JsonBuild>()
+where
+	T::MetaData: Default,
+	i64: Into<T::Number>,
+{
+	let value = T::number_array(vec![1i64, -2, i64::MAX], T::MetaData::default());
+	let numbers: Vec<_> = Iter::iter(value.as_array().unwrap())
+		.map(|item| item.as_i64().unwrap())
+		.collect();
+	assert_eq!(numbers, [1, -2, i64::MAX]);
+
+	let value = T::string_array(vec!["a", "", "é"], T::MetaData::default());
+	let strings: Vec<_> = Iter::iter(value.as_array().unwrap())
+		.map(|item| item.as_str().unwrap().to_string())
+		.collect();
+	assert_eq!(strings, ["a", "", "é"]);
+
+	let value = T::bool_array(vec![true, false], T::MetaData::default());
+	let booleans: Vec<_> = Iter::iter(value.as_array().unwrap())
+		.map(|item| item.as_bool().unwrap())
+		.collect();
+	assert_eq!(booleans, [true, false]);
+
+	assert_eq!(
+		T::number_array(Vec::<i64>::new(), T::MetaData::default())
+			.as_array()
+			.unwrap()
+			.len(),
+		0
+	);
+}
+
+#[test]
+fn meta_value() {
+	check::<MetaValue>();
+
+	let value: MetaValue<u8> = MetaValue::string_array(vec![String::from("x")], 1);
+	assert_eq!(value.metadata(), &1);
+	assert_eq!(value[0].metadata(), &0);
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json() {
+	check::<serde_json::Value>();
+	assert_eq!(
+		serde_json::Value::string_array(vec!["a", "b"], ()),
+		serde_json::json!(["a", "b"])
+	);
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn ijson() {
+	check::<ijson::IValue>();
+}
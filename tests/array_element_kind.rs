@@ -0,0 +1,32 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{Json, ValueKind};
+use serde_json::json;
+
+#[test]
+fn homogeneous_arrays() {
+	assert_eq!(
+		json!([1, 2.5, -3]).array_element_kind(),
+		Some(Some(ValueKind::Number))
+	);
+	assert_eq!(
+		json!([[], [1]]).array_element_kind(),
+		Some(Some(ValueKind::Array))
+	);
+	assert_eq!(
+		json!([null]).array_element_kind(),
+		Some(Some(ValueKind::Null))
+	);
+}
+
+#[test]
+fn mixed_and_empty_arrays() {
+	assert_eq!(json!([1, "1"]).array_element_kind(), Some(None));
+	assert_eq!(json!([{}, []]).array_element_kind(), Some(None));
+	assert_eq!(json!([]).array_element_kind(), Some(None));
+}
+
+#[test]
+fn non_arrays() {
+	assert_eq!(json!({ "a": 1 }).array_element_kind(), None);
+	assert_eq!(json!(1).array_element_kind(), None);
+}
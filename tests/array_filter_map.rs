@@ -0,0 +1,66 @@
+mod common;
+
+use common::TestValue;
+use generic_json::{Json, JsonNew, MetaValue, NumberBuf};
+
+fn mixed() -> MetaValue {
+	// `[1, "two", -3, null, 4.5, [5], 18446744073709551615]`
+	vec![
+		MetaValue::number(NumberBuf::from(1u32), ()),
+		MetaValue::string("two".into(), ()),
+		MetaValue::number(NumberBuf::from(-3i32), ()),
+		MetaValue::null(()),
+		MetaValue::number(NumberBuf::new("4.5".to_string()).unwrap(), ()),
+		vec![MetaValue::number(NumberBuf::from(5u32), ())]
+			.into_iter()
+			.collect(),
+		MetaValue::number(NumberBuf::from(u64::MAX), ()),
+	]
+	.into_iter()
+	.collect()
+}
+
+#[test]
+fn numeric_elements() {
+	assert_eq!(
+		mixed().array_filter_map(|item| item.as_i64()),
+		Some(vec![1, -3])
+	);
+	assert_eq!(
+		mixed().array_filter_map(|item| item.as_number().map(|_| item.kind())),
+		Some(vec![generic_json::ValueKind::Number; 4])
+	);
+}
+
+#[test]
+fn calls_in_order() {
+	let mut calls = 0;
+	let strings = mixed().array_filter_map(|item| {
+		calls += 1;
+		item.as_str().map(str::to_string)
+	});
+
+	assert_eq!(strings, Some(vec!["two".to_string()]));
+	assert_eq!(calls, 7);
+}
+
+#[test]
+fn non_arrays() {
+	assert_eq!(
+		MetaValue::null(()).array_filter_map(|item| item.as_i64()),
+		None
+	);
+	assert_eq!(
+		MetaValue::empty_object(()).array_filter_map(|item| item.as_i64()),
+		None
+	);
+	assert_eq!(
+		MetaValue::empty_array(()).array_filter_map(|item| item.as_i64()),
+		Some(Vec::new())
+	);
+	assert_eq!(
+		TestValue::Array(vec![TestValue::number(2.0), TestValue::number(2.5)])
+			.array_filter_map(|item| item.as_i64()),
+		Some(vec![2])
+	);
+}
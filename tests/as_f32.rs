@@ -0,0 +1,40 @@
+use generic_json::{Number, NumberBuf};
+
+fn check<N: Number>(exact: N, inexact: N, large: N) {
+	assert_eq!(exact.as_f32(), Some(0.5));
+	assert_eq!(inexact.as_f32(), None);
+	assert_eq!(large.as_f32(), None);
+}
+
+#[test]
+fn number_buf() {
+	let n = |s: &str| NumberBuf::new(s.to_string()).unwrap();
+	check(n("0.5"), n("0.1"), n("16777217"));
+	assert_eq!(n("16777216").as_f32(), Some(16777216.0));
+	assert_eq!(n("1e39").as_f32(), None);
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json() {
+	use serde_json::Number as N;
+	check(
+		N::from_f64(0.5).unwrap(),
+		N::from_f64(0.1).unwrap(),
+		N::from(16777217u32),
+	);
+	assert_eq!(N::from(-3).as_f32(), Some(-3.0));
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn ijson() {
+	use ijson::INumber as N;
+	use std::convert::TryFrom;
+	check(
+		N::try_from(0.5f64).unwrap(),
+		N::try_from(0.1f64).unwrap(),
+		N::from(16777217u32),
+	);
+	assert_eq!(N::from(-3).as_f32(), Some(-3.0));
+}
@@ -0,0 +1,57 @@
+use generic_json::{BorrowedValue, Json, Number, NumberBuf};
+
+#[test]
+fn number_buf() {
+	let n = NumberBuf::new("0.5".to_string()).unwrap();
+	assert_eq!(n.as_f64_checked(), Ok(0.5));
+	let n = NumberBuf::new("1.5e3".to_string()).unwrap();
+	assert_eq!(n.as_f64_checked(), Ok(1500.0));
+
+	let n = NumberBuf::new("12345678901234567891".to_string()).unwrap();
+	let err = n.as_f64_checked().unwrap_err();
+	assert_eq!(err.lexical(), "12345678901234567891");
+	assert_eq!(err.nearest(), 12345678901234567891.0);
+
+	let n = NumberBuf::new("1e400".to_string()).unwrap();
+	assert_eq!(n.as_f64_checked().unwrap_err().nearest(), f64::INFINITY);
+}
+
+#[test]
+fn borrowed_value() {
+	let value = BorrowedValue::parse("[9007199254740992, 9007199254740993, 0.1]").unwrap();
+	let items: Vec<_> = match &value {
+		BorrowedValue::Array(items) => items.iter().map(|item| item.as_f64_checked()).collect(),
+		_ => panic!("not an array"),
+	};
+
+	assert_eq!(items[0], Some(Ok(9007199254740992.0)));
+	assert_eq!(
+		items[1].as_ref().unwrap().as_ref().unwrap_err().lexical(),
+		"9007199254740993"
+	);
+	assert_eq!(items[2], Some(Ok(0.1)));
+	assert_eq!(value.as_f64_checked(), None);
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json() {
+	use serde_json::json;
+
+	assert_eq!(json!(42).as_f64_checked(), Some(Ok(42.0)));
+	assert_eq!(
+		json!(-0.25).as_value_ref().as_f64_checked(),
+		Some(Ok(-0.25))
+	);
+	assert_eq!(json!("1").as_f64_checked(), None);
+
+	let err = json!(18446744073709551615u64)
+		.as_f64_checked()
+		.unwrap()
+		.unwrap_err();
+	assert_eq!(err.lexical(), "18446744073709551615");
+	assert_eq!(
+		err.to_string(),
+		"`18446744073709551615` cannot be represented as an `f64` without losing precision"
+	);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{AsSerdeJson, BorrowedValue, JsonNew, MetaValue, NumberBuf};
+use serde_json::json;
+
+/// Generic code taking the fast path when possible.
+fn describe<T: AsSerdeJson>(value: &T) -> Option<String> {
+	value.as_serde_json().map(serde_json::Value::to_string)
+}
+
+#[test]
+fn some_for_serde_json() {
+	let value = json!({ "a": [1, "b"] });
+	assert!(std::ptr::eq(value.as_serde_json().unwrap(), &value));
+	assert_eq!(describe(&value).as_deref(), Some(r#"{"a":[1,"b"]}"#));
+}
+
+#[cfg(all(feature = "nightly", feature = "ijson-impl"))]
+#[test]
+fn none_for_ijson() {
+	let value: ijson::IValue = generic_json::to_backend(&json!({ "a": [1, "b"] }));
+	assert!(value.as_serde_json().is_none());
+	assert_eq!(describe(&value), None);
+}
+
+#[test]
+fn none_for_other_backends() {
+	let value = BorrowedValue::parse("[1]").unwrap();
+	assert!(value.as_serde_json().is_none());
+
+	let value: MetaValue<()> =
+		MetaValue::array(vec![MetaValue::number(NumberBuf::from(1u32), ())], ());
+	assert!(value.as_serde_json().is_none());
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::json;
+
+#[test]
+fn string_values() {
+	let value = json!(["", "abc", "é🦀"]);
+	for item in value.as_array().unwrap() {
+		let item = item.as_value_ref();
+		assert!(item.is_string());
+		// SAFETY: the value is a string.
+		let s = unsafe { item.as_str_unchecked() };
+		assert_eq!(Some(s), item.as_str());
+		assert!(std::ptr::eq(s, item.as_str().unwrap()));
+	}
+}
+
+#[test]
+fn outlives_the_reference() {
+	let value = json!({ "a": "b" });
+	let s = {
+		let item = value["a"].as_value_ref();
+		assert!(item.is_string());
+		// SAFETY: the value is a string.
+		unsafe { item.as_str_unchecked() }
+	};
+
+	assert_eq!(s, "b")
+}
@@ -0,0 +1,75 @@
+use generic_json::{BorrowedStr, BorrowedValue, Json, ValueMut};
+use std::collections::BTreeMap;
+
+static DOCUMENT: &str = r#"{ "name": "generic-json", "tags": ["a", "b\nc"], "version": 1.50 }"#;
+
+/// Checks that the given string points into `DOCUMENT`.
+fn points_into_document(s: &str) -> bool {
+	let range = DOCUMENT.as_bytes().as_ptr_range();
+	range.contains(&s.as_ptr()) && s.len() <= range.end as usize - s.as_ptr() as usize
+}
+
+type Object = BTreeMap<BorrowedStr<'static>, BorrowedValue<'static>>;
+
+fn object<'a>(value: &'a BorrowedValue<'static>) -> &'a Object {
+	match value {
+		BorrowedValue::Object(o) => o,
+		_ => panic!("not an object"),
+	}
+}
+
+#[test]
+fn strings_borrow_from_the_document() {
+	let value = BorrowedValue::parse(DOCUMENT).unwrap();
+	let o = object(&value);
+
+	for key in o.keys() {
+		assert!(key.is_borrowed());
+		assert!(points_into_document(key))
+	}
+
+	let name = o["name"].as_str().unwrap();
+	assert_eq!(name, "generic-json");
+	assert!(points_into_document(name));
+
+	match &o["tags"] {
+		BorrowedValue::Array(tags) => match (&tags[0], &tags[1]) {
+			(BorrowedValue::String(a), BorrowedValue::String(bc)) => {
+				assert!(a.is_borrowed() && points_into_document(a));
+				assert_eq!(&**bc, "b\nc");
+				assert!(!bc.is_borrowed() && !points_into_document(bc))
+			}
+			_ => panic!("not strings"),
+		},
+		_ => panic!("not an array"),
+	}
+}
+
+#[test]
+fn numbers_keep_their_lexical_form() {
+	let value = BorrowedValue::parse(DOCUMENT).unwrap();
+	match &object(&value)["version"] {
+		BorrowedValue::Number(n) => {
+			assert_eq!(n.as_str(), "1.50");
+			assert!(points_into_document(n.as_str()))
+		}
+		_ => panic!("not a number"),
+	}
+}
+
+#[test]
+fn in_place_modification_owns_new_strings() {
+	let mut value = BorrowedValue::parse(DOCUMENT).unwrap();
+	match value.as_value_mut() {
+		ValueMut::Object(o) => {
+			o.insert("new".into(), BorrowedValue::String("x".into()));
+		}
+		_ => panic!("not an object"),
+	}
+
+	let o = object(&value);
+	let (key, item) = o.get_key_value("new").unwrap();
+	assert!(!key.is_borrowed());
+	assert_eq!(item.as_str(), Some("x"));
+	assert!(!points_into_document(item.as_str().unwrap()));
+}
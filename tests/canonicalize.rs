@@ -0,0 +1,142 @@
+mod common;
+
+use common::TestValue;
+use generic_json::{canonicalize, JsonNew, MetaValue, NumberBuf, TriviaValue};
+
+fn number(lexical: &str) -> MetaValue {
+	MetaValue::number(NumberBuf::new(lexical.to_string()).unwrap(), ())
+}
+
+fn canonical_number(f: f64) -> String {
+	canonicalize(&TestValue::number(f)).unwrap()
+}
+
+#[test]
+fn rfc8785_numbers() {
+	for (bits, expected) in [
+		(0x0000000000000000u64, "0"),
+		(0x8000000000000000, "0"),
+		(0x0000000000000001, "5e-324"),
+		(0x8000000000000001, "-5e-324"),
+		(0x7fefffffffffffff, "1.7976931348623157e+308"),
+		(0xffefffffffffffff, "-1.7976931348623157e+308"),
+		(0x4340000000000000, "9007199254740992"),
+		(0xc340000000000000, "-9007199254740992"),
+		(0x4430000000000000, "295147905179352830000"),
+		(0x44b52d02c7e14af5, "9.999999999999997e+22"),
+		(0x44b52d02c7e14af6, "1e+23"),
+		(0x44b52d02c7e14af7, "1.0000000000000001e+23"),
+		(0x444b1ae4d6e2ef4e, "999999999999999700000"),
+		(0x444b1ae4d6e2ef4f, "999999999999999900000"),
+		(0x444b1ae4d6e2ef50, "1e+21"),
+		(0x3eb0c6f7a0b5ed8c, "9.999999999999997e-7"),
+		(0x3eb0c6f7a0b5ed8d, "0.000001"),
+		(0x41b3de4355555553, "333333333.3333332"),
+		(0x41b3de4355555554, "333333333.33333325"),
+		(0x41b3de4355555555, "333333333.3333333"),
+		(0x41b3de4355555556, "333333333.3333334"),
+		(0x41b3de4355555557, "333333333.33333343"),
+		(0xbecbf647612f3696, "-0.0000033333333333333333"),
+		(0x43143ff3c1cb0959, "1424953923781206.2"),
+	] {
+		assert_eq!(
+			canonical_number(f64::from_bits(bits)),
+			expected,
+			"{:#x}",
+			bits
+		)
+	}
+}
+
+#[test]
+fn numbers_beyond_f64_precision() {
+	// Integers are rounded to the nearest `f64`.
+	assert_eq!(
+		canonicalize(&number("9007199254740993")).unwrap(),
+		"9007199254740992"
+	);
+	assert_eq!(
+		canonicalize(&number("123456789012345678901234567890")).unwrap(),
+		"1.2345678901234568e+29"
+	);
+	assert_eq!(canonicalize(&number("4.50")).unwrap(), "4.5");
+	assert_eq!(canonicalize(&number("1E30")).unwrap(), "1e+30");
+}
+
+#[test]
+fn numbers_out_of_range() {
+	for lexical in ["1e400", "-1e400", "123e999"] {
+		let value: MetaValue = vec![number(lexical)].into_iter().collect();
+		let error = canonicalize(&value).unwrap_err();
+		assert_eq!(error.lexical(), lexical);
+		assert_eq!(
+			error.to_string(),
+			format!("`{}` is outside of the range of an `f64`", lexical)
+		);
+	}
+
+	// Tiny numbers underflow to zero instead.
+	assert_eq!(canonicalize(&number("1e-400")).unwrap(), "0");
+}
+
+#[test]
+fn non_finite_numbers() {
+	for f in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+		let value = TestValue::object(vec![("a", TestValue::number(f))]);
+		assert!(canonicalize(&value).is_err())
+	}
+}
+
+#[test]
+fn structure() {
+	let value = TestValue::object(vec![
+		(
+			"b",
+			TestValue::Array(vec![TestValue::Null, TestValue::Boolean(true)]),
+		),
+		(
+			"a",
+			TestValue::object(vec![
+				("\u{20ac}", TestValue::string("x")),
+				("\r", TestValue::Boolean(false)),
+			]),
+		),
+		("\u{1f600}", TestValue::string("\u{f}\n\"\\/")),
+		("\u{fb33}", TestValue::number(1.0)),
+	]);
+
+	assert_eq!(
+		canonicalize(&value).unwrap(),
+		"{\"a\":{\"\\r\":false,\"\u{20ac}\":\"x\"},\"b\":[null,true],\
+		\"\u{1f600}\":\"\\u000f\\n\\\"\\\\/\",\"\u{fb33}\":1}"
+	);
+}
+
+#[test]
+fn rfc8785_document() {
+	// Parsed with a correctly rounding parser, unlike `serde_json`'s default one.
+	let value = TriviaValue::parse(
+		r#"{
+			"numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+			"string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+			"literals": [null, true, false]
+		}"#,
+	)
+	.unwrap();
+
+	assert_eq!(
+		canonicalize(&value).unwrap(),
+		r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+	);
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn content_id() {
+	use generic_json::Json;
+
+	let a = TestValue::object(vec![("a", TestValue::number(1.0)), ("b", TestValue::Null)]);
+	let b = TestValue::object(vec![("b", TestValue::Null), ("a", TestValue::number(1.0))]);
+	assert_eq!(a.content_id().unwrap(), b.content_id().unwrap());
+	assert!(TestValue::number(f64::NAN).content_id().is_err());
+}
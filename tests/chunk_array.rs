@@ -0,0 +1,83 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{Json, JsonNew, MetaValue, NumberBuf};
+use serde_json::{json, Value};
+
+fn range(n: u32) -> Value {
+	Value::Array((0..n).map(Value::from).collect())
+}
+
+#[test]
+fn last_chunk_is_smaller() {
+	let chunks: Vec<Value> = range(10).chunk_array(3).unwrap();
+	assert_eq!(chunks.len(), 4);
+	assert_eq!(
+		chunks,
+		[
+			json!([0, 1, 2]),
+			json!([3, 4, 5]),
+			json!([6, 7, 8]),
+			json!([9])
+		]
+	);
+}
+
+#[test]
+fn exact_multiple() {
+	let chunks: Vec<Value> = range(10).chunk_array(5).unwrap();
+	assert_eq!(chunks, [json!([0, 1, 2, 3, 4]), json!([5, 6, 7, 8, 9])]);
+
+	let chunks: Vec<Value> = range(10).chunk_array(10).unwrap();
+	assert_eq!(chunks, [range(10)]);
+
+	let chunks: Vec<Value> = range(3).chunk_array(1).unwrap();
+	assert_eq!(chunks, [json!([0]), json!([1]), json!([2])]);
+}
+
+#[test]
+fn size_larger_than_array() {
+	let chunks: Vec<Value> = range(2).chunk_array(5).unwrap();
+	assert_eq!(chunks, [json!([0, 1])]);
+}
+
+#[test]
+fn empty_array() {
+	let chunks: Vec<Value> = json!([]).chunk_array(3).unwrap();
+	assert!(chunks.is_empty());
+}
+
+#[test]
+fn nested_elements() {
+	let chunks: Vec<Value> = json!([{ "a": [1] }, null, "x"]).chunk_array(2).unwrap();
+	assert_eq!(chunks, [json!([{ "a": [1] }, null]), json!(["x"])]);
+}
+
+#[test]
+fn none_cases() {
+	assert!(range(10).chunk_array::<Value>(0).is_none());
+	assert!(json!([]).chunk_array::<Value>(0).is_none());
+	assert!(json!({ "a": [1, 2] }).chunk_array::<Value>(1).is_none());
+	assert!(json!("[1, 2]").chunk_array::<Value>(1).is_none());
+	assert!(json!(null).chunk_array::<Value>(1).is_none());
+}
+
+#[test]
+fn across_backends() {
+	let value: MetaValue<u8> = MetaValue::array(
+		(0..5u32)
+			.map(|i| MetaValue::number(NumberBuf::from(i), 1))
+			.collect(),
+		2,
+	);
+
+	let chunks: Vec<MetaValue<u8>> = value.chunk_array(2).unwrap();
+	assert_eq!(chunks.len(), 3);
+	for chunk in &chunks {
+		assert_eq!(chunk.metadata(), &0);
+		for item in chunk.as_array().unwrap() {
+			assert_eq!(item.metadata(), &0);
+		}
+	}
+
+	let chunks: Vec<Value> = value.chunk_array(2).unwrap();
+	assert_eq!(chunks, [json!([0, 1]), json!([2, 3]), json!([4])]);
+}
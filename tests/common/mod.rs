@@ -0,0 +1,326 @@
+//! Test backend able to hold what the provided backends cannot:
+//! non-finite numbers and objects with duplicate keys.
+#![allow(dead_code)]
+use cc_traits::{
+	Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Keyed, KeyedRef, Len,
+	MapInsert, MapIter, MapIterMut, Remove,
+};
+use generic_json::{Json, JsonNew, Number, NumberFrom, Value, ValueMut, ValueRef};
+use std::{convert::TryInto, iter::FromIterator};
+
+/// Number stored as an `f64`, possibly `NaN` or infinite.
+///
+/// Numbers are compared bitwise.
+#[derive(Clone, Copy, Debug)]
+pub struct F64(pub f64);
+
+impl PartialEq for F64 {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.to_bits() == other.0.to_bits()
+	}
+}
+
+impl Eq for F64 {}
+
+impl Number for F64 {
+	fn as_u32(&self) -> Option<u32> {
+		self.as_u64().and_then(|u| u.try_into().ok())
+	}
+
+	fn as_u64(&self) -> Option<u64> {
+		let u = self.0 as u64;
+		if u as f64 == self.0 && self.0 < u64::MAX as f64 {
+			Some(u)
+		} else {
+			None
+		}
+	}
+
+	fn as_i32(&self) -> Option<i32> {
+		self.as_i64().and_then(|i| i.try_into().ok())
+	}
+
+	fn as_i64(&self) -> Option<i64> {
+		let i = self.0 as i64;
+		if i as f64 == self.0 && self.0 < i64::MAX as f64 {
+			Some(i)
+		} else {
+			None
+		}
+	}
+
+	fn as_f32(&self) -> Option<f32> {
+		let f = self.0 as f32;
+		if f as f64 == self.0 {
+			Some(f)
+		} else {
+			None
+		}
+	}
+
+	fn as_f32_lossy(&self) -> f32 {
+		self.0 as f32
+	}
+
+	fn as_f64(&self) -> Option<f64> {
+		Some(self.0)
+	}
+
+	fn as_f64_lossy(&self) -> f64 {
+		self.0
+	}
+}
+
+impl NumberFrom for F64 {
+	fn from_u64(u: u64) -> Self {
+		Self(u as f64)
+	}
+
+	fn from_i64(i: i64) -> Self {
+		Self(i as f64)
+	}
+
+	fn from_f64(f: f64) -> Option<Self> {
+		if f.is_finite() {
+			Some(Self(f))
+		} else {
+			None
+		}
+	}
+}
+
+/// JSON value whose objects are lists of members, possibly with duplicate keys.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TestValue {
+	Null,
+	Boolean(bool),
+	Number(F64),
+	String(String),
+	Array(Vec<TestValue>),
+	Object(Members),
+}
+
+impl TestValue {
+	/// Creates a number value.
+	pub fn number(f: f64) -> Self {
+		Self::Number(F64(f))
+	}
+
+	/// Creates a string value.
+	pub fn string(s: &str) -> Self {
+		Self::String(s.to_string())
+	}
+
+	/// Creates an object from the given members, keeping duplicates.
+	pub fn object<'a>(members: impl IntoIterator<Item = (&'a str, TestValue)>) -> Self {
+		Self::Object(Members(
+			members
+				.into_iter()
+				.map(|(key, value)| (key.to_string(), value))
+				.collect(),
+		))
+	}
+}
+
+/// Object members, in insertion order.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct Members(pub Vec<(String, TestValue)>);
+
+impl Collection for Members {
+	type Item = TestValue;
+}
+
+impl CollectionRef for Members {
+	type ItemRef<'a> = &'a TestValue;
+
+	cc_traits::covariant_item_ref!();
+}
+
+impl CollectionMut for Members {
+	type ItemMut<'a> = &'a mut TestValue;
+
+	cc_traits::covariant_item_mut!();
+}
+
+impl Keyed for Members {
+	type Key = String;
+}
+
+impl KeyedRef for Members {
+	type KeyRef<'a> = &'a String;
+
+	cc_traits::covariant_key_ref!();
+}
+
+impl Len for Members {
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+/// Returns the first member with the given key.
+impl<'q> Get<&'q str> for Members {
+	fn get(&self, key: &'q str) -> Option<&TestValue> {
+		self.get_key_value(key).map(|(_, value)| value)
+	}
+}
+
+impl<'q> GetKeyValue<&'q str> for Members {
+	fn get_key_value(&self, key: &'q str) -> Option<(&String, &TestValue)> {
+		self.0.iter().find(|(k, _)| k == key).map(|(k, v)| (k, v))
+	}
+}
+
+impl<'q> GetMut<&'q str> for Members {
+	fn get_mut(&mut self, key: &'q str) -> Option<&mut TestValue> {
+		self.0
+			.iter_mut()
+			.find(|(k, _)| k == key)
+			.map(|(_, value)| value)
+	}
+}
+
+pub type MembersIter<'a> = std::iter::Map<
+	std::slice::Iter<'a, (String, TestValue)>,
+	fn(&'a (String, TestValue)) -> (&'a String, &'a TestValue),
+>;
+
+pub type MembersIterMut<'a> = std::iter::Map<
+	std::slice::IterMut<'a, (String, TestValue)>,
+	fn(&'a mut (String, TestValue)) -> (&'a String, &'a mut TestValue),
+>;
+
+impl MapIter for Members {
+	type Iter<'a> = MembersIter<'a>;
+
+	fn iter(&self) -> MembersIter<'_> {
+		self.0.iter().map(|(k, v)| (k, v))
+	}
+}
+
+impl MapIterMut for Members {
+	type IterMut<'a> = MembersIterMut<'a>;
+
+	fn iter_mut(&mut self) -> MembersIterMut<'_> {
+		self.0.iter_mut().map(|(k, v)| (&*k, v))
+	}
+}
+
+/// Replaces the first member with the given key, or appends a new member.
+impl MapInsert<String> for Members {
+	type Output = Option<TestValue>;
+
+	fn insert(&mut self, key: String, value: TestValue) -> Option<TestValue> {
+		match self.get_mut(key.as_str()) {
+			Some(old) => Some(std::mem::replace(old, value)),
+			None => {
+				self.0.push((key, value));
+				None
+			}
+		}
+	}
+}
+
+/// Removes the first member with the given key.
+impl<'q> Remove<&'q str> for Members {
+	fn remove(&mut self, key: &'q str) -> Option<TestValue> {
+		let i = self.0.iter().position(|(k, _)| k == key)?;
+		Some(self.0.remove(i).1)
+	}
+}
+
+impl IntoIterator for Members {
+	type Item = (String, TestValue);
+	type IntoIter = std::vec::IntoIter<(String, TestValue)>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl FromIterator<(String, TestValue)> for Members {
+	fn from_iter<I: IntoIterator<Item = (String, TestValue)>>(iter: I) -> Self {
+		Self(iter.into_iter().collect())
+	}
+}
+
+impl Json for TestValue {
+	type MetaData = ();
+	type Number = F64;
+	type String = String;
+	type Array = Vec<Self>;
+	type Key = String;
+	type Object = Members;
+
+	fn as_value_ref(&self) -> ValueRef<'_, Self> {
+		self.into()
+	}
+
+	fn as_value_mut(&mut self) -> ValueMut<'_, Self> {
+		self.into()
+	}
+
+	fn into_parts(self) -> (Value<Self>, ()) {
+		let value = match self {
+			Self::Null => Value::Null,
+			Self::Boolean(b) => Value::Boolean(b),
+			Self::Number(n) => Value::Number(n),
+			Self::String(s) => Value::String(s),
+			Self::Array(a) => Value::Array(a),
+			Self::Object(o) => Value::Object(o),
+		};
+
+		(value, ())
+	}
+
+	fn metadata(&self) -> &() {
+		&()
+	}
+
+	fn as_pair_mut(&mut self) -> (ValueMut<'_, Self>, &()) {
+		(self.into(), &())
+	}
+}
+
+impl JsonNew for TestValue {
+	fn new(value: Value<Self>, _: ()) -> Self {
+		match value {
+			Value::Null => Self::Null,
+			Value::Boolean(b) => Self::Boolean(b),
+			Value::Number(n) => Self::Number(n),
+			Value::String(s) => Self::String(s),
+			Value::Array(a) => Self::Array(a),
+			Value::Object(o) => Self::Object(o),
+		}
+	}
+
+	fn new_key(key: &str, _: ()) -> String {
+		key.to_string()
+	}
+}
+
+impl<'a> From<&'a TestValue> for ValueRef<'a, TestValue> {
+	fn from(value: &'a TestValue) -> Self {
+		match value {
+			TestValue::Null => ValueRef::Null,
+			TestValue::Boolean(b) => ValueRef::Boolean(*b),
+			TestValue::Number(n) => ValueRef::Number(n),
+			TestValue::String(s) => ValueRef::String(s),
+			TestValue::Array(a) => ValueRef::Array(a),
+			TestValue::Object(o) => ValueRef::Object(o),
+		}
+	}
+}
+
+impl<'a> From<&'a mut TestValue> for ValueMut<'a, TestValue> {
+	fn from(value: &'a mut TestValue) -> Self {
+		match value {
+			TestValue::Null => ValueMut::Null,
+			TestValue::Boolean(b) => ValueMut::Boolean(*b),
+			TestValue::Number(n) => ValueMut::Number(n),
+			TestValue::String(s) => ValueMut::String(s),
+			TestValue::Array(a) => ValueMut::Array(a),
+			TestValue::Object(o) => ValueMut::Object(o),
+		}
+	}
+}
@@ -0,0 +1,86 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{Json, ValueKind};
+use serde_json::{json, Value};
+
+fn document() -> Value {
+	json!({
+		"a": { "b": [1, { "c": 2, "d/e": [3] }], "f": 4 },
+		"g": null,
+		"": { "~": 5 }
+	})
+}
+
+fn ancestor(a: &str, b: &str) -> Option<(String, ValueKind)> {
+	document()
+		.common_ancestor(a, b)
+		.map(|(pointer, node)| (pointer, node.kind()))
+}
+
+#[test]
+fn siblings() {
+	assert_eq!(
+		ancestor("/a/b/0", "/a/b/1"),
+		Some(("/a/b".to_string(), ValueKind::Array))
+	);
+	assert_eq!(
+		ancestor("/a/b/1/c", "/a/b/1/d~1e"),
+		Some(("/a/b/1".to_string(), ValueKind::Object))
+	);
+	assert_eq!(
+		ancestor("/a", "/g"),
+		Some((String::new(), ValueKind::Object))
+	);
+}
+
+#[test]
+fn different_depths() {
+	assert_eq!(
+		ancestor("/a/b/1/d~1e/0", "/a/f"),
+		Some(("/a".to_string(), ValueKind::Object))
+	);
+	assert_eq!(
+		ancestor("/g", "/a/b/1/c"),
+		Some((String::new(), ValueKind::Object))
+	);
+}
+
+#[test]
+fn ancestor_of_the_other() {
+	assert_eq!(
+		ancestor("/a/b", "/a/b/1/c"),
+		Some(("/a/b".to_string(), ValueKind::Array))
+	);
+	assert_eq!(
+		ancestor("/a/b/1/c", "/a/b"),
+		Some(("/a/b".to_string(), ValueKind::Array))
+	);
+	assert_eq!(
+		ancestor("", "/a/f"),
+		Some((String::new(), ValueKind::Object))
+	);
+	assert_eq!(
+		ancestor("/a/f", "/a/f"),
+		Some(("/a/f".to_string(), ValueKind::Number))
+	);
+}
+
+#[test]
+fn escaped_and_empty_keys() {
+	assert_eq!(
+		ancestor("//~0", "/"),
+		Some(("/".to_string(), ValueKind::Object))
+	);
+
+	let value = document();
+	let (_, node) = value.common_ancestor("//~0", "//~0").unwrap();
+	assert_eq!(node.as_u64(), Some(5));
+}
+
+#[test]
+fn absent_pointers() {
+	assert_eq!(ancestor("/a/b/2", "/a/b/0"), None);
+	assert_eq!(ancestor("/a/b/0", "/x"), None);
+	assert_eq!(ancestor("/a/b/01", "/a/b/0"), None);
+	assert_eq!(ancestor("a", "/a"), None);
+	assert_eq!(ancestor("/g/h", "/g"), None);
+}
@@ -0,0 +1,67 @@
+#![cfg(all(feature = "digest", feature = "serde_json-impl"))]
+mod common;
+
+use common::TestValue;
+use generic_json::{Json, TriviaValue};
+use serde_json::json;
+
+fn hex(id: [u8; 32]) -> String {
+	id.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn known_digest() {
+	// SHA-256 of `{"a":1,"b":[true,"x"]}`.
+	assert_eq!(
+		hex(json!({ "b": [true, "x"], "a": 1 }).content_id().unwrap()),
+		"63e8063d9dc6f0fd5a24b4706818a165fd57c3531b74466cf5dea62bff09b0b6"
+	);
+}
+
+#[test]
+fn key_order_and_backends() {
+	let a = json!({ "a": 1, "b": [true, "x"], "c": { "d": null, "e": 0.5 } });
+	let b = TriviaValue::parse(
+		r#"{ "c": { "e": 5e-1, "d": null }, /* comment */ "b": [ true, "x" ], "a": 1.0 }"#,
+	)
+	.unwrap();
+	let c = TestValue::object(vec![
+		(
+			"c",
+			TestValue::object(vec![("e", TestValue::number(0.5)), ("d", TestValue::Null)]),
+		),
+		("a", TestValue::number(1.0)),
+		(
+			"b",
+			TestValue::Array(vec![TestValue::Boolean(true), TestValue::string("x")]),
+		),
+	]);
+
+	let id = a.content_id().unwrap();
+	assert_eq!(b.content_id().unwrap(), id);
+	assert_eq!(c.content_id().unwrap(), id);
+
+	#[cfg(feature = "ijson-impl")]
+	{
+		let ivalue: ijson::IValue = serde_json::from_value(
+			json!({ "c": { "d": null, "e": 0.5 }, "b": [true, "x"], "a": 1 }),
+		)
+		.unwrap();
+		assert_eq!(ivalue.content_id().unwrap(), id);
+	}
+}
+
+#[test]
+fn differences() {
+	let id = json!({ "a": [1, 2] }).content_id().unwrap();
+	assert_ne!(json!({ "a": [2, 1] }).content_id().unwrap(), id);
+	assert_ne!(json!({ "a": [1, 2], "b": null }).content_id().unwrap(), id);
+	assert_ne!(json!({ "a": ["1", 2] }).content_id().unwrap(), id);
+	assert_ne!(json!([{ "a": [1, 2] }]).content_id().unwrap(), id);
+}
+
+#[test]
+fn uncanonicalizable_numbers() {
+	let value = TestValue::Array(vec![TestValue::number(f64::INFINITY)]);
+	assert!(value.content_id().is_err());
+}
@@ -0,0 +1,87 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+mod common;
+
+use cc_traits::Len;
+use common::TestValue;
+use generic_json::{convert, Json, MetaValue};
+use serde_json::{json, Value};
+
+fn document() -> Value {
+	json!({
+		"name": "generic-json",
+		"numbers": [0, -1, i64::MIN, u64::MAX, 0.1, -2.5, 1e300],
+		"nested": { "empty": [], "object": {}, "null": null, "flags": [true, false] }
+	})
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn serde_json_to_ijson_and_back() {
+	let ivalue: ijson::IValue = convert(document());
+	assert_eq!(
+		ivalue["numbers"][2].as_number().unwrap().to_i64(),
+		Some(i64::MIN)
+	);
+	assert_eq!(
+		ivalue["numbers"][3].as_number().unwrap().to_u64(),
+		Some(u64::MAX)
+	);
+	assert_eq!(ivalue["name"].as_string().unwrap().as_str(), "generic-json");
+
+	let back: Value = convert(ivalue);
+	assert_eq!(back, document());
+}
+
+#[test]
+fn serde_json_to_meta_value_and_back() {
+	let value: MetaValue = convert(document());
+	assert_eq!(value.as_object().unwrap().len(), 3);
+
+	let back: Value = convert(value);
+	assert_eq!(back, document());
+}
+
+#[test]
+fn unrepresentable_numbers_become_null() {
+	let value = TestValue::Array(vec![
+		TestValue::number(f64::NAN),
+		TestValue::number(f64::INFINITY),
+		TestValue::number(1.5),
+	]);
+
+	let value: Value = convert(value);
+	assert_eq!(value, json!([null, null, 1.5]));
+}
+
+#[test]
+fn big_numbers() {
+	let big = "123456789012345678901234567890";
+	let value: MetaValue = generic_json::BorrowedValue::parse(big)
+		.map(|value| generic_json::to_backend(&value))
+		.unwrap();
+
+	// Lexical representations are kept when both backends support them.
+	let serde: Value = convert(value);
+	#[cfg(feature = "arbitrary_precision")]
+	assert_eq!(serde.to_string(), big);
+	#[cfg(not(feature = "arbitrary_precision"))]
+	assert_eq!(serde.as_f64(), Some(1.2345678901234568e29));
+
+	let back: MetaValue = convert(serde);
+	let lexical = back.as_number().unwrap().as_str().to_string();
+	#[cfg(feature = "arbitrary_precision")]
+	assert_eq!(lexical, big);
+	#[cfg(not(feature = "arbitrary_precision"))]
+	assert_ne!(lexical, big);
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn big_numbers_lose_precision_in_ijson() {
+	let value: Value = serde_json::from_str("[18446744073709551616]").unwrap();
+	let ivalue: ijson::IValue = convert(value);
+	assert_eq!(
+		ivalue[0].as_number().unwrap().to_f64(),
+		Some(18446744073709551616.0)
+	);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{CsvError, Json};
+use serde_json::json;
+
+fn rows() -> serde_json::Value {
+	// Keys are sorted so that the header order does not depend
+	// on `serde_json`'s `preserve_order` feature.
+	json!([
+		{ "a": 1, "b": "x,y" },
+		{ "b": "say \"hi\"", "c": true, "d": null }
+	])
+}
+
+#[test]
+fn union_of_keys() {
+	assert_eq!(
+		rows().to_csv(None).unwrap(),
+		"a,b,c,d\n1,\"x,y\",,\n,\"say \"\"hi\"\"\",true,\n"
+	);
+}
+
+#[test]
+fn given_headers() {
+	assert_eq!(
+		rows().to_csv(Some(&["c", "a", "z"])).unwrap(),
+		"c,a,z\n,1,\ntrue,,\n"
+	);
+	assert_eq!(json!([]).to_csv(Some(&["a"])).unwrap(), "a\n");
+}
+
+#[test]
+fn errors() {
+	assert_eq!(json!({}).to_csv(None), Err(CsvError::NotAnArray));
+	assert_eq!(
+		json!([{}, 1]).to_csv(Some(&["a"])),
+		Err(CsvError::NotAnObject(1))
+	);
+
+	let value = json!([{ "a": 1 }, { "a": [1] }]);
+	let err = value.to_csv(None).unwrap_err();
+	assert_eq!(err, CsvError::NestedValue(1, "a".to_string()));
+	assert_eq!(err.to_string(), "member `a` of element 1 is not a scalar");
+
+	// Nested values outside of the headers are ignored.
+	assert_eq!(value.to_csv(Some(&["b"])).unwrap(), "b\n\n\n");
+}
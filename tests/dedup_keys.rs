@@ -0,0 +1,75 @@
+#![cfg(feature = "nightly")]
+mod common;
+
+use common::TestValue;
+use generic_json::{DuplicateKeyPolicy, Json};
+
+fn document() -> TestValue {
+	TestValue::object(vec![
+		("a", TestValue::number(1.0)),
+		("b", TestValue::number(2.0)),
+		("a", TestValue::number(3.0)),
+		(
+			"nested",
+			TestValue::Array(vec![TestValue::object(vec![
+				("x", TestValue::Null),
+				("x", TestValue::Boolean(true)),
+			])]),
+		),
+		("b", TestValue::number(4.0)),
+	])
+}
+
+#[test]
+fn keep_first() {
+	let mut value = document();
+	value.dedup_keys(DuplicateKeyPolicy::KeepFirst);
+	assert_eq!(
+		value,
+		TestValue::object(vec![
+			("a", TestValue::number(1.0)),
+			("b", TestValue::number(2.0)),
+			(
+				"nested",
+				TestValue::Array(vec![TestValue::object(vec![("x", TestValue::Null)])]),
+			),
+		])
+	)
+}
+
+#[test]
+fn keep_last() {
+	let mut value = document();
+	value.dedup_keys(DuplicateKeyPolicy::KeepLast);
+	assert_eq!(
+		value,
+		TestValue::object(vec![
+			("a", TestValue::number(3.0)),
+			(
+				"nested",
+				TestValue::Array(vec![TestValue::object(vec![(
+					"x",
+					TestValue::Boolean(true)
+				)])]),
+			),
+			("b", TestValue::number(4.0)),
+		])
+	)
+}
+
+#[test]
+fn no_duplicates() {
+	let mut value = TestValue::object(vec![("a", TestValue::Null), ("b", TestValue::Null)]);
+	let copy = value.clone();
+	value.dedup_keys(DuplicateKeyPolicy::KeepLast);
+	assert_eq!(value, copy)
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn no_op_on_maps() {
+	let mut value = serde_json::json!({ "a": [{ "b": 1 }], "c": null });
+	let copy = value.clone();
+	value.dedup_keys(DuplicateKeyPolicy::KeepFirst);
+	assert_eq!(value, copy)
+}
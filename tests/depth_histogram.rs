@@ -0,0 +1,25 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::json;
+
+#[test]
+fn nodes_per_depth() {
+	let value = json!({
+		"a": [1, [2, 3], { "b": null }],
+		"c": {},
+		"d": "x"
+	});
+
+	// depth 0: root
+	// depth 1: a, c, d
+	// depth 2: 1, [2, 3], { "b": null }
+	// depth 3: 2, 3, null
+	assert_eq!(value.depth_histogram(), [1, 3, 3, 3]);
+}
+
+#[test]
+fn scalars_and_empty_containers() {
+	assert_eq!(json!(1).depth_histogram(), [1]);
+	assert_eq!(json!([]).depth_histogram(), [1]);
+	assert_eq!(json!([[[]]]).depth_histogram(), [1, 1, 1]);
+}
@@ -0,0 +1,89 @@
+#![cfg(all(feature = "serde", feature = "serde_json-impl"))]
+use generic_json::{deserialize_into, json_eq, Json, MetaValue};
+use serde_json::json;
+
+const YAML: &str = "
+name: generic-json
+version: 0.7
+tags: [json, trait]
+nested:
+  count: -3
+  big: 18446744073709551615
+  enabled: true
+  nothing: ~
+";
+
+const TOML: &str = r#"
+name = "generic-json"
+version = 0.7
+tags = ["json", "trait"]
+
+[nested]
+count = -3
+enabled = true
+"#;
+
+#[test]
+fn yaml_into_meta_value() {
+	let value: MetaValue = deserialize_into(serde_yaml::Deserializer::from_str(YAML)).unwrap();
+	assert!(json_eq(
+		&value,
+		&json!({
+			"name": "generic-json",
+			"version": 0.7,
+			"tags": ["json", "trait"],
+			"nested": {
+				"count": -3,
+				"big": 18446744073709551615u64,
+				"enabled": true,
+				"nothing": null
+			}
+		})
+	));
+
+	// Members keep the document order.
+	let keys: Vec<_> = cc_traits::MapIter::iter(value.as_object().unwrap())
+		.map(|(key, _)| key.to_string())
+		.collect();
+	assert_eq!(keys, ["name", "version", "tags", "nested"]);
+	assert_eq!(value["version"].as_number().unwrap().as_str(), "0.7");
+}
+
+#[test]
+fn toml_into_meta_value() {
+	let value: MetaValue = deserialize_into(toml::Deserializer::new(TOML)).unwrap();
+	assert!(json_eq(
+		&value,
+		&json!({
+			"name": "generic-json",
+			"version": 0.7,
+			"tags": ["json", "trait"],
+			"nested": { "count": -3, "enabled": true }
+		})
+	));
+}
+
+#[test]
+fn reject_non_finite_numbers() {
+	let result: Result<MetaValue, _> =
+		deserialize_into(serde_yaml::Deserializer::from_str("a: .inf"));
+	assert!(result.unwrap_err().to_string().contains("invalid number"));
+
+	let result: Result<MetaValue, _> = deserialize_into(toml::Deserializer::new("a = nan"));
+	assert!(result.is_err());
+}
+
+#[test]
+fn reject_non_string_keys() {
+	let result: Result<MetaValue, _> =
+		deserialize_into(serde_yaml::Deserializer::from_str("[1]: a"));
+	assert!(result.is_err());
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn yaml_into_ijson() {
+	let value: ijson::IValue =
+		deserialize_into(serde_yaml::Deserializer::from_str("{ a: [1, 0.5, x] }")).unwrap();
+	assert!(json_eq(&value, &json!({ "a": [1, 0.5, "x"] })));
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{DiffSummary, Json};
+use serde_json::json;
+
+#[test]
+fn known_counts() {
+	let old = json!({
+		"name": "a",
+		"version": 1,
+		"removed": { "x": 1, "y": 2 },
+		"tags": ["a", "b", "c"],
+		"nested": { "kept": true, "gone": null, "list": [1] }
+	});
+
+	let new = json!({
+		"name": "b",
+		"version": 1.0,
+		"tags": ["a", "B"],
+		"nested": { "kept": true, "list": [1, 2, 3], "new": 0 },
+		"added": [1, 2]
+	});
+
+	assert_eq!(
+		old.diff_summary(&new),
+		DiffSummary {
+			added_keys: 2,
+			removed_keys: 2,
+			changed_values: 2,
+			added_elements: 2,
+			removed_elements: 1,
+		}
+	);
+}
+
+#[test]
+fn type_changes_count_once() {
+	assert_eq!(
+		json!({ "a": [1, 2] }).diff_summary(&json!({ "a": { "b": 1 } })),
+		DiffSummary {
+			changed_values: 1,
+			..DiffSummary::default()
+		}
+	);
+}
+
+#[test]
+fn identical_values() {
+	let value = json!({ "a": [1, { "b": null }] });
+	assert_eq!(value.diff_summary(&value), DiffSummary::default());
+}
+
+#[cfg(all(feature = "nightly", feature = "ijson-impl"))]
+#[test]
+fn across_backends() {
+	let old = json!({ "a": 1, "b": [true] });
+	let new: ijson::IValue =
+		generic_json::to_backend(&json!({ "a": 2, "b": [true, false], "c": "x" }));
+	assert_eq!(
+		old.diff_summary(&new),
+		DiffSummary {
+			added_keys: 1,
+			changed_values: 1,
+			added_elements: 1,
+			..DiffSummary::default()
+		}
+	);
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::TestValue;
+use generic_json::{display, display_pretty, BorrowedValue, Json, PrettyConfig};
+use std::fmt::Write;
+
+#[test]
+fn big_integers_round_trip() {
+	let input = r#"[123456789012345678901234567890,-1e400,1.50,0.1]"#;
+	let value = BorrowedValue::parse(input).unwrap();
+	assert_eq!(display(&value).to_string(), input);
+	assert_eq!(value.to_vec().unwrap(), input.as_bytes());
+}
+
+#[test]
+fn non_lexical_numbers() {
+	let value = TestValue::Array(vec![
+		TestValue::number(1.0),
+		TestValue::number(-0.5),
+		TestValue::number(1e300),
+		TestValue::number(1e-7),
+	]);
+	assert_eq!(display(&value).to_string(), "[1,-0.5,1e300,1e-7]");
+}
+
+#[test]
+fn reject_non_finite_numbers() {
+	for f in &[f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+		let value = TestValue::object(vec![("a", TestValue::number(*f))]);
+
+		let mut s = String::new();
+		assert!(write!(s, "{}", display(&value)).is_err());
+		assert!(write!(s, "{}", display_pretty(&value, PrettyConfig::default())).is_err());
+
+		let err = value.to_vec().unwrap_err();
+		assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+	}
+}
+
+#[cfg(feature = "serde_json-impl")]
+mod serde_json_output {
+	use generic_json::{display, display_pretty, Json, PrettyConfig};
+	use serde_json::json;
+
+	fn documents() -> Vec<serde_json::Value> {
+		vec![
+			json!(null),
+			json!("a/b \"c\" \\ \u{1} \u{7f} é"),
+			json!([1, -2, 18446744073709551615u64, 0.5, -1.25e-10, 1.0, 1e-7]),
+			json!({ "a": [true, false, null, {}], "b": { "c": [] } }),
+		]
+	}
+
+	#[test]
+	fn compact() {
+		for value in documents() {
+			let expected = serde_json::to_string(&value).unwrap();
+			assert_eq!(display(&value).to_string(), expected);
+			assert_eq!(value.clone().into_value().to_string(), expected);
+			assert_eq!(value.to_vec().unwrap(), expected.as_bytes());
+		}
+	}
+
+	#[test]
+	fn pretty() {
+		for value in documents() {
+			assert_eq!(
+				display_pretty(&value, PrettyConfig::default()).to_string(),
+				serde_json::to_string_pretty(&value).unwrap()
+			);
+		}
+
+		assert_eq!(
+			display_pretty(&json!({ "a": [1] }), PrettyConfig { indent: 4 }).to_string(),
+			"{\n    \"a\": [\n        1\n    ]\n}"
+		);
+	}
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::TestValue;
+use generic_json::{json_eq, CompareOptions, Json};
+
+fn object(members: Vec<(&str, f64)>) -> TestValue {
+	TestValue::object(
+		members
+			.into_iter()
+			.map(|(key, n)| (key, TestValue::number(n)))
+			.collect::<Vec<_>>(),
+	)
+}
+
+/// Compares `a` and `b` in every possible way, checking that all agree.
+fn all_eq(a: &TestValue, b: &TestValue) -> bool {
+	let equal = json_eq(a, b);
+	assert_eq!(a.semantically_eq(b, &CompareOptions::default()), equal);
+	assert_eq!(a.as_value_ref() == b.clone().into_value(), equal);
+	equal
+}
+
+#[test]
+fn duplicate_keys_in_either_object() {
+	let dup = object(vec![("a", 1.0), ("a", 1.0)]);
+	let other = object(vec![("a", 1.0), ("b", 2.0)]);
+	assert!(!all_eq(&dup, &other));
+	assert!(!all_eq(&other, &dup));
+
+	let dup = object(vec![("a", 1.0), ("b", 2.0), ("a", 1.0)]);
+	let other = object(vec![("a", 1.0), ("b", 2.0), ("c", 3.0)]);
+	assert!(!all_eq(&dup, &other));
+	assert!(!all_eq(&other, &dup));
+}
+
+#[test]
+fn nested_duplicate_keys() {
+	let a = TestValue::Array(vec![object(vec![("x", 0.0), ("x", 0.0)])]);
+	let b = TestValue::Array(vec![object(vec![("x", 0.0), ("y", 0.0)])]);
+	assert!(!all_eq(&a, &b));
+	assert!(!all_eq(&b, &a));
+}
+
+#[test]
+fn distinct_keys() {
+	assert!(all_eq(
+		&object(vec![("a", 1.0), ("b", 2.0)]),
+		&object(vec![("b", 2.0), ("a", 1.0)])
+	));
+	assert!(!all_eq(
+		&object(vec![("a", 1.0)]),
+		&object(vec![("a", 1.0), ("b", 2.0)])
+	));
+}
+
+#[test]
+fn ignored_members() {
+	let options = CompareOptions {
+		ignore_null_fields: true,
+		..Default::default()
+	};
+
+	let a = TestValue::object(vec![("a", TestValue::number(1.0)), ("a", TestValue::Null)]);
+	let b = object(vec![("a", 1.0)]);
+	assert!(a.semantically_eq(&b, &options));
+	assert!(b.semantically_eq(&a, &options));
+
+	let a = TestValue::object(vec![("a", TestValue::number(1.0)), ("n", TestValue::Null)]);
+	let b = TestValue::object(vec![
+		("a", TestValue::number(1.0)),
+		("a", TestValue::number(1.0)),
+	]);
+	assert!(!a.semantically_eq(&b, &options));
+	assert!(!b.semantically_eq(&a, &options));
+}
+
+#[test]
+fn streams() {
+	let eq = |value: &TestValue, document: &str| value.eq_stream(document.as_bytes()).unwrap();
+	let dup = object(vec![("a", 1.0), ("a", 1.0)]);
+	let other = object(vec![("a", 1.0), ("b", 2.0)]);
+	assert!(!eq(&dup, r#"{ "a": 1, "b": 2 }"#));
+	assert!(!eq(&dup, r#"{ "a": 1, "a": 1 }"#));
+	assert!(!eq(&other, r#"{ "a": 1, "a": 1 }"#));
+	assert!(eq(&other, r#"{ "b": 2, "a": 1 }"#));
+}
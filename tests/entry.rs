@@ -0,0 +1,112 @@
+#![cfg(feature = "nightly")]
+use cc_traits::{Get, Len, MapIter};
+use generic_json::{Entry, Json, JsonNew, Key as _, MetaValue, NumberBuf, NumberFrom};
+
+fn count(n: i64, metadata: u32) -> MetaValue<u32> {
+	MetaValue::number(NumberBuf::from_i64(n), metadata)
+}
+
+#[test]
+fn histogram() {
+	let mut histogram = MetaValue::empty_object(7);
+	for word in "the cat saw the other cat and the dog".split(' ') {
+		let entry = histogram.entry(word).unwrap();
+		assert_eq!(entry.key(), word);
+		let value = entry.or_insert(count(0, 1));
+		*value = count(value.as_i64().unwrap() + 1, 2);
+	}
+
+	let object = histogram.as_object().unwrap();
+	assert_eq!(object.len(), 6);
+	for (word, expected) in [
+		("the", 3),
+		("cat", 2),
+		("saw", 1),
+		("other", 1),
+		("and", 1),
+		("dog", 1),
+	] {
+		assert_eq!(object.get(word).unwrap().as_i64(), Some(expected));
+	}
+
+	// Inserted keys get the metadata of the object.
+	for (key, _) in MapIter::iter(object) {
+		assert_eq!(key.metadata(), &7)
+	}
+}
+
+#[test]
+fn occupied_and_vacant() {
+	let mut value = MetaValue::empty_object(0);
+	value.entry("a").unwrap().or_insert(count(1, 1));
+
+	match value.entry("a").unwrap() {
+		Entry::Occupied(mut entry) => {
+			assert_eq!(entry.key(), "a");
+			assert_eq!(entry.get().as_i64(), Some(1));
+			*entry.get_mut() = count(2, 2);
+			assert_eq!(entry.into_mut().metadata(), &2);
+		}
+		Entry::Vacant(_) => panic!("`a` is present"),
+	}
+
+	match value.entry("b").unwrap() {
+		Entry::Occupied(_) => panic!("`b` is absent"),
+		Entry::Vacant(entry) => {
+			assert_eq!(entry.key(), "b");
+			assert_eq!(entry.insert(count(3, 3)).as_i64(), Some(3));
+		}
+	}
+
+	assert_eq!(value["a"].as_i64(), Some(2));
+	assert_eq!(value["b"].as_i64(), Some(3));
+}
+
+#[test]
+fn and_modify() {
+	let mut value = MetaValue::empty_object(0);
+
+	// Nothing to modify: the default is inserted.
+	value
+		.entry("a")
+		.unwrap()
+		.and_modify(|_| panic!("`a` is absent"))
+		.or_insert_with(|| count(1, 1));
+	assert_eq!(value["a"].as_i64(), Some(1));
+
+	// The existing value is modified, the default is not used.
+	value
+		.entry("a")
+		.unwrap()
+		.and_modify(|v| *v = count(v.as_i64().unwrap() * 10, 2))
+		.or_insert_with(|| panic!("`a` is present"));
+	assert_eq!(value["a"].as_i64(), Some(10));
+	assert_eq!(value.as_object().unwrap().len(), 1);
+}
+
+#[test]
+fn value_mut_entry() {
+	let mut value = MetaValue::object(
+		vec![(MetaValue::new_key("a", 5), count(1, 1))]
+			.into_iter()
+			.collect(),
+		5,
+	);
+
+	value
+		.as_value_mut()
+		.entry("b")
+		.unwrap()
+		.or_insert(count(2, 2));
+	let (key, _) = MapIter::iter(value.as_object().unwrap())
+		.find(|(key, _)| &***key == "b")
+		.unwrap();
+	assert_eq!(key.metadata(), &0);
+}
+
+#[test]
+fn not_an_object() {
+	assert!(MetaValue::<u32>::empty_array(0).entry("a").is_none());
+	assert!(count(1, 0).entry("a").is_none());
+	assert!(count(1, 0).as_value_mut().entry("a").is_none());
+}
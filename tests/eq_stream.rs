@@ -0,0 +1,67 @@
+#![cfg(feature = "serde_json-impl")]
+mod common;
+
+use common::TestValue;
+use generic_json::{Json, ParseError};
+use serde_json::json;
+use std::io;
+
+fn eq(value: &serde_json::Value, document: &str) -> io::Result<bool> {
+	value.eq_stream(document.as_bytes())
+}
+
+#[test]
+fn matching_streams() {
+	let value = json!({ "a": [1, 2.5, "x\ny"], "b": { "c": null, "d": true } });
+	assert!(eq(&value, r#"{"a":[1,2.5,"x\ny"],"b":{"c":null,"d":true}}"#).unwrap());
+	assert!(eq(
+		&value,
+		r#" { "b" : { "d" : true , "c" : null } , "a" : [ 1.0 , 25e-1 , "x\u000ay" ] } "#
+	)
+	.unwrap());
+	assert!(eq(&json!([]), "[]").unwrap());
+	assert!(eq(&json!({}), "{}").unwrap());
+}
+
+#[test]
+fn mismatching_streams() {
+	let value = json!({ "a": [1, 2], "b": "x" });
+	assert!(!eq(&value, r#"{"a":[1,2]}"#).unwrap());
+	assert!(!eq(&value, r#"{"a":[1,2],"b":"x","c":null}"#).unwrap());
+	assert!(!eq(&value, r#"{"a":[2,1],"b":"x"}"#).unwrap());
+	assert!(!eq(&value, r#"{"a":[1,2,3],"b":"x"}"#).unwrap());
+	assert!(!eq(&value, r#"{"a":[1],"b":"x"}"#).unwrap());
+	assert!(!eq(&value, r#"{"a":[1,2],"b":"y"}"#).unwrap());
+	assert!(!eq(&value, r#"[1,2]"#).unwrap());
+	assert!(!eq(&json!(1), "true").unwrap());
+}
+
+#[test]
+fn duplicate_keys_are_never_equal() {
+	let value = json!({ "a": 1, "b": 2 });
+	assert!(!eq(&value, r#"{"a":1,"a":1}"#).unwrap());
+	assert!(!eq(&value, r#"{"a":1,"b":2,"a":1}"#).unwrap());
+	assert!(!eq(&json!({ "a": 1 }), r#"{"a":1,"a":1}"#).unwrap());
+}
+
+#[test]
+fn short_circuits_before_malformed_input() {
+	assert!(!eq(&json!([1, 2]), "[0, oops").unwrap());
+}
+
+#[test]
+fn malformed_input() {
+	let e = eq(&json!([1, 2]), "[1, 2").unwrap_err();
+	assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+	assert!(e.get_ref().unwrap().downcast_ref::<ParseError>().is_some());
+}
+
+#[test]
+fn duplicate_keys_in_value() {
+	let value = TestValue::object(vec![
+		("a", TestValue::number(1.0)),
+		("a", TestValue::number(1.0)),
+	]);
+	assert!(!value.eq_stream(r#"{"a":1}"#.as_bytes()).unwrap());
+	assert!(!value.eq_stream(r#"{"a":1,"a":1}"#.as_bytes()).unwrap());
+}
@@ -0,0 +1,128 @@
+use generic_json::{
+	CsvError, PairsError, ParseError, PointerError, PrecisionLost, QueryParseError, RefError,
+	SchemaError, TryFromArrayError, TryFromValueError,
+};
+use std::error::Error;
+
+fn assert_error<E: Error + Send + Sync + 'static>() {}
+
+#[test]
+fn public_errors_implement_error() {
+	assert_error::<CsvError>();
+	assert_error::<PairsError>();
+	assert_error::<ParseError>();
+	assert_error::<PointerError>();
+	assert_error::<PrecisionLost>();
+	assert_error::<QueryParseError>();
+	assert_error::<RefError>();
+	assert_error::<SchemaError>();
+	assert_error::<TryFromValueError>();
+	assert_error::<TryFromArrayError<TryFromValueError>>();
+
+	#[cfg(feature = "nightly")]
+	assert_error::<generic_json::PatchError>();
+}
+
+/// Returns the messages of the given error and of its sources.
+#[cfg(feature = "serde_json-impl")]
+fn chain(e: &(dyn Error + 'static)) -> Vec<String> {
+	let mut messages = vec![e.to_string()];
+	let mut source = e.source();
+	while let Some(e) = source {
+		messages.push(e.to_string());
+		source = e.source()
+	}
+
+	messages
+}
+
+#[test]
+fn parse_error_downcast() {
+	let e: Box<dyn Error> = generic_json::TriviaValue::parse("[1,").unwrap_err().into();
+	assert!(e.downcast_ref::<ParseError>().is_some());
+	assert!(e.source().is_none())
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn nested_array_error_chain() {
+	use generic_json::Value;
+	use std::convert::TryFrom;
+
+	let value: Value<serde_json::Value> = serde_json::json!([[1, 2], [3, "x"]]).into();
+	let e = Vec::<Vec<u32>>::try_from(value).unwrap_err();
+
+	assert_eq!(
+		chain(&e),
+		[
+			"invalid array element at index 1",
+			"invalid array element at index 1",
+			"value is not a valid `u32`"
+		]
+	);
+
+	let boxed: Box<dyn Error> = Box::new(e);
+	let inner = boxed
+		.source()
+		.unwrap()
+		.downcast_ref::<TryFromArrayError<TryFromValueError>>()
+		.unwrap();
+	assert!(matches!(inner, TryFromArrayError::Element(1, _)));
+
+	let leaf = inner.source().unwrap().downcast_ref::<TryFromValueError>();
+	assert_eq!(leaf.map(TryFromValueError::expected), Some("u32"));
+}
+
+#[cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+#[test]
+fn patch_error_wraps_pointer_error() {
+	use generic_json::{apply_patch, PatchError, PatchErrorKind, PatchOp, Value};
+
+	let mut value = serde_json::json!({ "a": [1, 2] });
+	let patch = [
+		PatchOp::Remove {
+			path: "/a/0".to_string(),
+		},
+		PatchOp::Add {
+			path: "/a/5".to_string(),
+			value: Value::Null,
+		},
+	];
+
+	let e = apply_patch(&mut value, &patch).unwrap_err();
+	assert_eq!(e.operation(), 1);
+	assert_eq!(
+		e.kind(),
+		&PatchErrorKind::Pointer(PointerError::InvalidIndex("/a/5".to_string()))
+	);
+	assert_eq!(
+		chain(&e),
+		[
+			"cannot resolve `/a/5` (operation 1)",
+			"invalid array index in `/a/5`"
+		]
+	);
+
+	let boxed: Box<dyn Error + Send + Sync> = Box::new(e);
+	let e = boxed.downcast::<PatchError>().unwrap();
+	let source = e.source().unwrap().downcast_ref::<PointerError>();
+	assert_eq!(source.map(PointerError::pointer), Some("/a/5"));
+}
+
+#[cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+#[test]
+fn patch_error_without_source() {
+	use generic_json::{apply_patch, PatchErrorKind, PatchOp};
+
+	let mut value = serde_json::json!({ "a": 1 });
+	let e = apply_patch(
+		&mut value,
+		&[PatchOp::Remove {
+			path: "/b".to_string(),
+		}],
+	)
+	.unwrap_err();
+
+	assert_eq!(e.kind(), &PatchErrorKind::NotFound("/b".to_string()));
+	assert!(e.source().is_none());
+}
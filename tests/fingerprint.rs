@@ -0,0 +1,69 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{Fingerprinted, Json};
+use serde_json::json;
+use std::collections::HashSet;
+
+#[test]
+fn equal_values_match() {
+	let a = json!({ "a": [1, "x", null], "b": { "c": true } });
+	let b = json!({ "b": { "c": true }, "a": [1.0, "x", null] });
+	assert_eq!(a.fingerprint(), b.fingerprint());
+	assert_eq!(json!(-0.0).fingerprint(), json!(0).fingerprint());
+}
+
+#[test]
+fn unequal_values_differ() {
+	let values = vec![
+		json!(null),
+		json!(false),
+		json!(true),
+		json!(0),
+		json!(1),
+		json!(1.5),
+		json!("1"),
+		json!(""),
+		json!([]),
+		json!({}),
+		json!([1]),
+		json!([[1]]),
+		json!([1, 2]),
+		json!([2, 1]),
+		json!({ "a": 1 }),
+		json!({ "a": "1" }),
+		json!({ "b": 1 }),
+		json!({ "a": 1, "b": 1 }),
+		json!({ "a": [1] }),
+	];
+
+	let fingerprints: HashSet<_> = values.iter().map(Json::fingerprint).collect();
+	assert_eq!(fingerprints.len(), values.len());
+}
+
+#[test]
+fn fingerprinted_comparison() {
+	let a = json!({ "a": [1, 2] });
+	let b = json!({ "a": [1.0, 2] });
+	let c = json!({ "a": [2, 1] });
+
+	let fa = Fingerprinted::new(&a);
+	assert_eq!(fa.fingerprint(), a.fingerprint());
+	assert!(std::ptr::eq(fa.value(), &a));
+
+	// Matching fingerprints fall back to a full comparison.
+	assert!(fa == Fingerprinted::new(&b));
+	assert!(fa != Fingerprinted::new(&c));
+
+	let set: HashSet<_> = vec![&a, &b, &c]
+		.into_iter()
+		.map(Fingerprinted::new)
+		.collect();
+	assert_eq!(set.len(), 2);
+}
+
+#[cfg(all(feature = "nightly", feature = "ijson-impl"))]
+#[test]
+fn across_backends() {
+	let value = json!({ "a": [1, 0.5, u64::MAX], "b": "x" });
+	let ivalue: ijson::IValue = generic_json::to_backend(&value);
+	assert_eq!(value.fingerprint(), ivalue.fingerprint());
+}
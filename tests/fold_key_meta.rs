@@ -0,0 +1,85 @@
+use cc_traits::{Get, Iter, MapIter};
+use generic_json::{Json, JsonNew, Key as _, MetaKey, MetaValue, NumberBuf};
+
+type Span = (usize, usize);
+
+fn merge(a: Span, b: Span) -> Span {
+	(a.0.min(b.0), a.1.max(b.1))
+}
+
+fn member(key: &str, key_span: Span, value: MetaValue<Span>) -> (MetaKey<Span>, MetaValue<Span>) {
+	(MetaKey::new(key.to_string(), key_span), value)
+}
+
+/// `{"a": 1, "b": [{"c": true}]}` with the spans of each token.
+fn document() -> MetaValue<Span> {
+	MetaValue::object(
+		vec![
+			member(
+				"a",
+				(1, 4),
+				MetaValue::number(NumberBuf::from(1u32), (6, 7)),
+			),
+			member(
+				"b",
+				(9, 12),
+				MetaValue::array(
+					vec![MetaValue::object(
+						vec![member("c", (16, 19), MetaValue::boolean(true, (21, 25)))]
+							.into_iter()
+							.collect(),
+						(15, 26),
+					)]
+					.into_iter()
+					.collect(),
+					(14, 27),
+				),
+			),
+		]
+		.into_iter()
+		.collect(),
+		(0, 28),
+	)
+}
+
+#[test]
+fn member_spans() {
+	let value = document().fold_key_meta_into_values(merge);
+	assert_eq!(value.metadata(), &(0, 28));
+
+	let o = value.as_object().unwrap();
+	assert_eq!(o.get("a").unwrap().metadata(), &(1, 7));
+
+	let b = o.get("b").unwrap();
+	assert_eq!(b.metadata(), &(9, 27));
+
+	let inner = Iter::iter(b.as_array().unwrap()).next().unwrap();
+	assert_eq!(inner.metadata(), &(15, 26));
+	assert_eq!(
+		inner.as_object().unwrap().get("c").unwrap().metadata(),
+		&(16, 25)
+	);
+}
+
+#[test]
+fn keys_keep_their_metadata() {
+	let value = document().fold_key_meta_into_values(merge);
+	let keys: Vec<_> = MapIter::iter(value.as_object().unwrap())
+		.map(|(key, _)| *key.metadata())
+		.collect();
+	assert_eq!(keys, [(1, 4), (9, 12)]);
+}
+
+#[test]
+fn combine_order() {
+	let mut calls = Vec::new();
+	document().fold_key_meta_into_values(|key, value| {
+		calls.push((key, value));
+		value
+	});
+
+	assert_eq!(
+		calls,
+		[((1, 4), (6, 7)), ((16, 19), (21, 25)), ((9, 12), (14, 27))]
+	);
+}
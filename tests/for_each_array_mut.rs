@@ -0,0 +1,40 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{Json, ValueMut};
+use serde_json::json;
+
+#[test]
+fn negate_numbers() {
+	let mut value = json!([1, -2.5, "3", null, [4]]);
+	value.for_each_array_mut(|item| {
+		if let ValueMut::Number(n) = item {
+			*n = match n.as_i64() {
+				Some(i) => (-i).into(),
+				None => serde_json::Number::from_f64(-n.as_f64().unwrap()).unwrap(),
+			}
+		}
+	});
+
+	assert_eq!(value, json!([-1, 2.5, "3", null, [4]]));
+}
+
+#[test]
+fn visits_elements_in_order() {
+	let mut value = json!(["a", "b", "c"]);
+	let mut seen = Vec::new();
+	value.for_each_array_mut(|item| {
+		if let ValueMut::String(s) = item {
+			seen.push(s.clone());
+			s.push('!')
+		}
+	});
+
+	assert_eq!(seen, ["a", "b", "c"]);
+	assert_eq!(value, json!(["a!", "b!", "c!"]));
+}
+
+#[test]
+fn non_arrays_are_ignored() {
+	let mut value = json!({ "a": 1 });
+	value.for_each_array_mut(|_| panic!("called on an object member"));
+	assert_eq!(value, json!({ "a": 1 }));
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "serde_json-impl")]
+mod common;
+
+use cc_traits::{Get, Iter, Len, MapInsert, MapIter};
+use common::TestValue;
+use generic_json::{
+	pointer, FrozenValue, Json, JsonNew, Key as _, MetaKey, MetaValue, Number, NumberBuf,
+	SharedValue, ValueRef,
+};
+use serde_json::json;
+use std::{sync::Arc, thread};
+
+fn get<'a>(value: &'a SharedValue, key: &str) -> &'a SharedValue {
+	value.as_object().unwrap().get(key).unwrap()
+}
+
+fn assert_send_sync_clone<T: Send + Sync + Clone>() {}
+
+#[test]
+fn frozen_values_are_shareable() {
+	assert_send_sync_clone::<FrozenValue>();
+	assert_send_sync_clone::<FrozenValue<String>>();
+}
+
+#[test]
+fn query_across_threads() {
+	let frozen = json!({
+		"users": [
+			{ "name": "a", "age": 20 },
+			{ "name": "b", "age": 30 },
+			{ "name": "c", "age": 40 }
+		]
+	})
+	.freeze()
+	.unwrap();
+
+	let handles: Vec<_> = (0..3)
+		.map(|i| {
+			let frozen = frozen.clone();
+			thread::spawn(move || {
+				let user = get(&frozen, "users").as_array().unwrap().get(i).unwrap();
+				(
+					get(user, "name").as_str().unwrap().to_string(),
+					get(user, "age").as_u64().unwrap(),
+				)
+			})
+		})
+		.collect();
+
+	let users: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+	assert_eq!(
+		users,
+		[
+			("a".to_string(), 20),
+			("b".to_string(), 30),
+			("c".to_string(), 40)
+		]
+	);
+
+	assert_eq!(get(&frozen, "users").as_array().unwrap().len(), 3);
+	assert_eq!(
+		pointer(&*frozen, "/users/1/name").unwrap().as_str(),
+		Some("b")
+	);
+}
+
+#[test]
+fn clones_share_storage() {
+	let frozen = json!({ "a": [1, 2, 3], "b": "x" }).freeze().unwrap();
+	let clone = frozen.clone();
+	assert_eq!(frozen, clone);
+	assert!(frozen
+		.as_object()
+		.unwrap()
+		.ptr_eq(clone.as_object().unwrap()));
+	assert!(get(&frozen, "a")
+		.as_array()
+		.unwrap()
+		.ptr_eq(get(&clone, "a").as_array().unwrap()));
+	match (
+		get(&frozen, "b").as_value_ref(),
+		get(&clone, "b").as_value_ref(),
+	) {
+		(ValueRef::String(a), ValueRef::String(b)) => assert!(Arc::ptr_eq(a, b)),
+		_ => panic!("`b` is a string"),
+	}
+}
+
+#[test]
+fn thawing_does_not_affect_clones() {
+	let frozen = json!({ "a": [1] }).freeze().unwrap();
+	let mut shared = frozen.clone().into_shared();
+	shared
+		.as_object_mut()
+		.unwrap()
+		.insert(SharedValue::new_key("a", ()), SharedValue::null(()));
+
+	assert!(get(&shared, "a").is_null());
+	assert_eq!(get(&frozen, "a").as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn keep_metadata() {
+	let value: MetaValue<u32> = MetaValue::object(
+		vec![(
+			MetaKey::new("a".to_string(), 1),
+			MetaValue::array(vec![MetaValue::null(2)], 3),
+		)]
+		.into_iter()
+		.collect(),
+		4,
+	);
+
+	let frozen = value.freeze().unwrap();
+	assert_eq!(frozen.metadata(), &4);
+	let (key, a) = MapIter::iter(frozen.as_object().unwrap()).next().unwrap();
+	assert_eq!(key.metadata(), &1);
+	assert_eq!(a.metadata(), &3);
+	assert_eq!(a.as_array().unwrap().get(0).unwrap().metadata(), &2);
+}
+
+#[test]
+fn keep_lexical_numbers() {
+	let value: MetaValue = MetaValue::array(
+		vec![
+			MetaValue::number(NumberBuf::new("1.50".to_string()).unwrap(), ()),
+			MetaValue::number(NumberBuf::new("1e400".to_string()).unwrap(), ()),
+			MetaValue::number(NumberBuf::from(u64::MAX), ()),
+		],
+		(),
+	);
+
+	let frozen = value.freeze().unwrap();
+	let numbers: Vec<_> = frozen
+		.as_array()
+		.unwrap()
+		.iter()
+		.map(|n| Number::as_str(n.as_number().unwrap()).unwrap().to_string())
+		.collect();
+	assert_eq!(numbers, ["1.50", "1e400", "18446744073709551615"]);
+}
+
+#[test]
+fn reject_invalid_numbers() {
+	let value = TestValue::object(vec![(
+		"a",
+		TestValue::Array(vec![TestValue::number(1.5), TestValue::number(f64::NAN)]),
+	)]);
+	assert!(value.freeze().is_none());
+	assert!(TestValue::number(f64::INFINITY).freeze().is_none());
+
+	// Numbers without a lexical representation are frozen exactly when finite.
+	let frozen = TestValue::number(0.1).freeze().unwrap();
+	assert_eq!(frozen.as_f64(), Some(0.1));
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn generic_functions_take_the_underlying_value() {
+	let value = json!({ "a": [1, "b", null], "c": { "d": true } });
+	let frozen = value.clone().freeze().unwrap();
+	let copy: serde_json::Value = generic_json::to_backend(&*frozen);
+	assert_eq!(copy, value);
+}
@@ -0,0 +1,29 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::Json;
+use serde_json::json;
+
+#[test]
+fn case_insensitive_lookup() {
+	let headers = json!({ "content-type": "application/json", "Accept": "*/*" });
+	assert_eq!(
+		headers.get_ci("Content-Type").and_then(|v| v.into_str()),
+		Some("application/json")
+	);
+	assert_eq!(
+		headers.get_ci("ACCEPT").and_then(|v| v.into_str()),
+		Some("*/*")
+	);
+	assert!(headers.get_ci("content-length").is_none());
+}
+
+#[test]
+fn first_match_in_iteration_order() {
+	// "X-A" comes first, both in key and insertion order.
+	let value = json!({ "X-A": 1, "x-a": 2 });
+	assert_eq!(value.get_ci("x-A").and_then(|v| v.as_u64()), Some(1));
+}
+
+#[test]
+fn not_an_object() {
+	assert!(json!(["content-type"]).get_ci("content-type").is_none());
+}
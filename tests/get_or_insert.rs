@@ -0,0 +1,38 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::Json;
+use serde_json::json;
+
+#[test]
+fn create_and_reuse_array() {
+	let mut value = json!({ "a": 1 });
+	value.get_or_insert_array("list").unwrap().push(json!(1));
+	value.get_or_insert_array("list").unwrap().push(json!(2));
+	assert_eq!(value, json!({ "a": 1, "list": [1, 2] }));
+	assert_eq!(value.get_or_insert_array("list").unwrap().len(), 2);
+}
+
+#[test]
+fn create_and_reuse_object() {
+	let mut value = json!({});
+	value
+		.get_or_insert_object("o")
+		.unwrap()
+		.insert("x".to_string(), json!(1));
+	value
+		.get_or_insert_object("o")
+		.unwrap()
+		.insert("y".to_string(), json!(2));
+	assert_eq!(value, json!({ "o": { "x": 1, "y": 2 } }));
+}
+
+#[test]
+fn conflicting_values() {
+	let mut value = json!({ "a": 1, "o": {}, "l": [] });
+	assert!(value.get_or_insert_array("a").is_none());
+	assert!(value.get_or_insert_array("o").is_none());
+	assert!(value.get_or_insert_object("l").is_none());
+	assert_eq!(value, json!({ "a": 1, "o": {}, "l": [] }));
+
+	assert!(json!([]).get_or_insert_array("a").is_none());
+	assert!(json!(null).get_or_insert_object("a").is_none());
+}
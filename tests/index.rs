@@ -0,0 +1,98 @@
+use generic_json::{Json, JsonNew, MetaValue, NumberBuf};
+
+fn meta_value() -> MetaValue {
+	// `{ "a": [1, { "b": "x" }] }`
+	vec![(
+		"a".to_string(),
+		vec![
+			MetaValue::number(NumberBuf::from(1u32), ()),
+			vec![("b".to_string(), MetaValue::string("x".to_string(), ()))]
+				.into_iter()
+				.collect(),
+		]
+		.into_iter()
+		.collect(),
+	)]
+	.into_iter()
+	.collect()
+}
+
+#[test]
+fn nested_meta_value() {
+	let value = meta_value();
+	assert_eq!(value["a"][0].as_u64(), Some(1));
+	assert_eq!(value["a"][1]["b"].as_str(), Some("x"));
+}
+
+#[test]
+fn nested_meta_value_mut() {
+	let mut value = meta_value();
+	value["a"][1]["b"] = MetaValue::string("y".to_string(), ());
+	value["a"][0] = MetaValue::null(());
+	assert_eq!(value["a"][1]["b"].as_str(), Some("y"));
+	assert!(value["a"][0].is_null());
+}
+
+#[test]
+#[should_panic(expected = "no such key `c`")]
+fn missing_key() {
+	let _ = &meta_value()["c"];
+}
+
+#[test]
+#[should_panic(expected = "index 2 out of bounds")]
+fn out_of_bounds() {
+	let _ = &meta_value()["a"][2];
+}
+
+#[test]
+#[should_panic(expected = "cannot index")]
+fn wrong_kind() {
+	let _ = &meta_value()["a"]["b"];
+}
+
+#[test]
+#[should_panic(expected = "cannot index")]
+fn wrong_kind_mut() {
+	let mut value = meta_value();
+	value[0] = MetaValue::null(());
+}
+
+#[cfg(feature = "serde_json-impl")]
+mod serde_json_backend {
+	use generic_json::{Json, Value};
+	use serde_json::json;
+
+	#[test]
+	fn value() {
+		let mut value: Value<serde_json::Value> = json!({ "a": [1, { "b": "x" }] }).into_value();
+		assert_eq!(value["a"][1]["b"], "x");
+		assert_eq!(value["a"], json!([1, { "b": "x" }]));
+
+		value["a"] = json!(null);
+		assert!(value["a"].is_null());
+	}
+
+	#[test]
+	fn value_ref() {
+		let document = json!([{ "a": 1 }, 2]);
+		let value = document.as_value_ref();
+		assert_eq!(value[0]["a"], 1);
+		assert_eq!(value[1], 2);
+		assert!(value.get_index(2).is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "index 0 out of bounds")]
+	fn value_out_of_bounds() {
+		let value: Value<serde_json::Value> = json!([]).into_value();
+		let _ = &value[0];
+	}
+
+	#[test]
+	#[should_panic(expected = "cannot index")]
+	fn value_ref_wrong_kind() {
+		let document = json!([1]);
+		let _ = &document.as_value_ref()["a"];
+	}
+}
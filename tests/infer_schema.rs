@@ -0,0 +1,74 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{infer_schema_from, InferredSchema};
+use serde_json::json;
+
+fn leaf(f: impl FnOnce(&mut InferredSchema)) -> InferredSchema {
+	let mut schema = InferredSchema::default();
+	f(&mut schema);
+	schema
+}
+
+#[test]
+fn optional_fields_and_unions() {
+	let schema = infer_schema_from(vec![
+		json!({ "id": 1, "name": "a", "tags": ["x"] }),
+		json!({ "id": 2, "name": null, "extra": true }),
+		json!({ "id": "3", "name": "c", "tags": [] }),
+	]);
+
+	assert_eq!(schema.type_count(), 1);
+	let fields = schema.object.as_ref().unwrap();
+	assert_eq!(
+		fields.keys().map(String::as_str).collect::<Vec<_>>(),
+		["extra", "id", "name", "tags"]
+	);
+
+	let id = &fields["id"];
+	assert!(!id.optional);
+	assert!(id.schema.is_union());
+	assert_eq!(
+		id.schema,
+		leaf(|s| {
+			s.number = true;
+			s.string = true
+		})
+	);
+
+	let name = &fields["name"];
+	assert!(!name.optional);
+	assert_eq!(
+		name.schema,
+		leaf(|s| {
+			s.null = true;
+			s.string = true
+		})
+	);
+
+	let extra = &fields["extra"];
+	assert!(extra.optional);
+	assert_eq!(extra.schema, leaf(|s| s.boolean = true));
+
+	let tags = &fields["tags"];
+	assert!(tags.optional);
+	assert!(!tags.schema.is_union());
+	assert_eq!(
+		**tags.schema.array.as_ref().unwrap(),
+		leaf(|s| s.string = true)
+	);
+}
+
+#[test]
+fn objects_and_scalars() {
+	let schema = infer_schema_from(vec![json!({ "a": 1 }), json!(1), json!([])]);
+	assert_eq!(schema.type_count(), 3);
+	assert!(schema.number);
+	assert!(!schema.object.as_ref().unwrap()["a"].optional);
+	assert!(schema.array.as_ref().unwrap().is_empty());
+}
+
+#[test]
+fn no_examples() {
+	let schema = infer_schema_from(Vec::<serde_json::Value>::new());
+	assert!(schema.is_empty());
+	assert_eq!(schema, InferredSchema::default());
+}
@@ -0,0 +1,56 @@
+use cc_traits::{Get, Iter};
+use generic_json::{Json, JsonNew, SharedValue};
+use std::sync::Arc;
+
+fn strings(value: &SharedValue) -> Vec<&str> {
+	Iter::iter(value.as_array().unwrap())
+		.map(|item| item.as_str().unwrap())
+		.collect()
+}
+
+#[test]
+fn equal_strings_share_storage() {
+	let mut value = SharedValue::array(
+		(0..1000)
+			.map(|_| SharedValue::string(Arc::from("repeated"), ()))
+			.chain(std::iter::once(SharedValue::string(Arc::from("other"), ())))
+			.collect(),
+		(),
+	);
+
+	let before = strings(&value);
+	assert!(before[0].as_ptr() != before[1].as_ptr());
+
+	value.intern_strings();
+	let after = strings(&value);
+	assert!(after[..1000]
+		.iter()
+		.all(|s| s.as_ptr() == after[0].as_ptr()));
+	assert!(after[..1000].iter().all(|s| *s == "repeated"));
+	assert_eq!(after[1000], "other");
+}
+
+#[test]
+fn nested_strings_share_storage() {
+	let string = || SharedValue::string(Arc::from("x"), ());
+	let mut value = SharedValue::array(
+		vec![
+			string(),
+			SharedValue::object(
+				vec![(SharedValue::new_key("x", ()), string())]
+					.into_iter()
+					.collect(),
+				(),
+			),
+		]
+		.into_iter()
+		.collect(),
+		(),
+	);
+
+	value.intern_strings();
+	let a = value.as_array().unwrap();
+	let first = a.get(0).unwrap().as_str().unwrap();
+	let nested = a.get(1).unwrap().as_object().unwrap().get("x").unwrap();
+	assert_eq!(first.as_ptr(), nested.as_str().unwrap().as_ptr());
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::json;
+
+/// One value of each kind, with whether it is a boolean.
+fn values() -> Vec<(serde_json::Value, bool)> {
+	vec![
+		(json!(null), false),
+		(json!(true), true),
+		(json!(false), true),
+		(json!(0), false),
+		(json!("true"), false),
+		(json!([true]), false),
+		(json!({ "a": true }), false),
+	]
+}
+
+#[test]
+fn is_bool_on_every_kind() {
+	for (mut value, expected) in values() {
+		assert_eq!(Json::is_bool(&value), expected, "{}", value);
+		assert_eq!(value.as_value_ref().is_bool(), expected, "{}", value);
+		assert_eq!(value.as_value_mut().is_bool(), expected, "{}", value);
+		assert_eq!(value.clone().into_value().is_bool(), expected, "{}", value);
+		assert_eq!(Json::as_bool(&value).is_some(), expected, "{}", value);
+	}
+}
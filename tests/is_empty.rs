@@ -0,0 +1,30 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::json;
+
+#[test]
+fn is_empty_object() {
+	assert!(json!({}).is_empty_object());
+	assert!(!json!({ "a": {} }).is_empty_object());
+	assert!(!json!([]).is_empty_object());
+	assert!(!json!([{}]).is_empty_object());
+	assert!(!json!(null).is_empty_object());
+	assert!(!json!("").is_empty_object());
+}
+
+#[test]
+fn is_empty_array() {
+	assert!(json!([]).is_empty_array());
+	assert!(!json!([[]]).is_empty_array());
+	assert!(!json!({}).is_empty_array());
+	assert!(!json!(0).is_empty_array());
+}
+
+#[test]
+fn is_empty_array_or_object() {
+	assert!(json!([]).is_empty_array_or_object());
+	assert!(json!({}).is_empty_array_or_object());
+	assert!(!json!([null]).is_empty_array_or_object());
+	assert!(!json!({ "a": null }).is_empty_array_or_object());
+	assert!(!json!("").is_empty_array_or_object());
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::TestValue;
+use generic_json::Json;
+
+#[test]
+fn falsy() {
+	for value in [
+		TestValue::Null,
+		TestValue::Boolean(false),
+		TestValue::string(""),
+		TestValue::number(0.0),
+		TestValue::number(-0.0),
+		TestValue::number(f64::NAN),
+		TestValue::number(-f64::NAN),
+	] {
+		assert!(!value.is_js_truthy(), "{:?} is truthy", value);
+		assert!(!value.as_value_ref().is_js_truthy());
+	}
+}
+
+#[test]
+fn truthy() {
+	for mut value in [
+		TestValue::Boolean(true),
+		TestValue::string("0"),
+		TestValue::string("false"),
+		TestValue::number(-1.0),
+		TestValue::number(0.5),
+		TestValue::number(f64::MIN_POSITIVE),
+		TestValue::number(f64::INFINITY),
+		TestValue::number(f64::NEG_INFINITY),
+		TestValue::Array(Vec::new()),
+		TestValue::Array(vec![TestValue::Null]),
+		TestValue::object(Vec::new()),
+	] {
+		assert!(value.is_js_truthy(), "{:?} is falsy", value);
+		assert!(value.as_value_mut().is_js_truthy());
+	}
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json_numbers() {
+	use serde_json::json;
+
+	assert!(!json!(0).is_js_truthy());
+	assert!(!json!(0.0).is_js_truthy());
+	assert!(!json!(-0.0).is_js_truthy());
+	assert!(json!(1e-300).is_js_truthy());
+	assert!(json!(u64::MAX).is_js_truthy());
+}
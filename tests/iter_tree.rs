@@ -0,0 +1,77 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{Json, PathComponent, ValueKind};
+use serde_json::{json, Value};
+
+#[test]
+fn pre_order() {
+	// Keys are sorted so that iteration order does not depend
+	// on `serde_json`'s `preserve_order` feature.
+	let value = json!({ "a": [1, { "b": null }], "c/d": "x", "e": {} });
+	let nodes: Vec<_> = value
+		.iter_tree()
+		.map(|(path, node)| (path.to_string(), node.kind()))
+		.collect();
+
+	assert_eq!(
+		nodes,
+		[
+			(String::new(), ValueKind::Object),
+			("/a".to_string(), ValueKind::Array),
+			("/a/0".to_string(), ValueKind::Number),
+			("/a/1".to_string(), ValueKind::Object),
+			("/a/1/b".to_string(), ValueKind::Null),
+			("/c~1d".to_string(), ValueKind::String),
+			("/e".to_string(), ValueKind::Object),
+		]
+	)
+}
+
+#[test]
+fn count_nodes() {
+	assert_eq!(json!(null).iter_tree().count(), 1);
+	assert_eq!(json!([]).iter_tree().count(), 1);
+	assert_eq!(json!([[1, 2], { "a": [3] }, "x"]).iter_tree().count(), 8);
+}
+
+#[test]
+fn lazy() {
+	let value = json!([[1], 2]);
+	let mut nodes = value.iter_tree();
+	let (path, root) = nodes.next().unwrap();
+	assert!(path.is_root());
+	assert_eq!(root.kind(), ValueKind::Array);
+	assert_eq!(nodes.next().unwrap().0.to_string(), "/0");
+}
+
+#[test]
+fn deeply_nested_leaf() {
+	const DEPTH: usize = 10_000;
+
+	let mut value = json!("leaf");
+	for i in 0..DEPTH {
+		value = if i % 2 == 0 {
+			Value::Array(vec![value])
+		} else {
+			Value::Object(std::iter::once(("k".to_string(), value)).collect())
+		}
+	}
+
+	{
+		let (path, leaf) = value.iter_tree().last().unwrap();
+		assert_eq!(leaf.as_str(), Some("leaf"));
+		assert_eq!(path.depth(), DEPTH);
+		assert_eq!(path.last(), Some(PathComponent::Index(0)));
+		assert_eq!(path.parent().unwrap().last(), Some(PathComponent::Key("k")));
+		assert_eq!(value.iter_tree().count(), DEPTH + 1);
+	}
+
+	// Unwind the value iteratively so that dropping it does not overflow the stack.
+	let mut stack = vec![value];
+	while let Some(mut value) = stack.pop() {
+		match &mut value {
+			Value::Array(a) => stack.append(a),
+			Value::Object(o) => stack.extend(std::mem::take(o).into_iter().map(|(_, v)| v)),
+			_ => (),
+		}
+	}
+}
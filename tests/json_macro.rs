@@ -0,0 +1,60 @@
+#![cfg(feature = "nightly")]
+use cc_traits::Len;
+use generic_json::{json, Json, MetaValue, Number, Value};
+
+fn int(value: &Value<MetaValue>) -> Option<i64> {
+	value.as_number().and_then(Number::as_i64)
+}
+
+#[test]
+fn keywords_and_literals() {
+	let value: Value<MetaValue> = json!(MetaValue; [null, true, false, 0, -7, 2.5, "x"]);
+	assert!(value[0].is_null());
+	assert_eq!(value[1].as_bool(), Some(true));
+	assert_eq!(value[2].as_bool(), Some(false));
+	assert_eq!(value[3].as_u64(), Some(0));
+	assert_eq!(value[4].as_i64(), Some(-7));
+	assert_eq!(value[5].as_f64(), Some(2.5));
+	assert_eq!(value[6].as_str(), Some("x"));
+}
+
+#[test]
+fn interpolate_vec() {
+	let numbers: Vec<i32> = vec![3, -1, 4];
+	let value: Value<MetaValue> =
+		json!(MetaValue; { "numbers": #[numbers], "len": #[numbers.len()] });
+	assert_eq!(value["len"].as_u64(), Some(3));
+
+	let numbers = value["numbers"].as_array().unwrap();
+	assert_eq!(numbers.len(), 3);
+	assert_eq!(numbers[1].as_i64(), Some(-1));
+	assert_eq!(int(&json!(MetaValue; #[numbers[2].as_i64()])), Some(4));
+}
+
+#[test]
+fn interpolate_options_and_non_finite() {
+	let value: Value<MetaValue> = json!(MetaValue; [#[None::<u8>], #[Some("x")], #[f64::NAN]]);
+	assert!(value[0].is_null());
+	assert_eq!(value[1].as_str(), Some("x"));
+	assert!(value[2].is_null());
+}
+
+#[test]
+fn nested() {
+	let value: Value<MetaValue> = json!(MetaValue; { "a": [{ "b": [] }, {}], "c": { "d": -1.5 } });
+	assert!(value["a"][0]["b"].as_array().unwrap().is_empty());
+	assert!(value["a"][1].as_object().unwrap().is_empty());
+	assert_eq!(value["c"]["d"].as_f64(), Some(-1.5));
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json_backend() {
+	let tags = vec![1, 2];
+	let value: Value<serde_json::Value> =
+		json!(serde_json::Value; { "a": [null, true, -3, 0.5, "s"], "tags": #[tags] });
+	assert_eq!(
+		value.with_default(),
+		serde_json::json!({ "a": [null, true, -3, 0.5, "s"], "tags": [1, 2] })
+	);
+}
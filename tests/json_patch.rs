@@ -0,0 +1,234 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{
+	apply_patch, json_eq, to_backend, Json, MetaValue, PatchErrorKind, PatchOp, PointerError, Value,
+};
+use serde_json::json;
+
+fn meta(value: serde_json::Value) -> MetaValue {
+	to_backend(&value)
+}
+
+fn v(value: serde_json::Value) -> Value<MetaValue> {
+	meta(value).into_value()
+}
+
+fn add(path: &str, value: serde_json::Value) -> PatchOp<MetaValue> {
+	PatchOp::Add {
+		path: path.to_string(),
+		value: v(value),
+	}
+}
+
+fn test(path: &str, value: serde_json::Value) -> PatchOp<MetaValue> {
+	PatchOp::Test {
+		path: path.to_string(),
+		value: v(value),
+	}
+}
+
+/// Applies the patch and checks the result.
+fn check(document: serde_json::Value, patch: &[PatchOp<MetaValue>], expected: serde_json::Value) {
+	let mut value = meta(document);
+	apply_patch(&mut value, patch).unwrap();
+	assert!(json_eq(&value, &expected), "{:?}", value);
+}
+
+/// Applies the patch, checking that it fails on the given operation
+/// and leaves the document untouched.
+fn check_error(document: serde_json::Value, patch: &[PatchOp<MetaValue>]) -> PatchErrorKind {
+	let mut value = meta(document.clone());
+	let err = apply_patch(&mut value, patch).unwrap_err();
+	assert_eq!(err.operation(), patch.len() - 1);
+	assert!(json_eq(&value, &document));
+	err.kind().clone()
+}
+
+#[test]
+fn a1_add_object_member() {
+	check(
+		json!({ "foo": "bar" }),
+		&[add("/baz", json!("qux"))],
+		json!({ "baz": "qux", "foo": "bar" }),
+	)
+}
+
+#[test]
+fn a2_add_array_element() {
+	check(
+		json!({ "foo": ["bar", "baz"] }),
+		&[add("/foo/1", json!("qux"))],
+		json!({ "foo": ["bar", "qux", "baz"] }),
+	)
+}
+
+#[test]
+fn a3_remove_object_member() {
+	check(
+		json!({ "baz": "qux", "foo": "bar" }),
+		&[PatchOp::Remove {
+			path: "/baz".to_string(),
+		}],
+		json!({ "foo": "bar" }),
+	)
+}
+
+#[test]
+fn a4_remove_array_element() {
+	check(
+		json!({ "foo": ["bar", "qux", "baz"] }),
+		&[PatchOp::Remove {
+			path: "/foo/1".to_string(),
+		}],
+		json!({ "foo": ["bar", "baz"] }),
+	)
+}
+
+#[test]
+fn a5_replace_value() {
+	check(
+		json!({ "baz": "qux", "foo": "bar" }),
+		&[PatchOp::Replace {
+			path: "/baz".to_string(),
+			value: v(json!("boo")),
+		}],
+		json!({ "baz": "boo", "foo": "bar" }),
+	)
+}
+
+#[test]
+fn a6_move_value() {
+	check(
+		json!({ "foo": { "bar": "baz", "waldo": "fred" }, "qux": { "corge": "grault" } }),
+		&[PatchOp::Move {
+			from: "/foo/waldo".to_string(),
+			path: "/qux/thud".to_string(),
+		}],
+		json!({ "foo": { "bar": "baz" }, "qux": { "corge": "grault", "thud": "fred" } }),
+	)
+}
+
+#[test]
+fn a7_move_array_element() {
+	check(
+		json!({ "foo": ["all", "grass", "cows", "eat"] }),
+		&[PatchOp::Move {
+			from: "/foo/1".to_string(),
+			path: "/foo/3".to_string(),
+		}],
+		json!({ "foo": ["all", "cows", "eat", "grass"] }),
+	)
+}
+
+#[test]
+fn a8_test_success() {
+	let document = json!({ "baz": "qux", "foo": ["a", 2, "c"] });
+	check(
+		document.clone(),
+		&[test("/baz", json!("qux")), test("/foo/1", json!(2))],
+		document,
+	)
+}
+
+#[test]
+fn a9_test_error() {
+	assert_eq!(
+		check_error(json!({ "baz": "qux" }), &[test("/baz", json!("bar"))]),
+		PatchErrorKind::TestFailed("/baz".to_string())
+	)
+}
+
+#[test]
+fn a10_add_nested_member_object() {
+	check(
+		json!({ "foo": "bar" }),
+		&[add("/child", json!({ "grandchild": {} }))],
+		json!({ "foo": "bar", "child": { "grandchild": {} } }),
+	)
+}
+
+#[test]
+fn a12_add_to_nonexistent_target() {
+	assert_eq!(
+		check_error(json!({ "foo": "bar" }), &[add("/baz/bat", json!("qux"))]),
+		PatchErrorKind::Pointer(PointerError::ParentNotFound("/baz".to_string()))
+	)
+}
+
+#[test]
+fn a14_escape_ordering() {
+	let document = json!({ "/": 9, "~1": 10 });
+	check(document.clone(), &[test("/~01", json!(10))], document)
+}
+
+#[test]
+fn a15_comparing_strings_and_numbers() {
+	assert_eq!(
+		check_error(json!({ "/": 9, "~1": 10 }), &[test("/~01", json!("10"))]),
+		PatchErrorKind::TestFailed("/~01".to_string())
+	)
+}
+
+#[test]
+fn a16_add_array_value() {
+	check(
+		json!({ "foo": ["bar"] }),
+		&[add("/foo/-", json!(["abc", "def"]))],
+		json!({ "foo": ["bar", ["abc", "def"]] }),
+	)
+}
+
+#[test]
+fn copy_and_replace_root() {
+	check(
+		json!({ "a": [1] }),
+		&[
+			PatchOp::Copy {
+				from: "/a".to_string(),
+				path: "/b".to_string(),
+			},
+			add("/b/-", json!(2)),
+		],
+		json!({ "a": [1], "b": [1, 2] }),
+	);
+	check(json!({ "a": 1 }), &[add("", json!([true]))], json!([true]));
+}
+
+#[test]
+fn failing_operation_keeps_previous_ones() {
+	let mut value = meta(json!({ "a": 1 }));
+	let err = apply_patch(
+		&mut value,
+		&[
+			add("/b", json!(2)),
+			test("/a", json!(2)),
+			add("/c", json!(3)),
+		],
+	)
+	.unwrap_err();
+
+	assert_eq!(err.operation(), 1);
+	assert!(json_eq(&value, &json!({ "a": 1, "b": 2 })));
+}
+
+#[test]
+fn other_errors() {
+	assert_eq!(
+		check_error(
+			json!({ "a": { "b": 1 } }),
+			&[PatchOp::Move {
+				from: "/a".to_string(),
+				path: "/a/b/c".to_string(),
+			}]
+		),
+		PatchErrorKind::MoveIntoChild("/a".to_string())
+	);
+	assert_eq!(
+		check_error(
+			json!({ "a": 1 }),
+			&[PatchOp::Remove {
+				path: "/b".to_string(),
+			}]
+		),
+		PatchErrorKind::NotFound("/b".to_string())
+	);
+}
@@ -0,0 +1,67 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use cc_traits::MapIter;
+use generic_json::{
+	merge_patch, merge_patch_with, to_backend, Json, KeyOrder, MergeOptions, MetaValue,
+};
+use serde_json::json;
+
+fn keys(value: &MetaValue) -> Vec<String> {
+	MapIter::iter(value.as_object().unwrap())
+		.map(|(key, _)| key.to_string())
+		.collect()
+}
+
+/// Object whose keys are not in insertion order.
+fn target() -> MetaValue {
+	let mut value: MetaValue = to_backend(&json!({ "b": 1 }));
+	merge_patch(&mut value, &to_backend(&json!({ "d": 2 })));
+	merge_patch(&mut value, &to_backend(&json!({ "a": 3 })));
+	value
+}
+
+#[test]
+fn appended_keys_land_at_the_end() {
+	let mut value = target();
+	assert_eq!(keys(&value), ["b", "d", "a"]);
+
+	merge_patch(&mut value, &to_backend(&json!({ "c": 4, "d": 5 })));
+	assert_eq!(keys(&value), ["b", "d", "a", "c"]);
+	assert_eq!(value["d"].as_u64(), Some(5));
+}
+
+#[test]
+fn sorted_keys() {
+	let options = MergeOptions {
+		key_order: KeyOrder::Sorted,
+	};
+
+	let mut value: MetaValue = to_backend(&json!({ "b": 1, "d": 2 }));
+	merge_patch_with(
+		&mut value,
+		&to_backend(&json!({ "a": 0, "c": 3, "e": 4 })),
+		&options,
+	);
+	assert_eq!(keys(&value), ["a", "b", "c", "d", "e"]);
+
+	// Unsorted objects keep their existing order.
+	let mut value = target();
+	merge_patch_with(&mut value, &to_backend(&json!({ "c": 4 })), &options);
+	assert_eq!(keys(&value), ["b", "c", "d", "a"]);
+}
+
+#[test]
+fn nested_objects() {
+	let mut value: MetaValue = to_backend(&json!({ "o": { "y": 1 } }));
+	merge_patch(&mut value, &to_backend(&json!({ "o": { "x": 2 } })));
+	assert_eq!(keys(&value["o"]), ["y", "x"]);
+
+	let options = MergeOptions {
+		key_order: KeyOrder::Sorted,
+	};
+	merge_patch_with(
+		&mut value,
+		&to_backend(&json!({ "o": { "w": 3 } })),
+		&options,
+	);
+	assert_eq!(keys(&value["o"]), ["w", "y", "x"]);
+}
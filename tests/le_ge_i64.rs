@@ -0,0 +1,49 @@
+use generic_json::{NumberBuf, NumberFrom};
+
+fn check<N: NumberFrom>() {
+	// `i64::MAX` and `i64::MAX - 1` are the same `f64`.
+	let max = N::from_i64(i64::MAX);
+	assert!(!max.le_i64(i64::MAX - 1));
+	assert!(max.le_i64(i64::MAX));
+	assert!(max.ge_i64(i64::MAX));
+
+	let n = N::from_i64(i64::MAX - 1);
+	assert!(!n.ge_i64(i64::MAX));
+	assert!(n.le_i64(i64::MAX));
+
+	// `2^53 + 1` rounds down to `2^53` as an `f64`.
+	let n = N::from_u64((1 << 53) + 1);
+	assert!(!n.le_i64(1 << 53));
+	assert!(n.ge_i64((1 << 53) + 1));
+
+	let min = N::from_i64(i64::MIN + 1);
+	assert!(!min.le_i64(i64::MIN));
+	assert!(min.ge_i64(i64::MIN));
+
+	// Integers larger than any `i64`.
+	assert!(N::from_u64(u64::MAX).ge_i64(i64::MAX));
+	assert!(!N::from_u64(u64::MAX).le_i64(i64::MAX));
+
+	// Non-integers are compared as floats.
+	let half = N::from_f64(-0.5).unwrap();
+	assert!(half.le_i64(0));
+	assert!(half.ge_i64(-1));
+	assert!(!half.ge_i64(0));
+}
+
+#[test]
+fn number_buf() {
+	check::<NumberBuf>();
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json_number() {
+	check::<serde_json::Number>();
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn ijson_number() {
+	check::<ijson::INumber>();
+}
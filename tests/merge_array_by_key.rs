@@ -0,0 +1,72 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::merge_array_by_key;
+use serde_json::json;
+
+#[test]
+fn upsert() {
+	let mut target = json!([
+		{ "id": 1, "name": "a", "tags": ["x"] },
+		{ "id": 2, "name": "b" },
+		{ "id": "3", "name": "c" }
+	]);
+
+	merge_array_by_key(
+		&mut target,
+		&json!([
+			{ "id": 2, "name": "B", "extra": true },
+			{ "id": 4, "name": "d" },
+			{ "id": 1, "tags": null }
+		]),
+		"id",
+	);
+
+	assert_eq!(
+		target,
+		json!([
+			{ "id": 1, "name": "a" },
+			{ "id": 2, "name": "B", "extra": true },
+			{ "id": "3", "name": "c" },
+			{ "id": 4, "name": "d" }
+		])
+	)
+}
+
+#[test]
+fn ids_must_have_the_same_type() {
+	let mut target = json!([{ "id": "1", "v": 0 }, { "id": 1, "v": 0 }]);
+	merge_array_by_key(&mut target, &json!([{ "id": 1, "v": 1 }]), "id");
+	assert_eq!(target, json!([{ "id": "1", "v": 0 }, { "id": 1, "v": 1 }]))
+}
+
+#[test]
+fn append_elements_lacking_the_key() {
+	let mut target = json!([{ "name": "a" }, { "id": 1 }, 5]);
+	merge_array_by_key(
+		&mut target,
+		&json!([{ "name": "a" }, 5, { "id": 1, "name": "b" }]),
+		"id",
+	);
+
+	assert_eq!(
+		target,
+		json!([{ "name": "a" }, { "id": 1, "name": "b" }, 5, { "name": "a" }, 5])
+	)
+}
+
+#[test]
+fn first_match_only() {
+	let mut target = json!([{ "id": 1, "v": 0 }, { "id": 1, "v": 0 }]);
+	merge_array_by_key(&mut target, &json!([{ "id": 1, "v": 1 }]), "id");
+	assert_eq!(target, json!([{ "id": 1, "v": 1 }, { "id": 1, "v": 0 }]))
+}
+
+#[test]
+fn non_arrays() {
+	let mut target = json!({ "id": 1 });
+	merge_array_by_key(&mut target, &json!([{ "id": 1, "v": 1 }]), "id");
+	assert_eq!(target, json!({ "id": 1 }));
+
+	let mut target = json!([{ "id": 1 }]);
+	merge_array_by_key(&mut target, &json!({ "id": 1, "v": 1 }), "id");
+	assert_eq!(target, json!([{ "id": 1 }]))
+}
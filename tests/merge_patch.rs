@@ -0,0 +1,99 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::merge_patch;
+use serde_json::{json, Value};
+
+fn check(target: Value, patch: Value, expected: Value) {
+	let mut result = target.clone();
+	merge_patch(&mut result, &patch);
+	assert_eq!(result, expected, "{} + {}", target, patch)
+}
+
+/// Test cases of RFC 7386, appendix A.
+#[test]
+fn rfc_7386_examples() {
+	check(json!({"a":"b"}), json!({"a":"c"}), json!({"a":"c"}));
+	check(json!({"a":"b"}), json!({"b":"c"}), json!({"a":"b","b":"c"}));
+	check(json!({"a":"b"}), json!({"a":null}), json!({}));
+	check(
+		json!({"a":"b","b":"c"}),
+		json!({"a":null}),
+		json!({"b":"c"}),
+	);
+	check(json!({"a":["b"]}), json!({"a":"c"}), json!({"a":"c"}));
+	check(json!({"a":"c"}), json!({"a":["b"]}), json!({"a":["b"]}));
+	check(
+		json!({"a":{"b":"c"}}),
+		json!({"a":{"b":"d","c":null}}),
+		json!({"a":{"b":"d"}}),
+	);
+	check(json!({"a":[{"b":"c"}]}), json!({"a":[1]}), json!({"a":[1]}));
+	check(json!(["a", "b"]), json!(["c", "d"]), json!(["c", "d"]));
+	check(json!({"a":"b"}), json!(["c"]), json!(["c"]));
+	check(json!({"a":"foo"}), json!(null), json!(null));
+	check(json!({"a":"foo"}), json!("bar"), json!("bar"));
+	check(json!({"e":null}), json!({"a":1}), json!({"e":null,"a":1}));
+	check(json!([1, 2]), json!({"a":"b","c":null}), json!({"a":"b"}));
+	check(
+		json!({}),
+		json!({"a":{"bb":{"ccc":null}}}),
+		json!({"a":{"bb":{}}}),
+	);
+}
+
+#[test]
+fn scalar_target_with_object_patch() {
+	check(json!(1), json!({"a":{"b":null}}), json!({"a":{}}));
+	check(json!("x"), json!({}), json!({}));
+}
+
+/// Converts the given value into a `MetaValue` with the given metadata everywhere.
+fn meta(value: &Value, m: u32) -> generic_json::MetaValue<u32> {
+	use generic_json::{JsonNew, MetaValue, NumberBuf};
+
+	match value {
+		Value::Null => MetaValue::null(m),
+		Value::Bool(b) => MetaValue::boolean(*b, m),
+		Value::Number(n) => MetaValue::number(NumberBuf::new(n.to_string()).unwrap(), m),
+		Value::String(s) => MetaValue::string(s.clone(), m),
+		Value::Array(a) => MetaValue::array(a.iter().map(|item| meta(item, m)).collect(), m),
+		Value::Object(o) => MetaValue::object(
+			o.iter()
+				.map(|(key, item)| (MetaValue::new_key(key, m), meta(item, m)))
+				.collect(),
+			m,
+		),
+	}
+}
+
+#[test]
+fn metadata() {
+	use generic_json::Json;
+
+	let mut target = meta(
+		&json!({
+			"kept": [1],
+			"merged": { "a": 1 },
+			"replaced": 2
+		}),
+		1,
+	);
+	let patch = meta(
+		&json!({
+			"added": true,
+			"merged": { "b": 2 },
+			"replaced": 3
+		}),
+		2,
+	);
+
+	merge_patch(&mut target, &patch);
+	assert_eq!(target.metadata(), &1);
+	assert_eq!(target["kept"].metadata(), &1);
+	assert_eq!(target["kept"][0].metadata(), &1);
+	assert_eq!(target["merged"].metadata(), &1);
+	assert_eq!(target["merged"]["a"].metadata(), &1);
+	assert_eq!(target["merged"]["b"].metadata(), &2);
+	assert_eq!(target["replaced"].metadata(), &2);
+	assert_eq!(target["replaced"].as_u64(), Some(3));
+	assert_eq!(target["added"].metadata(), &2);
+}
@@ -0,0 +1,98 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{merge_patch, Json, Value};
+use serde_json::json;
+
+/// Checks that the patch from `source` to `target` is `expected`,
+/// and that applying it to `source` yields `target`.
+fn round_trip(source: serde_json::Value, target: serde_json::Value, expected: serde_json::Value) {
+	let patch: Value<serde_json::Value> = source.merge_patch_diff(&target);
+	let patch = patch.with_default();
+	assert_eq!(patch, expected);
+
+	let mut patched = source;
+	merge_patch(&mut patched, &patch);
+	assert_eq!(patched, target);
+}
+
+#[test]
+fn object_changes() {
+	round_trip(
+		json!({ "a": "b", "c": { "d": "e", "f": "g" }, "k": 1 }),
+		json!({ "a": "z", "c": { "d": "e" }, "i": { "j": 1.5 }, "k": 1 }),
+		json!({ "a": "z", "c": { "f": null }, "i": { "j": 1.5 } }),
+	);
+}
+
+#[test]
+fn nested_objects() {
+	round_trip(
+		json!({ "a": { "b": { "c": 1, "d": 2 } } }),
+		json!({ "a": { "b": { "c": 1, "d": 3, "e": [] } } }),
+		json!({ "a": { "b": { "d": 3, "e": [] } } }),
+	);
+}
+
+#[test]
+fn arrays_are_replaced() {
+	round_trip(
+		json!({ "a": [1, 2, { "b": 1 }] }),
+		json!({ "a": [1, 2, { "b": 2 }] }),
+		json!({ "a": [1, 2, { "b": 2 }] }),
+	);
+	round_trip(json!([1]), json!([1, 2]), json!([1, 2]));
+}
+
+#[test]
+fn kind_changes() {
+	round_trip(json!({ "a": 1 }), json!([1]), json!([1]));
+	round_trip(json!("x"), json!({ "a": 1 }), json!({ "a": 1 }));
+	round_trip(
+		json!({ "a": { "b": 1 } }),
+		json!({ "a": 1 }),
+		json!({ "a": 1 }),
+	);
+	round_trip(
+		json!({ "a": 1 }),
+		json!({ "a": { "b": 1 } }),
+		json!({ "a": { "b": 1 } }),
+	);
+}
+
+#[test]
+fn unchanged() {
+	round_trip(
+		json!({ "a": [1], "b": { "c": 1 } }),
+		json!({ "b": { "c": 1 }, "a": [1] }),
+		json!({}),
+	);
+
+	// Members are compared by value.
+	let patch: Value<serde_json::Value> = json!({ "a": [1], "b": { "c": 1 } })
+		.merge_patch_diff(&json!({ "b": { "c": 1.0 }, "a": [1.0] }));
+	assert_eq!(patch.with_default(), json!({}));
+}
+
+#[test]
+fn across_backends() {
+	use generic_json::{JsonNew, MetaValue, NumberBuf};
+
+	// `{ "a": 1, "b": "x" }`
+	let source: MetaValue<u8> = MetaValue::object(
+		vec![
+			(
+				generic_json::MetaKey::new("a".to_string(), 1),
+				MetaValue::number(NumberBuf::from(1u32), 2),
+			),
+			(
+				generic_json::MetaKey::new("b".to_string(), 3),
+				MetaValue::string("x".into(), 4),
+			),
+		]
+		.into_iter()
+		.collect(),
+		0,
+	);
+
+	let patch: Value<serde_json::Value> = source.merge_patch_diff(&json!({ "a": 2, "b": "x" }));
+	assert_eq!(patch.with_default(), json!({ "a": 2 }));
+}
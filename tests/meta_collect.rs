@@ -0,0 +1,62 @@
+use cc_traits::{Iter, Len, MapIter};
+use generic_json::{Json, JsonNew, Key, MetaValue, NumberBuf};
+
+fn number(n: u32) -> MetaValue<u8> {
+	MetaValue::number(NumberBuf::from(n), 1)
+}
+
+#[test]
+fn collect_array() {
+	let value: MetaValue<u8> = (0..3).map(number).collect();
+	assert_eq!(value.metadata(), &0);
+
+	let a = value.as_array().unwrap();
+	assert_eq!(a.len(), 3);
+	assert_eq!(value[2].as_u64(), Some(2));
+	assert!(Iter::iter(a).all(|item| item.metadata() == &1));
+}
+
+#[test]
+fn collect_object() {
+	let value: MetaValue<u8> = vec![
+		("b".to_string(), number(0)),
+		("a".to_string(), number(1)),
+		("b".to_string(), number(2)),
+	]
+	.into_iter()
+	.collect();
+	assert_eq!(value.metadata(), &0);
+
+	let members: Vec<_> = MapIter::iter(value.as_object().unwrap())
+		.map(|(key, item)| (key.to_string(), *key.metadata(), item.as_u64().unwrap()))
+		.collect();
+	assert_eq!(members, [("b".to_string(), 0, 2), ("a".to_string(), 0, 1)]);
+}
+
+#[test]
+fn extend_array() {
+	let mut value: MetaValue<u8> = MetaValue::array(vec![number(0)], 3);
+	value.extend(vec![number(1), number(2)]);
+	assert_eq!(value.metadata(), &3);
+	assert_eq!(value.as_array().unwrap().len(), 3);
+	assert_eq!(value[1].as_u64(), Some(1));
+}
+
+#[test]
+fn extend_object() {
+	let mut value: MetaValue<u8> = vec![("a".to_string(), number(0))].into_iter().collect();
+	value.extend(vec![
+		("b".to_string(), number(1)),
+		("a".to_string(), number(2)),
+	]);
+	assert_eq!(value.as_object().unwrap().len(), 2);
+	assert_eq!(value["a"].as_u64(), Some(2));
+	assert_eq!(value["b"].as_u64(), Some(1));
+}
+
+#[test]
+#[should_panic]
+fn extend_non_array() {
+	let mut value: MetaValue = MetaValue::null(());
+	value.extend(vec![MetaValue::null(())]);
+}
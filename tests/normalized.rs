@@ -0,0 +1,41 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::Json;
+use serde_json::json;
+use std::borrow::Cow;
+
+#[test]
+fn strip_nulls() {
+	let mut value = json!({ "a": null, "b": [null, { "c": null, "d": 1 }] });
+	assert!(value.strip_nulls());
+	assert_eq!(value, json!({ "b": [null, { "d": 1 }] }));
+	assert!(!value.strip_nulls());
+}
+
+#[test]
+fn normalize_integral_floats() {
+	let mut value = json!([2.0, 1e3, -4.0, 0.5, 3, { "a": 1.0 }]);
+	assert!(value.normalize_integral_floats());
+	assert_eq!(value, json!([2, 1000, -4, 0.5, 3, { "a": 1 }]));
+	assert!(value[0].is_u64());
+	assert!(!value.normalize_integral_floats());
+}
+
+#[test]
+fn borrow_unchanged() {
+	let value = json!({ "a": [null, 1, 0.5], "b": "x" });
+	match value.normalized() {
+		Cow::Borrowed(v) => assert!(std::ptr::eq(v, &value)),
+		Cow::Owned(_) => panic!("unchanged value has been cloned"),
+	}
+}
+
+#[test]
+fn own_changed() {
+	let value = json!({ "a": null, "b": [2.0] });
+	match value.normalized() {
+		Cow::Owned(v) => assert_eq!(v, json!({ "b": [2] })),
+		Cow::Borrowed(_) => panic!("changed value has been borrowed"),
+	}
+
+	assert_eq!(value, json!({ "a": null, "b": [2.0] }));
+}
@@ -0,0 +1,50 @@
+use generic_json::{BorrowedValue, Json, Number, NumberBuf, NumberFrom};
+
+const BIG: &str = "1234567890123456789012345678901234567890";
+
+#[test]
+fn default_is_none() {
+	assert_eq!(generic_json::number::Zero.as_str(), None);
+}
+
+#[test]
+fn number_buf() {
+	let n = NumberBuf::from_lexical(BIG).unwrap();
+	assert_eq!(Number::as_str(&n), Some(BIG));
+	assert_eq!(Number::as_str(&NumberBuf::from_u64(7)), Some("7"));
+}
+
+#[test]
+fn borrowed_value() {
+	let document = format!("[{}, -{}]", BIG, BIG);
+	let value = BorrowedValue::parse(&document).unwrap();
+	let numbers: Vec<_> = cc_traits::Iter::iter(value.as_array().unwrap())
+		.map(|item| {
+			Number::as_str(item.as_number().unwrap())
+				.unwrap()
+				.to_string()
+		})
+		.collect();
+	assert_eq!(numbers, [BIG.to_string(), format!("-{}", BIG)]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn parse_into_meta_value() {
+	let (value, rest): (generic_json::MetaValue, _) = generic_json::parse_prefix(BIG).unwrap();
+	assert!(rest.is_empty());
+	assert_eq!(Number::as_str(value.as_number().unwrap()), Some(BIG));
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json_number() {
+	let value: serde_json::Value = serde_json::from_str(BIG).unwrap();
+	let n = value.as_number().unwrap();
+
+	#[cfg(feature = "arbitrary_precision")]
+	assert_eq!(Number::as_str(n), Some(BIG));
+
+	#[cfg(not(feature = "arbitrary_precision"))]
+	assert_eq!(Number::as_str(n), None);
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::F64;
+use generic_json::{number::Zero, Number, NumberBuf, NumberFrom};
+
+fn check<N: NumberFrom>() {
+	for n in [
+		N::from_u64(0),
+		N::from_u64(42),
+		N::from_i64(-7),
+		N::from_u64(u64::MAX),
+	] {
+		assert!(n.is_integer());
+		assert!(n.is_finite());
+	}
+
+	assert!(N::from_i64(-7).is_negative());
+	assert!(N::from_i64(i64::MIN).is_negative());
+	assert!(!N::from_u64(0).is_negative());
+	assert!(!N::from_u64(42).is_negative());
+
+	let f = N::from_f64(-0.5).unwrap();
+	assert!(!f.is_integer());
+	assert!(f.is_negative());
+	assert!(f.is_finite());
+	assert!(!N::from_f64(0.5).unwrap().is_negative());
+	assert!(!N::from_f64(-0.0).unwrap().is_negative());
+}
+
+#[test]
+fn zero() {
+	assert!(Zero.is_integer());
+	assert!(!Zero.is_negative());
+	assert!(Zero.is_finite());
+}
+
+#[test]
+fn number_buf() {
+	check::<NumberBuf>();
+
+	let n = |s: &str| NumberBuf::new(s.to_string()).unwrap();
+	assert!(n("123456789012345678901234567890").is_integer());
+	assert!(n("-123456789012345678901234567890").is_negative());
+	assert!(!n("1e2").is_integer());
+	assert!(!n("-0e5").is_negative());
+	assert!(n("-1e-400").is_negative());
+}
+
+#[test]
+fn non_finite() {
+	assert!(F64(-7.0).is_integer());
+	assert!(F64(-7.0).is_negative());
+	assert!(!F64(0.5).is_integer());
+
+	assert!(!F64(f64::NAN).is_integer());
+	assert!(!F64(f64::NAN).is_negative());
+	assert!(!F64(f64::NAN).is_finite());
+	assert!(!F64(f64::INFINITY).is_finite());
+	assert!(!F64(f64::INFINITY).is_negative());
+	assert!(!F64(f64::NEG_INFINITY).is_finite());
+	assert!(F64(f64::NEG_INFINITY).is_negative());
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json_number() {
+	check::<serde_json::Number>();
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn ijson_number() {
+	check::<ijson::INumber>();
+}
@@ -0,0 +1,50 @@
+use generic_json::{NumberBuf, NumberFrom};
+
+#[allow(clippy::approx_constant)]
+fn check<N: NumberFrom + std::fmt::Debug>() {
+	assert_eq!(N::from_u64(42).as_u64(), Some(42));
+	assert_eq!(N::from_i64(-7).as_i64(), Some(-7));
+	assert_eq!(N::from_i64(-7).as_u64(), None);
+	assert_eq!(N::from_f64(3.14).unwrap().as_f64(), Some(3.14));
+	assert_eq!(N::from_f64(0.1).unwrap().as_f64(), Some(0.1));
+	assert!(N::from_f64(f64::NAN).is_none());
+	assert!(N::from_f64(f64::INFINITY).is_none());
+
+	assert_eq!(N::from_lexical("42"), Some(N::from_u64(42)));
+	assert_eq!(N::from_lexical("-7"), Some(N::from_i64(-7)));
+	assert_eq!(N::from_lexical("0.1"), N::from_f64(0.1));
+	assert_eq!(N::from_lexical("3.14"), N::from_f64(3.14));
+}
+
+#[test]
+fn number_buf() {
+	check::<NumberBuf>();
+
+	let big = "123456789012345678901234567890";
+	assert_eq!(NumberBuf::from_lexical(big).unwrap().as_str(), big);
+	assert_eq!(NumberBuf::from_lexical("1e400").unwrap().as_str(), "1e400");
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json_number() {
+	check::<serde_json::Number>();
+
+	#[cfg(not(feature = "arbitrary_precision"))]
+	assert!(serde_json::Number::from_lexical("1e400").is_none());
+}
+
+#[cfg(feature = "arbitrary_precision")]
+#[test]
+fn serde_json_number_exact() {
+	let big = "123456789012345678901234567890";
+	let n = serde_json::Number::from_lexical(big).unwrap();
+	assert_eq!(generic_json::Number::as_str(&n), Some(big));
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn ijson_number() {
+	check::<ijson::INumber>();
+	assert!(ijson::INumber::from_lexical("1e400").is_none());
+}
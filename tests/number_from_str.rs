@@ -0,0 +1,65 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{number_from_str, JsonNew, Number, NumberBuf, Value};
+
+fn parse(s: &str) -> Option<serde_json::Value> {
+	number_from_str(s).map(|value| serde_json::Value::new(value, ()))
+}
+
+#[test]
+fn integers() {
+	assert_eq!(parse("0").unwrap().as_u64(), Some(0));
+	assert_eq!(parse("42").unwrap().as_u64(), Some(42));
+	assert_eq!(
+		parse("18446744073709551615").unwrap().as_u64(),
+		Some(u64::MAX)
+	);
+}
+
+#[test]
+fn negatives() {
+	assert_eq!(parse("-1").unwrap().as_i64(), Some(-1));
+	assert_eq!(
+		parse("-9223372036854775808").unwrap().as_i64(),
+		Some(i64::MIN)
+	);
+	assert_eq!(parse("-0.5").unwrap().as_f64(), Some(-0.5));
+}
+
+#[test]
+fn exponents() {
+	assert_eq!(parse("1e3").unwrap().as_f64(), Some(1000.0));
+	assert_eq!(parse("2.5E-2").unwrap().as_f64(), Some(0.025));
+	assert_eq!(parse("-1e+2").unwrap().as_f64(), Some(-100.0));
+}
+
+#[test]
+fn invalid() {
+	for s in [
+		"", "-", "+1", "01", "1.", ".5", "1e", "1e+", "0x10", "NaN", "Infinity", " 1", "1 ",
+	]
+	.iter()
+	{
+		assert!(parse(s).is_none(), "{:?} should be rejected", s)
+	}
+}
+
+#[test]
+fn lossless_with_lexical_backend() {
+	let n: Value<generic_json::MetaValue<()>> =
+		number_from_str("123456789012345678901234567890.5").unwrap();
+	match n {
+		Value::Number(n) => {
+			let n: NumberBuf = n;
+			assert_eq!(n.as_str(), "123456789012345678901234567890.5")
+		}
+		_ => panic!("expected a number"),
+	}
+}
+
+#[test]
+fn trailing_zero() {
+	match number_from_str::<serde_json::Value>("1.50").unwrap() {
+		Value::Number(n) => assert_eq!(n.as_f64_lossy(), 1.5),
+		_ => panic!("expected a number"),
+	}
+}
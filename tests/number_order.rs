@@ -0,0 +1,106 @@
+use generic_json::{Json, NumberBuf, Value, ValueMut, ValueRef};
+use std::{cmp::Ordering, collections::BTreeMap};
+
+fn n(s: &str) -> NumberBuf {
+	NumberBuf::new(s.to_string()).unwrap()
+}
+
+#[test]
+fn numbers_are_ordered_by_value() {
+	assert!(n("9") < n("10"));
+	assert!(n("-2") < n("-1"));
+	assert!(n("-10") < n("-9"));
+	assert!(n("1.5") < n("10"));
+	assert!(n("1e2") > n("99"));
+	assert!(n("-0.5") < n("0"));
+
+	let mut numbers: Vec<_> = ["10", "-1", "9", "1e1", "-2", "0.5", "100"]
+		.iter()
+		.map(|s| n(s))
+		.collect();
+	numbers.sort();
+	let sorted: Vec<_> = numbers.iter().map(NumberBuf::as_str).collect();
+	assert_eq!(sorted, ["-2", "-1", "0.5", "9", "10", "1e1", "100"]);
+}
+
+#[test]
+fn ordering_is_consistent_with_equality() {
+	// Equal values with different lexical representations are not equal.
+	assert_ne!(n("1"), n("1.0"));
+	assert_ne!(n("1").cmp(&n("1.0")), Ordering::Equal);
+	assert_ne!(n("0").cmp(&n("-0")), Ordering::Equal);
+	assert_eq!(n("1.0").cmp(&n("1.0")), Ordering::Equal);
+}
+
+/// Minimal backend storing its numbers as `NumberBuf`,
+/// whose values can be ordered.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum Ordered {
+	Number(NumberBuf),
+	Array(Vec<Ordered>),
+}
+
+impl Json for Ordered {
+	type MetaData = ();
+	type Number = NumberBuf;
+	type String = String;
+	type Array = Vec<Self>;
+	type Key = String;
+	type Object = BTreeMap<String, Self>;
+
+	fn as_value_ref(&self) -> ValueRef<'_, Self> {
+		match self {
+			Self::Number(n) => ValueRef::Number(n),
+			Self::Array(a) => ValueRef::Array(a),
+		}
+	}
+
+	fn as_value_mut(&mut self) -> ValueMut<'_, Self> {
+		match self {
+			Self::Number(n) => ValueMut::Number(n),
+			Self::Array(a) => ValueMut::Array(a),
+		}
+	}
+
+	fn into_parts(self) -> (Value<Self>, ()) {
+		match self {
+			Self::Number(n) => (Value::Number(n), ()),
+			Self::Array(a) => (Value::Array(a), ()),
+		}
+	}
+
+	fn metadata(&self) -> &() {
+		&()
+	}
+
+	fn as_pair_mut(&mut self) -> (ValueMut<'_, Self>, &()) {
+		(self.as_value_mut(), &())
+	}
+}
+
+#[test]
+fn value_numbers_are_ordered_by_value() {
+	let nine: Value<Ordered> = Value::Number(n("9"));
+	let ten: Value<Ordered> = Value::Number(n("10"));
+	assert!(nine < ten);
+	assert_eq!(nine.partial_cmp(&ten), Some(Ordering::Less));
+
+	let minus_nine: Value<Ordered> = Value::Number(n("-9"));
+	let minus_ten: Value<Ordered> = Value::Number(n("-10"));
+	assert!(minus_nine > minus_ten);
+}
+
+#[test]
+fn value_arrays_are_ordered_by_value() {
+	let array = |items: &[&str]| -> Value<Ordered> {
+		Value::Array(items.iter().map(|s| Ordered::Number(n(s))).collect())
+	};
+
+	assert!(array(&["9"]) < array(&["10"]));
+	assert!(array(&["1", "9"]) < array(&["1", "10"]));
+
+	let nested = |s: &str| -> Value<Ordered> {
+		Value::Array(vec![Ordered::Array(vec![Ordered::Number(n(s))])])
+	};
+	assert!(nested("9") < nested("10"));
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::F64;
+use generic_json::{Number, NumberBuf};
+
+fn buf(lexical: &str) -> NumberBuf {
+	NumberBuf::new(lexical.to_string()).unwrap()
+}
+
+#[test]
+fn lexical_forms() {
+	assert!(buf("1").numeric_eq(&buf("1.0")));
+	assert!(buf("100").numeric_eq(&buf("1e2")));
+	assert!(buf("-0").numeric_eq(&buf("0.0")));
+	assert!(!buf("1").numeric_eq(&buf("1.5")));
+	assert!(buf("1").numeric_eq(&F64(1.0)));
+}
+
+#[test]
+fn large_integers_are_exact() {
+	// Both are the same `f64`.
+	assert!(!buf("9223372036854775807").numeric_eq(&buf("9223372036854775806")));
+	assert!(!buf("18446744073709551615").numeric_eq(&buf("-1")));
+	assert!(buf("18446744073709551615").numeric_eq(&buf("18446744073709551615")));
+}
+
+#[test]
+fn non_finite() {
+	assert!(!F64(f64::NAN).numeric_eq(&F64(f64::NAN)));
+	assert!(F64(f64::INFINITY).numeric_eq(&F64(f64::INFINITY)));
+	assert!(!F64(f64::INFINITY).numeric_eq(&buf("1")));
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json_numbers() {
+	let one = serde_json::Number::from(1);
+	let float = serde_json::Number::from_f64(1.0).unwrap();
+	assert_ne!(one, float);
+	assert!(one.numeric_eq(&float));
+	assert!(one.numeric_eq(&buf("1.00")));
+	assert!(!one.numeric_eq(&serde_json::Number::from_f64(1.5).unwrap()));
+}
+
+#[cfg(all(feature = "serde_json-impl", feature = "ijson-impl"))]
+#[test]
+fn serde_json_integer_and_ijson_float() {
+	use std::convert::TryFrom;
+
+	let a = serde_json::Number::from(3);
+	let b = ijson::INumber::try_from(3.0).unwrap();
+	assert!(a.numeric_eq(&b));
+	assert!(b.numeric_eq(&a));
+
+	let b = ijson::INumber::try_from(3.5).unwrap();
+	assert!(b.has_decimal_point());
+	assert!(!a.numeric_eq(&b));
+	assert!(!b.numeric_eq(&a));
+	assert!(b.numeric_eq(&serde_json::Number::from_f64(3.5).unwrap()));
+
+	let a = serde_json::Number::from(u64::MAX);
+	assert!(!a.numeric_eq(&ijson::INumber::from(i64::MAX)));
+	assert!(a.numeric_eq(&ijson::INumber::from(u64::MAX)));
+}
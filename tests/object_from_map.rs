@@ -0,0 +1,40 @@
+#![cfg(feature = "nightly")]
+use generic_json::{JsonBuild, MetaValue};
+use std::collections::HashMap;
+
+fn check<T: JsonBuild + std::fmt::Debug>()
+where
+	T::MetaData: Default,
+	i32: Into<generic_json::Value<T>>,
+{
+	let map: HashMap<&str, i32> = vec![("a", 1), ("b", -2)].into_iter().collect();
+	let value = T::object_from_map(map);
+
+	let o = value.as_object().unwrap();
+	assert_eq!(cc_traits::Len::len(o), 2);
+	assert_eq!(cc_traits::Get::get(o, "a").unwrap().as_i64(), Some(1));
+	assert_eq!(cc_traits::Get::get(o, "b").unwrap().as_i64(), Some(-2));
+
+	assert!(T::object_from_map(HashMap::<String, i32>::new()).is_empty_object());
+}
+
+#[test]
+fn meta_value() {
+	check::<MetaValue>();
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json() {
+	check::<serde_json::Value>();
+
+	let value =
+		serde_json::Value::object_from_map(vec![("a".to_string(), 1), ("a".to_string(), 2)]);
+	assert_eq!(value, serde_json::json!({ "a": 2 }));
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn ijson() {
+	check::<ijson::IValue>();
+}
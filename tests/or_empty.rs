@@ -0,0 +1,39 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{Json, ValueRef};
+use serde_json::json;
+
+#[test]
+fn scalars_yield_nothing() {
+	for value in &[json!(null), json!(true), json!(1), json!("a")] {
+		assert_eq!(value.as_array_or_empty().count(), 0);
+		assert_eq!(value.as_object_or_empty().count(), 0);
+	}
+}
+
+#[test]
+fn array_elements() {
+	let value = json!([1, "a", [2]]);
+	let items: Vec<_> = value.as_array_or_empty().collect();
+	assert_eq!(items.len(), 3);
+	assert!(matches!(items[0], ValueRef::Number(n) if generic_json::Number::as_u64(n) == Some(1)));
+	assert!(matches!(items[1], ValueRef::String(s) if s == "a"));
+	assert!(matches!(items[2], ValueRef::Array(_)));
+	assert_eq!(value.as_object_or_empty().count(), 0);
+}
+
+#[test]
+fn object_members() {
+	let value = json!({ "a": 1, "b": { "c": null } });
+	let members: Vec<_> = value
+		.as_object_or_empty()
+		.map(|(key, item)| (key, item.kind()))
+		.collect();
+	assert_eq!(
+		members,
+		[
+			("a", generic_json::ValueKind::Number),
+			("b", generic_json::ValueKind::Object)
+		]
+	);
+	assert_eq!(value.as_array_or_empty().count(), 0);
+}
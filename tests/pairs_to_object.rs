@@ -0,0 +1,62 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+mod common;
+
+use common::TestValue;
+use generic_json::{Json, PairsError};
+use serde_json::{json, Value};
+
+#[test]
+fn valid_pairs() {
+	let pairs = json!([["a", 1], ["b", [true, null]], ["c", { "d": "x" }]]);
+	assert_eq!(
+		pairs.pairs_to_object::<Value>().unwrap(),
+		json!({ "a": 1, "b": [true, null], "c": { "d": "x" } })
+	);
+	assert_eq!(json!([]).pairs_to_object::<Value>().unwrap(), json!({}));
+}
+
+#[test]
+fn across_backends() {
+	let pairs = json!([["n", 12345678901234567890u64], ["f", 0.5]]);
+	let object: TestValue = pairs.pairs_to_object().unwrap();
+	assert_eq!(
+		object,
+		TestValue::object(vec![
+			("n", TestValue::number(12345678901234567890.0)),
+			("f", TestValue::number(0.5))
+		])
+	);
+}
+
+#[test]
+fn malformed_pairs() {
+	let to_object = |value: Value| value.pairs_to_object::<Value>();
+
+	assert_eq!(to_object(json!({ "a": 1 })), Err(PairsError::NotAnArray));
+	assert_eq!(
+		to_object(json!([["a", 1], ["b"]])),
+		Err(PairsError::NotAPair(1))
+	);
+	assert_eq!(
+		to_object(json!([["a", 1, 2]])),
+		Err(PairsError::NotAPair(0))
+	);
+	assert_eq!(to_object(json!([{ "a": 1 }])), Err(PairsError::NotAPair(0)));
+	assert_eq!(
+		to_object(json!([["a", 1], [2, 3]])),
+		Err(PairsError::NonStringKey(1))
+	);
+}
+
+#[test]
+fn unrepresentable_number() {
+	let pairs = TestValue::Array(vec![
+		TestValue::Array(vec![TestValue::string("a"), TestValue::number(1.0)]),
+		TestValue::Array(vec![TestValue::string("b"), TestValue::number(f64::NAN)]),
+	]);
+
+	assert_eq!(
+		pairs.pairs_to_object::<Value>(),
+		Err(PairsError::InvalidNumber(1))
+	);
+}
@@ -0,0 +1,48 @@
+use generic_json::{BorrowedValue, ParseError, ParseErrorKind, ParseOptions, TriviaValue};
+
+fn options(max_array_elements: usize, max_object_members: usize) -> ParseOptions {
+	ParseOptions {
+		max_array_elements,
+		max_object_members,
+		..Default::default()
+	}
+}
+
+/// Parses the input with both parsers, checking that they agree.
+fn parse(input: &str, options: ParseOptions) -> Result<(), ParseError> {
+	let borrowed = BorrowedValue::parse_with(input, options).map(|_| ());
+	let trivia = TriviaValue::parse_with(input, options).map(|_| ());
+	assert_eq!(borrowed, trivia);
+	borrowed
+}
+
+#[test]
+fn unlimited_by_default() {
+	let input = format!("[{}]", vec!["{}"; 10_000].join(","));
+	assert!(parse(&input, ParseOptions::default()).is_ok());
+}
+
+#[test]
+fn array_limit() {
+	assert!(parse("[1, 2]", options(2, 0)).is_ok());
+	assert!(parse("[]", options(0, 0)).is_ok());
+
+	let err = parse("[1, 2, 3]", options(2, 0)).unwrap_err();
+	assert_eq!(err.kind(), ParseErrorKind::ArrayLimitExceeded);
+	assert_eq!(err.position(), 7);
+
+	let err = parse(r#"{ "a": [[1], [2, 3]] }"#, options(1, 1)).unwrap_err();
+	assert_eq!(err.kind(), ParseErrorKind::ArrayLimitExceeded);
+	assert_eq!(err.position(), 13);
+}
+
+#[test]
+fn object_limit() {
+	assert!(parse(r#"{ "a": 1, "b": 2 }"#, options(0, 2)).is_ok());
+	assert!(parse("{}", options(0, 0)).is_ok());
+
+	let err = parse(r#"{ "a": 1, "b": 2, "c": 3 }"#, options(0, 2)).unwrap_err();
+	assert_eq!(err.kind(), ParseErrorKind::ObjectLimitExceeded);
+	assert_eq!(err.position(), 18);
+	assert_eq!(err.kind().to_string(), "object members limit exceeded");
+}
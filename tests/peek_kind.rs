@@ -0,0 +1,41 @@
+use generic_json::{peek_kind, ParseErrorKind, ValueKind};
+
+#[test]
+fn classify_leading_token() {
+	let cases = vec![
+		("null", ValueKind::Null),
+		("true", ValueKind::Boolean),
+		(" false", ValueKind::Boolean),
+		("-1", ValueKind::Number),
+		("0.5", ValueKind::Number),
+		("\"s\"", ValueKind::String),
+		("\n\t[1, 2]", ValueKind::Array),
+		("\r\n{ \"a\": 1 }", ValueKind::Object),
+	];
+
+	for (input, kind) in cases {
+		assert_eq!(peek_kind(input), Ok(kind), "{:?}", input)
+	}
+}
+
+#[test]
+fn rest_is_not_validated() {
+	assert_eq!(peek_kind("[1,"), Ok(ValueKind::Array));
+	assert_eq!(peek_kind("nope"), Ok(ValueKind::Null));
+}
+
+#[test]
+fn errors() {
+	let err = peek_kind("").unwrap_err();
+	assert_eq!(err.kind(), ParseErrorKind::UnexpectedEnd);
+	assert_eq!(err.position(), 0);
+
+	let err = peek_kind(" \n ").unwrap_err();
+	assert_eq!(err.kind(), ParseErrorKind::UnexpectedEnd);
+	assert_eq!(err.position(), 3);
+
+	let err = peek_kind("  é").unwrap_err();
+	assert_eq!(err.kind(), ParseErrorKind::UnexpectedChar('é'));
+	assert_eq!(err.position(), 2);
+	assert_eq!(peek_kind("\u{a0}1").unwrap_err().position(), 0);
+}
@@ -0,0 +1,46 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{Json, JsonNew};
+use serde_json::{json, Value};
+
+fn build(value: generic_json::Value<Value>) -> Value {
+	Value::new(value, ())
+}
+
+#[test]
+fn pick_two_keys() {
+	let value = json!({ "a": 1, "b": [2], "c": { "d": 3 } });
+	assert_eq!(
+		build(value.pick(&["a", "c"])),
+		json!({ "a": 1, "c": { "d": 3 } })
+	);
+	assert_eq!(build(value.pick(&["a", "z"])), json!({ "a": 1 }));
+	assert_eq!(build(value.pick(&[])), json!({}));
+}
+
+#[test]
+fn omit_one_key() {
+	let value = json!({ "a": 1, "b": [2], "c": { "d": 3 } });
+	assert_eq!(
+		build(value.omit(&["b"])),
+		json!({ "a": 1, "c": { "d": 3 } })
+	);
+	assert_eq!(build(value.omit(&["z"])), value);
+}
+
+#[test]
+fn only_top_level_members() {
+	let value = json!({ "a": { "a": 1, "b": 2 }, "b": 3 });
+	assert_eq!(
+		build(value.pick(&["a"])),
+		json!({ "a": { "a": 1, "b": 2 } })
+	);
+	assert_eq!(build(value.omit(&["a"])), json!({ "b": 3 }));
+}
+
+#[test]
+fn non_objects_are_copied() {
+	let value = json!([{ "a": 1 }, 2]);
+	assert_eq!(build(value.pick(&["a"])), value);
+	assert_eq!(build(value.omit(&["a"])), value);
+	assert_eq!(build(json!("a").pick(&["a"])), json!("a"));
+}
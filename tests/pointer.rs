@@ -0,0 +1,82 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{pointer, valueref_to_serde};
+use serde_json::{json, Value};
+
+/// Example document of RFC 6901, section 5.
+fn document() -> Value {
+	json!({
+		"foo": ["bar", "baz"],
+		"": 0,
+		"a/b": 1,
+		"c%d": 2,
+		"e^f": 3,
+		"g|h": 4,
+		"i\\j": 5,
+		"k\"l": 6,
+		" ": 7,
+		"m~n": 8
+	})
+}
+
+fn resolve(value: &Value, ptr: &str) -> Option<Value> {
+	pointer(value, ptr).map(|v| valueref_to_serde(v).unwrap())
+}
+
+#[test]
+fn rfc_6901_examples() {
+	let value = document();
+	let examples = vec![
+		("", value.clone()),
+		("/foo", json!(["bar", "baz"])),
+		("/foo/0", json!("bar")),
+		("/", json!(0)),
+		("/a~1b", json!(1)),
+		("/c%d", json!(2)),
+		("/e^f", json!(3)),
+		("/g|h", json!(4)),
+		("/i\\j", json!(5)),
+		("/k\"l", json!(6)),
+		("/ ", json!(7)),
+		("/m~0n", json!(8)),
+	];
+
+	for (ptr, expected) in examples {
+		assert_eq!(resolve(&value, ptr), Some(expected), "{}", ptr)
+	}
+}
+
+#[test]
+fn unresolved_pointers() {
+	let value = document();
+	for ptr in &[
+		"foo", "/foo/2", "/foo/01", "/foo/-", "/foo/+1", "/missing", "/foo/0/x", "/m~2n", "/m~",
+		"/a/b",
+	] {
+		assert_eq!(resolve(&value, ptr), None, "{}", ptr)
+	}
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn rfc_6901_examples_mut() {
+	use generic_json::{pointer_mut, ValueMut};
+
+	let mut value = document();
+	match pointer_mut(&mut value, "/foo/1") {
+		Some(ValueMut::String(s)) => s.push('!'),
+		_ => panic!("not a string"),
+	}
+	match pointer_mut(&mut value, "/m~0n") {
+		Some(ValueMut::Number(n)) => *n = 9.into(),
+		_ => panic!("not a number"),
+	}
+
+	assert_eq!(value["foo"], json!(["bar", "baz!"]));
+	assert_eq!(value["m~n"], json!(9));
+	assert!(pointer_mut(&mut value, "/foo/2").is_none());
+	assert!(pointer_mut(&mut value, "a~1b").is_none());
+	assert!(matches!(
+		pointer_mut(&mut value, ""),
+		Some(ValueMut::Object(_))
+	));
+}
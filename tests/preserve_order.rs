@@ -0,0 +1,56 @@
+#![cfg(feature = "preserve_order")]
+use generic_json::{JsonNew, Value};
+use serde_json::json;
+
+fn keys(value: &serde_json::Value) -> Vec<&str> {
+	value
+		.as_object()
+		.unwrap()
+		.keys()
+		.map(String::as_str)
+		.collect()
+}
+
+#[test]
+fn object_collects_in_iteration_order() {
+	let object = vec!["z", "a", "m"]
+		.into_iter()
+		.enumerate()
+		.map(|(i, key)| (serde_json::Value::new_key(key, ()), json!(i)))
+		.collect();
+
+	let value = serde_json::Value::object(object, ());
+	assert_eq!(keys(&value), ["z", "a", "m"]);
+}
+
+#[test]
+fn new_value_keeps_insertion_order() {
+	let object = vec![("z", 0), ("a", 1), ("m", 2)]
+		.into_iter()
+		.map(|(key, i)| (key.to_string(), json!(i)))
+		.collect();
+
+	let value = serde_json::Value::new(Value::Object(object), ());
+	assert_eq!(keys(&value), ["z", "a", "m"]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn backend_conversion_keeps_order() {
+	let value = json!({ "z": [{ "y": 1, "b": 2 }], "a": null, "m": {} });
+	let copy: serde_json::Value = generic_json::to_backend(&value);
+	assert_eq!(keys(&copy), ["z", "a", "m"]);
+	assert_eq!(keys(&copy["z"][0]), ["y", "b"]);
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn query_string_keeps_order() {
+	use generic_json::Json;
+
+	let value: serde_json::Value =
+		generic_json::from_query_string("z=0&a[c]=1&a[b]=2&m=3").unwrap();
+	assert_eq!(keys(&value), ["z", "a", "m"]);
+	assert_eq!(keys(&value["a"]), ["c", "b"]);
+	assert_eq!(value.to_query_string(), "z=0&a[c]=1&a[b]=2&m=3");
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::PrettyDebug;
+use serde_json::json;
+
+#[test]
+fn nested_snapshot() {
+	let value = json!({
+		"a": [1, -2.5, null],
+		"b": { "c": "x\"y", "d": [] },
+		"e": true
+	});
+
+	assert_eq!(
+		format!("{:?}", PrettyDebug(&value)),
+		r#"{
+    "a": [
+        1,
+        -2.5,
+        null,
+    ],
+    "b": {
+        "c": "x\"y",
+        "d": [],
+    },
+    "e": true,
+}"#
+	);
+}
+
+#[test]
+fn scalars() {
+	assert_eq!(format!("{:?}", PrettyDebug(&json!(null))), "null");
+	assert_eq!(format!("{:?}", PrettyDebug(&json!("a\n"))), r#""a\n""#);
+	assert_eq!(
+		format!("{:?}", PrettyDebug(&json!(u64::MAX))),
+		"18446744073709551615"
+	);
+}
+
+#[test]
+fn equality() {
+	assert_eq!(
+		PrettyDebug(&json!([1, { "a": 2 }])),
+		PrettyDebug(&json!([1, { "a": 2 }]))
+	);
+	assert_ne!(PrettyDebug(&json!([1])), PrettyDebug(&json!([2])));
+}
@@ -0,0 +1,66 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{Json, JsonNew, Selection};
+use serde_json::json;
+
+fn document() -> serde_json::Value {
+	json!({
+		"user": { "name": "Alice", "email": "alice@example.com", "age": 42 },
+		"posts": [
+			{ "title": "First", "body": "..." },
+			{ "title": "Second", "body": "...", "tags": ["a"] }
+		],
+		"meta": { "version": 3 }
+	})
+}
+
+fn project(value: &serde_json::Value, selection: &Selection) -> serde_json::Value {
+	serde_json::Value::new(value.project(selection), ())
+}
+
+#[test]
+fn two_leaf_paths() {
+	let mut selection = Selection::empty();
+	selection.select(vec!["user", "name"]);
+	selection.select(vec!["meta", "version"]);
+
+	assert_eq!(
+		project(&document(), &selection),
+		json!({ "user": { "name": "Alice" }, "meta": { "version": 3 } })
+	)
+}
+
+#[test]
+fn each_array_element() {
+	let mut selection = Selection::empty();
+	selection.select(vec!["posts", "*", "title"]);
+
+	assert_eq!(
+		project(&document(), &selection),
+		json!({ "posts": [{ "title": "First" }, { "title": "Second" }] })
+	)
+}
+
+#[test]
+fn whole_subtree_and_missing_members() {
+	let mut selection = Selection::empty();
+	selection.select(vec!["user"]);
+	selection.select(vec!["user", "name"]);
+	selection.select(vec!["missing"]);
+
+	assert_eq!(
+		project(&document(), &selection),
+		json!({ "user": { "name": "Alice", "email": "alice@example.com", "age": 42 } })
+	)
+}
+
+#[test]
+fn shape_mismatch() {
+	let mut selection = Selection::empty();
+	selection.select(vec!["user", "*"]);
+	selection.select(vec!["posts", "title"]);
+
+	assert_eq!(
+		project(&document(), &selection),
+		json!({ "user": null, "posts": null })
+	)
+}
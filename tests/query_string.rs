@@ -0,0 +1,94 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::json;
+
+#[test]
+fn nested_object_with_array() {
+	let value = json!({
+		"a": { "b": [1, true], "c": null },
+		"d": "x"
+	});
+
+	assert_eq!(value.to_query_string(), "a[b][0]=1&a[b][1]=true&a[c]=&d=x");
+}
+
+#[test]
+fn percent_encoding() {
+	let value = json!({ "k[1]": "~*-._", "q": "a b&c=d/é" });
+	assert_eq!(
+		value.to_query_string(),
+		"k%5B1%5D=%7E*-._&q=a+b%26c%3Dd%2F%C3%A9"
+	);
+}
+
+#[test]
+fn empty_containers_and_scalars() {
+	assert_eq!(json!({ "a": [], "b": {} }).to_query_string(), "");
+	assert_eq!(json!("scalar").to_query_string(), "");
+	assert_eq!(json!(1).to_query_string(), "");
+}
+
+#[cfg(feature = "nightly")]
+mod parse {
+	use generic_json::{from_query_string, Json, QueryParseError};
+	use serde_json::{json, Value};
+
+	fn parse(s: &str) -> Result<Value, QueryParseError> {
+		from_query_string(s)
+	}
+
+	#[test]
+	fn round_trip() {
+		let value = json!({
+			"a": { "b": ["x y", "é&="], "c": { "d": "" } },
+			"k[1]": "~",
+			"e": "+%"
+		});
+
+		assert_eq!(parse(&value.to_query_string()).unwrap(), value)
+	}
+
+	#[test]
+	fn round_trip_stringifies_scalars() {
+		let value = json!({ "a": [1, true, null] });
+		assert_eq!(
+			parse(&value.to_query_string()).unwrap(),
+			json!({ "a": ["1", "true", ""] })
+		)
+	}
+
+	#[test]
+	fn top_level_array_parses_as_object() {
+		let query = json!(["a", "b"]).to_query_string();
+		assert_eq!(query, "0=a&1=b");
+		assert_eq!(parse(&query).unwrap(), json!({ "0": "a", "1": "b" }))
+	}
+
+	#[test]
+	fn invalid_percent_encoding() {
+		for s in ["a=%+F", "a=%-1", "a=%F", "a=%GG", "%+F=a", "a=%FF"].iter() {
+			assert_eq!(
+				parse(s),
+				Err(QueryParseError::InvalidEncoding(
+					s.rsplit('=').find(|p| p.contains('%')).unwrap().to_string()
+				)),
+				"{}",
+				s
+			)
+		}
+	}
+
+	#[test]
+	fn malformed_multibyte_keys() {
+		for s in ["a[b]é]=1", "a[b]é[c]=1", "a[b]xé=1", "a[é=1"].iter() {
+			assert_eq!(
+				parse(s),
+				Err(QueryParseError::InvalidKey(
+					s.split('=').next().unwrap().to_string()
+				)),
+				"{}",
+				s
+			)
+		}
+	}
+}
@@ -0,0 +1,97 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+mod common;
+
+use common::TestValue;
+use generic_json::{CaseStyle, Json};
+use serde_json::json;
+
+#[test]
+fn convert() {
+	let cases = [
+		("FooBar", "foobar", "FOOBAR", "fooBar", "foo_bar"),
+		("foo_bar", "foo_bar", "FOO_BAR", "fooBar", "foo_bar"),
+		(
+			"HTTPServer",
+			"httpserver",
+			"HTTPSERVER",
+			"httpServer",
+			"http_server",
+		),
+		(
+			"version2Name",
+			"version2name",
+			"VERSION2NAME",
+			"version2Name",
+			"version2_name",
+		),
+		("  a-b c ", "  a-b c ", "  A-B C ", "aBC", "a_b_c"),
+		("", "", "", "", ""),
+	];
+
+	for (key, lower, upper, camel, snake) in cases {
+		assert_eq!(CaseStyle::Lower.convert(key), lower);
+		assert_eq!(CaseStyle::Upper.convert(key), upper);
+		assert_eq!(CaseStyle::Camel.convert(key), camel, "{}", key);
+		assert_eq!(CaseStyle::Snake.convert(key), snake, "{}", key);
+	}
+}
+
+#[test]
+fn foo_bar() {
+	let mut value = json!({ "FooBar": 1 });
+	assert!(value.recase_keys(CaseStyle::Snake).is_empty());
+	assert_eq!(value, json!({ "foo_bar": 1 }));
+
+	let mut value = json!({ "FooBar": 1 });
+	assert!(value.recase_keys(CaseStyle::Camel).is_empty());
+	assert_eq!(value, json!({ "fooBar": 1 }));
+}
+
+#[test]
+fn nested() {
+	let mut value = json!({ "OuterKey": [{ "InnerKey": { "LeafKey": null } }] });
+	value.recase_keys(CaseStyle::Snake);
+	assert_eq!(
+		value,
+		json!({ "outer_key": [{ "inner_key": { "leaf_key": null } }] })
+	);
+
+	value.recase_keys(CaseStyle::Upper);
+	assert_eq!(
+		value,
+		json!({ "OUTER_KEY": [{ "INNER_KEY": { "LEAF_KEY": null } }] })
+	);
+}
+
+#[test]
+fn collisions() {
+	let mut value = TestValue::object(vec![
+		("fooBar", TestValue::number(1.0)),
+		("other", TestValue::Null),
+		("foo_bar", TestValue::number(2.0)),
+		(
+			"x",
+			TestValue::object(vec![
+				("A", TestValue::Null),
+				("a", TestValue::Boolean(true)),
+			]),
+		),
+	]);
+
+	assert_eq!(value.recase_keys(CaseStyle::Snake), ["/foo_bar", "/x/a"]);
+	assert_eq!(
+		value,
+		TestValue::object(vec![
+			("foo_bar", TestValue::number(2.0)),
+			("other", TestValue::Null),
+			(
+				"x",
+				TestValue::object(vec![("a", TestValue::Boolean(true))])
+			),
+		])
+	);
+
+	let mut value = TestValue::object(vec![("B", TestValue::Null), ("b", TestValue::Null)]);
+	assert_eq!(value.recase_keys(CaseStyle::Upper), ["/B"]);
+	assert_eq!(value, TestValue::object(vec![("B", TestValue::Null)]));
+}
@@ -0,0 +1,207 @@
+#![cfg(feature = "serde_json-impl")]
+mod common;
+
+use generic_json::Json;
+use serde_json::{json, Value};
+
+fn schema() -> Value {
+	// Keys are sorted so that iteration order does not depend
+	// on `serde_json`'s `preserve_order` feature.
+	json!({
+		"definitions": {
+			"name": { "type": "string" },
+			"tag": { "anyOf": [{ "$ref": "#/definitions/name" }, { "type": "integer" }] }
+		},
+		"properties": {
+			"name": { "$ref": "#/definitions/name" },
+			"other": { "$ref": "other.json#/a~1b" },
+			"tags": {
+				"items": { "$ref": "#/definitions/tag", "description": "a tag" },
+				"type": "array"
+			}
+		},
+		"type": "object"
+	})
+}
+
+#[test]
+fn collect_nested_refs() {
+	assert_eq!(
+		schema().collect_refs(),
+		[
+			("/definitions/tag/anyOf/0", "#/definitions/name"),
+			("/properties/name", "#/definitions/name"),
+			("/properties/other", "other.json#/a~1b"),
+			("/properties/tags/items", "#/definitions/tag"),
+		]
+		.iter()
+		.map(|(p, t)| (p.to_string(), t.to_string()))
+		.collect::<Vec<_>>()
+	)
+}
+
+#[test]
+fn collect_escapes_pointers() {
+	let value = json!({ "a/b": [{ "~": { "$ref": "x" } }] });
+	assert_eq!(
+		value.collect_refs(),
+		[("/a~1b/0/~0".to_string(), "x".to_string())]
+	)
+}
+
+#[test]
+fn collect_ignores_non_string_refs() {
+	assert!(json!({ "$ref": 1, "a": [{ "$ref": null }] })
+		.collect_refs()
+		.is_empty())
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn as_ref_object() {
+	let value = schema();
+	assert_eq!(
+		value["properties"]["name"].as_ref_object(),
+		Some("#/definitions/name")
+	);
+	assert_eq!(
+		value["properties"]["tags"]["items"].as_ref_object(),
+		Some("#/definitions/tag")
+	);
+	assert_eq!(value["properties"].as_ref_object(), None);
+	assert_eq!(json!({ "$ref": 1 }).as_ref_object(), None);
+	assert_eq!(json!(["#/a"]).as_ref_object(), None);
+}
+
+#[cfg(feature = "nightly")]
+mod resolve {
+	use generic_json::{Json, RefError};
+	use serde_json::json;
+
+	#[test]
+	fn expand_nested_refs() {
+		let mut value = super::schema();
+		value.resolve_local_refs().unwrap();
+
+		let name = json!({ "type": "string" });
+		assert_eq!(value["properties"]["name"], name);
+		assert_eq!(
+			value["properties"]["tags"]["items"],
+			json!({ "anyOf": [name, { "type": "integer" }] })
+		);
+		assert_eq!(
+			value["definitions"]["tag"],
+			value["properties"]["tags"]["items"]
+		);
+		assert_eq!(
+			value["properties"]["other"],
+			json!({ "$ref": "other.json#/a~1b" })
+		);
+	}
+
+	#[test]
+	fn expand_escaped_and_root_refs() {
+		let mut value = json!({
+			"a/b": { "~": 1 },
+			"c": { "$ref": "#/a~1b/~0" },
+			"d": { "$ref": "#/a%7E1b" },
+			"e": [{ "$ref": "#" }]
+		});
+
+		assert_eq!(
+			value.resolve_local_refs(),
+			Err(RefError::Cycle("#".to_string()))
+		);
+
+		value["e"] = json!(null);
+		value.resolve_local_refs().unwrap();
+		assert_eq!(
+			value,
+			json!({ "a/b": { "~": 1 }, "c": 1, "d": { "~": 1 }, "e": null })
+		);
+	}
+
+	#[test]
+	fn detect_cycles() {
+		let mut value = json!({
+			"a": { "$ref": "#/b" },
+			"b": { "c": { "$ref": "#/a" } }
+		});
+
+		// `/a` refers to `#/b`, which refers back to `#/a`, referring to `#/b` again.
+		assert_eq!(
+			value.resolve_local_refs(),
+			Err(RefError::Cycle("#/b".to_string()))
+		);
+
+		let mut value = json!({ "a": { "$ref": "#/a" } });
+		assert_eq!(
+			value.resolve_local_refs(),
+			Err(RefError::Cycle("#/a".to_string()))
+		);
+	}
+
+	#[test]
+	fn detect_dangling_refs() {
+		let mut value = json!({ "a": [{ "$ref": "#/b/0" }], "b": [] });
+		assert_eq!(
+			value.resolve_local_refs(),
+			Err(RefError::Dangling("#/b/0".to_string()))
+		);
+
+		let mut value = json!({ "a": { "$ref": "#/%+F" } });
+		value.resolve_local_refs().unwrap();
+		assert_eq!(value, json!({ "a": { "$ref": "#/%+F" } }));
+	}
+
+	#[test]
+	fn keep_target_metadata() {
+		use cc_traits::Get;
+		use generic_json::{JsonNew, MetaKey, MetaValue, NumberBuf};
+
+		let member = |key: &str, value, meta| (MetaKey::new(key.to_string(), meta), value);
+
+		// `{"a": 1, "b": {"$ref": "#/a"}}`, with distinct metadata.
+		let mut value: MetaValue<u32> = MetaValue::object(
+			vec![
+				member("a", MetaValue::number(NumberBuf::from(1u32), 1), 2),
+				member(
+					"b",
+					MetaValue::object(
+						vec![member("$ref", MetaValue::string("#/a".into(), 3), 4)]
+							.into_iter()
+							.collect(),
+						5,
+					),
+					6,
+				),
+			]
+			.into_iter()
+			.collect(),
+			0,
+		);
+
+		value.resolve_local_refs().unwrap();
+		let b = value.as_object().unwrap().get("b").unwrap();
+		assert_eq!(b.as_u64(), Some(1));
+		assert_eq!(b.metadata(), &1);
+	}
+
+	#[test]
+	fn reject_uncopyable_numbers() {
+		use super::common::TestValue;
+
+		let mut value = TestValue::object(vec![
+			("a", TestValue::number(f64::NAN)),
+			(
+				"b",
+				TestValue::object(vec![("$ref", TestValue::string("#/a"))]),
+			),
+		]);
+
+		assert_eq!(
+			value.resolve_local_refs(),
+			Err(RefError::InvalidNumber("#/a".to_string()))
+		);
+	}
+}
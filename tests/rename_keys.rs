@@ -0,0 +1,74 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+mod common;
+
+use common::TestValue;
+use generic_json::Json;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+fn mapping(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+	pairs
+		.iter()
+		.map(|(from, to)| (from.to_string(), to.to_string()))
+		.collect()
+}
+
+#[test]
+fn clean_rename() {
+	let mut value = json!({
+		"firstName": "Ada",
+		"address": { "zipCode": "1815", "city": "London" },
+		"children": [{ "firstName": "Byron" }, "firstName"]
+	});
+
+	let collisions = value.rename_keys(&mapping(&[
+		("firstName", "first_name"),
+		("zipCode", "zip_code"),
+	]));
+
+	assert!(collisions.is_empty());
+	assert_eq!(
+		value,
+		json!({
+			"first_name": "Ada",
+			"address": { "zip_code": "1815", "city": "London" },
+			"children": [{ "first_name": "Byron" }, "firstName"]
+		})
+	)
+}
+
+#[test]
+fn colliding_rename() {
+	let mut value = json!({
+		"a": { "ID": 2, "id": 1 },
+		"b": [{ "id/x": 3, "key": 4 }]
+	});
+
+	let collisions = value.rename_keys(&mapping(&[("ID", "id"), ("key", "id/x")]));
+
+	// "ID" comes before "id", both in key and insertion order.
+	assert_eq!(collisions, ["/a/id", "/b/0/id~1x"]);
+	assert_eq!(value, json!({ "a": { "id": 1 }, "b": [{ "id/x": 4 }] }))
+}
+
+#[test]
+fn last_entry_wins_at_first_position() {
+	let mut value = TestValue::object(vec![
+		("x", TestValue::number(1.0)),
+		("a", TestValue::number(2.0)),
+		("y", TestValue::number(3.0)),
+		("b", TestValue::number(4.0)),
+	]);
+
+	let collisions = value.rename_keys(&mapping(&[("a", "c"), ("b", "c")]));
+
+	assert_eq!(collisions, ["/c"]);
+	assert_eq!(
+		value,
+		TestValue::object(vec![
+			("x", TestValue::number(1.0)),
+			("c", TestValue::number(4.0)),
+			("y", TestValue::number(3.0)),
+		])
+	)
+}
@@ -0,0 +1,133 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use cc_traits::{Get, Len, MapIter};
+use generic_json::{Json, JsonNew, Key as _, MetaKey, MetaValue, NumberBuf};
+use serde_json::json;
+
+#[test]
+fn remove_null_elements() {
+	let mut value = json!([null, 1, null, "a", null, [null], { "b": null }, null]);
+	assert!(value.as_value_mut().retain_array(|item| !item.is_null()));
+	assert_eq!(value, json!([1, "a", [null], { "b": null }]));
+
+	let mut value = json!([null, null]);
+	assert!(value.as_value_mut().retain_array(|item| !item.is_null()));
+	assert_eq!(value, json!([]));
+
+	let mut value = json!([]);
+	assert!(value.as_value_mut().retain_array(|_| false));
+	assert_eq!(value, json!([]));
+}
+
+#[test]
+fn array_elements_are_visited_in_order() {
+	let mut value = json!([0, 1, 2, 3, 4, 5]);
+	let mut visited = Vec::new();
+	assert!(value.as_value_mut().retain_array(|item| {
+		let i = item.as_u64().unwrap();
+		visited.push(i);
+		i % 2 == 0
+	}));
+	assert_eq!(visited, [0, 1, 2, 3, 4, 5]);
+	assert_eq!(value, json!([0, 2, 4]));
+}
+
+#[test]
+fn remove_underscore_entries() {
+	let mut value = json!({
+		"_id": 1,
+		"name": "a",
+		"_rev": 2,
+		"nested": { "_id": 3 },
+		"_": null,
+		"a_b": true
+	});
+	assert!(value
+		.as_value_mut()
+		.retain_object(|key, _| !key.starts_with('_')));
+	assert_eq!(
+		value,
+		json!({ "name": "a", "nested": { "_id": 3 }, "a_b": true })
+	);
+
+	let mut value = json!({ "_a": 1, "_b": 2 });
+	assert!(value
+		.as_value_mut()
+		.retain_object(|key, _| !key.starts_with('_')));
+	assert_eq!(value, json!({}));
+}
+
+#[test]
+fn every_entry_is_visited() {
+	let mut value = json!({ "a": 1, "b": 2, "c": 3, "d": 4 });
+	let mut visited = Vec::new();
+	assert!(value.as_value_mut().retain_object(|key, item| {
+		visited.push(key.to_string());
+		item.as_u64().unwrap() > 2
+	}));
+	visited.sort();
+	assert_eq!(visited, ["a", "b", "c", "d"]);
+	assert_eq!(value, json!({ "c": 3, "d": 4 }));
+}
+
+#[test]
+fn retain_by_value() {
+	let mut value = json!({ "a": null, "b": 1, "c": null });
+	assert!(value
+		.as_value_mut()
+		.retain_object(|_, item| !item.is_null()));
+	assert_eq!(value, json!({ "b": 1 }));
+}
+
+#[test]
+fn other_kinds_are_untouched() {
+	let mut value = json!({ "a": null });
+	assert!(!value.as_value_mut().retain_array(|_| false));
+	assert_eq!(value, json!({ "a": null }));
+
+	let mut value = json!([null]);
+	assert!(!value.as_value_mut().retain_object(|_, _| false));
+	assert_eq!(value, json!([null]));
+
+	let mut value = json!("x");
+	assert!(!value.as_value_mut().retain_array(|_| false));
+	assert!(!value.as_value_mut().retain_object(|_, _| false));
+	assert_eq!(value, json!("x"));
+}
+
+#[test]
+fn keep_metadata() {
+	let mut value: MetaValue<u32> = MetaValue::array(
+		vec![
+			MetaValue::null(1),
+			MetaValue::number(NumberBuf::from(1u32), 2),
+			MetaValue::null(3),
+			MetaValue::string("a".into(), 4),
+		],
+		0,
+	);
+	assert!(value.as_value_mut().retain_array(|item| !item.is_null()));
+	let a = value.as_array().unwrap();
+	assert_eq!(a.len(), 2);
+	assert_eq!(a.get(0).unwrap().metadata(), &2);
+	assert_eq!(a.get(1).unwrap().metadata(), &4);
+	assert_eq!(value.metadata(), &0);
+
+	let mut value: MetaValue<u32> = MetaValue::object(
+		vec![
+			(MetaKey::new("_a".to_string(), 1), MetaValue::null(2)),
+			(MetaKey::new("b".to_string(), 3), MetaValue::null(4)),
+		]
+		.into_iter()
+		.collect(),
+		0,
+	);
+	assert!(value
+		.as_value_mut()
+		.retain_object(|key, _| !key.starts_with('_')));
+	let o = value.as_object().unwrap();
+	assert_eq!(o.len(), 1);
+	let (key, item) = MapIter::iter(o).next().unwrap();
+	assert_eq!(&**key, "b");
+	assert_eq!(key.metadata(), &3);
+	assert_eq!(item.metadata(), &4);
+}
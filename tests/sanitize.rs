@@ -0,0 +1,75 @@
+#![cfg(feature = "nightly")]
+mod common;
+
+use common::TestValue;
+use generic_json::{Json, SanitizePolicy};
+
+fn document() -> TestValue {
+	TestValue::object(vec![
+		("nan", TestValue::number(f64::NAN)),
+		(
+			"list",
+			TestValue::Array(vec![
+				TestValue::number(f64::INFINITY),
+				TestValue::number(1.5),
+				TestValue::number(f64::NEG_INFINITY),
+			]),
+		),
+		("finite", TestValue::number(-2.0)),
+	])
+}
+
+fn expected(replace: impl Fn(f64) -> TestValue) -> TestValue {
+	TestValue::object(vec![
+		("nan", replace(f64::NAN)),
+		(
+			"list",
+			TestValue::Array(vec![
+				replace(f64::INFINITY),
+				TestValue::number(1.5),
+				replace(f64::NEG_INFINITY),
+			]),
+		),
+		("finite", TestValue::number(-2.0)),
+	])
+}
+
+#[test]
+fn sanitize_null() {
+	let mut value = document();
+	value.sanitize(SanitizePolicy::Null);
+	assert_eq!(value, expected(|_| TestValue::Null))
+}
+
+#[test]
+fn sanitize_zero() {
+	let mut value = document();
+	value.sanitize(SanitizePolicy::Zero);
+	assert_eq!(value, expected(|_| TestValue::number(0.0)))
+}
+
+#[test]
+fn sanitize_string() {
+	let mut value = document();
+	value.sanitize(SanitizePolicy::String);
+	assert_eq!(
+		value,
+		expected(|f| {
+			TestValue::string(if f.is_nan() {
+				"NaN"
+			} else if f > 0.0 {
+				"Infinity"
+			} else {
+				"-Infinity"
+			})
+		})
+	)
+}
+
+#[test]
+fn sanitize_finite_document() {
+	let mut value = TestValue::Array(vec![TestValue::number(0.1), TestValue::Null]);
+	let copy = value.clone();
+	value.sanitize(SanitizePolicy::Null);
+	assert_eq!(value, copy)
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{CompareOptions, Json};
+use serde_json::json;
+
+fn options(
+	ignore_null_fields: bool,
+	ignore_empty_containers: bool,
+	numeric_coercion: bool,
+) -> CompareOptions {
+	CompareOptions {
+		ignore_null_fields,
+		ignore_empty_containers,
+		numeric_coercion,
+	}
+}
+
+#[test]
+fn strict_by_default() {
+	let a = json!({ "a": [1, { "b": "x" }], "c": true });
+	let b = json!({ "c": true, "a": [1, { "b": "x" }] });
+	assert!(a.semantically_eq(&b, &CompareOptions::default()));
+	assert!(!a.semantically_eq(
+		&json!({ "a": [1, { "b": "x" }] }),
+		&CompareOptions::default()
+	));
+	assert!(!json!([1, 2]).semantically_eq(&json!([2, 1]), &CompareOptions::default()));
+}
+
+#[test]
+fn ignore_null_fields() {
+	let a = json!({ "a": 1, "b": { "c": null } });
+	let b = json!({ "a": 1, "b": {}, "d": null });
+	assert!(!a.semantically_eq(&b, &CompareOptions::default()));
+	assert!(a.semantically_eq(&b, &options(true, false, false)));
+	assert!(b.semantically_eq(&a, &options(true, false, false)));
+
+	// Only object members are ignored.
+	assert!(!json!([null]).semantically_eq(&json!([]), &options(true, false, false)));
+	assert!(!json!({ "a": null }).semantically_eq(&json!({ "a": 1 }), &options(true, false, false)));
+}
+
+#[test]
+fn ignore_empty_containers() {
+	let a = json!({ "a": 1, "b": [], "c": { "d": {} } });
+	let b = json!({ "a": 1, "c": { "e": [] } });
+	assert!(!a.semantically_eq(&b, &CompareOptions::default()));
+	assert!(!a.semantically_eq(&b, &options(true, false, false)));
+	assert!(a.semantically_eq(&b, &options(false, true, false)));
+
+	let a = json!({ "a": null, "b": [] });
+	assert!(!a.semantically_eq(&json!({}), &options(false, true, false)));
+	assert!(a.semantically_eq(&json!({}), &options(true, true, false)));
+}
+
+#[test]
+fn numeric_coercion() {
+	let a = json!({ "a": [1, 2.5], "b": -0.0 });
+	let b = json!({ "a": [1.0, 2.5], "b": 0 });
+	assert!(!a.semantically_eq(&b, &CompareOptions::default()));
+	assert!(a.semantically_eq(&b, &options(false, false, true)));
+	assert!(!json!(1).semantically_eq(&json!(1.5), &options(false, false, true)));
+	assert!(!json!(1).semantically_eq(&json!("1"), &options(true, true, true)));
+}
+
+#[cfg(all(feature = "nightly", feature = "ijson-impl"))]
+#[test]
+fn across_backends() {
+	let a = json!({ "a": 1, "b": null });
+	let b: ijson::IValue = generic_json::to_backend(&json!({ "a": 1.0 }));
+	assert!(a.semantically_eq(&b, &options(true, false, true)));
+	assert!(!a.semantically_eq(&json!({ "a": 2 }), &options(true, false, true)));
+}
@@ -0,0 +1,78 @@
+#![cfg(all(feature = "serde", feature = "serde_json-impl"))]
+use generic_json::{Json, JsonNew, MetaValue, NumberBuf, Value};
+use serde_json::json;
+
+fn number(s: &str) -> MetaValue {
+	MetaValue::number(NumberBuf::new(s.to_string()).unwrap(), ())
+}
+
+fn document() -> Value<MetaValue> {
+	let value: MetaValue = vec![
+		(
+			"a".to_string(),
+			vec![
+				MetaValue::null(()),
+				MetaValue::boolean(true, ()),
+				number("-3"),
+				number("18446744073709551615"),
+				number("0.5"),
+			]
+			.into_iter()
+			.collect(),
+		),
+		(
+			"b".to_string(),
+			vec![("c".to_string(), MetaValue::string("é\n".to_string(), ()))]
+				.into_iter()
+				.collect(),
+		),
+	]
+	.into_iter()
+	.collect();
+
+	value.into_value()
+}
+
+fn expected() -> serde_json::Value {
+	json!({
+		"a": [null, true, -3, 18446744073709551615u64, 0.5],
+		"b": { "c": "é\n" }
+	})
+}
+
+#[test]
+fn round_trip_through_serde_json() {
+	let s = serde_json::to_string(&document()).unwrap();
+	assert_eq!(
+		serde_json::from_str::<serde_json::Value>(&s).unwrap(),
+		expected()
+	);
+}
+
+#[test]
+fn round_trip_through_cbor() {
+	let mut bytes = Vec::new();
+	ciborium::ser::into_writer(&document(), &mut bytes).unwrap();
+	let value: serde_json::Value = ciborium::de::from_reader(bytes.as_slice()).unwrap();
+	assert_eq!(value, expected());
+
+	// Integers are encoded as CBOR integers, not floats.
+	let mut bytes = Vec::new();
+	ciborium::ser::into_writer(&number("-3").into_value(), &mut bytes).unwrap();
+	assert_eq!(bytes, [0x22]);
+}
+
+#[test]
+fn lossy_fallback() {
+	let value: MetaValue = vec![
+		number("0.1000000000000000000001"),
+		number("123456789012345678901234567890"),
+	]
+	.into_iter()
+	.collect();
+
+	assert_eq!(
+		serde_json::to_value(value.into_value()).unwrap(),
+		json!([0.1, 123456789012345678901234567890f64])
+	);
+}
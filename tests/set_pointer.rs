@@ -0,0 +1,61 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{Json, PointerError, Value};
+use serde_json::json;
+
+fn document() -> serde_json::Value {
+	json!({ "a": { "b": [1, 2] }, "c": "x" })
+}
+
+#[test]
+fn replace_existing_leaf() {
+	let mut value = document();
+	let old = value.set_pointer("/a/b/1", Value::Boolean(true)).unwrap();
+	assert!(matches!(old, Some(Value::Number(n)) if n.as_u64() == Some(2)));
+	assert_eq!(value, json!({ "a": { "b": [1, true] }, "c": "x" }));
+
+	let old = value.set_pointer("/c", Value::Null).unwrap();
+	assert!(matches!(old, Some(Value::String(s)) if s == "x"));
+	assert_eq!(value["c"], json!(null));
+}
+
+#[test]
+fn add_to_existing_parent() {
+	let mut value = document();
+	assert!(value.set_pointer("/a/d", Value::Null).unwrap().is_none());
+	assert!(value.set_pointer("/a/b/-", Value::Null).unwrap().is_none());
+	assert!(value.set_pointer("/a/b/3", Value::Null).unwrap().is_none());
+	assert_eq!(
+		value,
+		json!({ "a": { "b": [1, 2, null, null], "d": null }, "c": "x" })
+	);
+}
+
+#[test]
+fn replace_root() {
+	let mut value = document();
+	let old = value.set_pointer("", Value::Null).unwrap();
+	assert!(matches!(old, Some(Value::Object(_))));
+	assert_eq!(value, json!(null));
+}
+
+#[test]
+fn errors() {
+	let mut value = document();
+	assert_eq!(
+		value.set_pointer("/x/y", Value::Null),
+		Err(PointerError::ParentNotFound("/x".to_string()))
+	);
+	assert_eq!(
+		value.set_pointer("/c/y", Value::Null),
+		Err(PointerError::NotAContainer("/c".to_string()))
+	);
+	assert_eq!(
+		value.set_pointer("/a/b/5", Value::Null),
+		Err(PointerError::InvalidIndex("/a/b/5".to_string()))
+	);
+	assert_eq!(
+		value.set_pointer("a", Value::Null),
+		Err(PointerError::Invalid("a".to_string()))
+	);
+	assert_eq!(value, document());
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::json;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+fn shape<T: Json>(value: &T) -> u64 {
+	let mut h = DefaultHasher::new();
+	value.structural_shape_hash(&mut h);
+	h.finish()
+}
+
+#[test]
+fn same_shape_different_values() {
+	let a = json!({ "id": 1, "name": "a", "tags": ["x", "y"], "ok": true });
+	let b = json!({ "tags": ["z", ""], "ok": false, "name": "bbb", "id": 2.5 });
+	assert_eq!(shape(&a), shape(&b));
+}
+
+#[test]
+fn different_shapes() {
+	let a = json!({ "id": 1, "tags": ["x", "y"] });
+	assert_ne!(shape(&a), shape(&json!({ "id": 1, "tags": ["x"] })));
+	assert_ne!(shape(&a), shape(&json!({ "id": 1, "tag": ["x", "y"] })));
+	assert_ne!(shape(&a), shape(&json!({ "id": "1", "tags": ["x", "y"] })));
+	assert_ne!(
+		shape(&a),
+		shape(&json!({ "id": 1, "tags": ["x", "y"], "n": null }))
+	);
+	assert_ne!(shape(&json!([])), shape(&json!({})));
+	assert_ne!(shape(&json!(null)), shape(&json!(false)));
+	assert_ne!(shape(&json!([[1], []])), shape(&json!([[], [1]])));
+}
+
+#[cfg(all(feature = "nightly", feature = "ijson-impl"))]
+#[test]
+fn across_backends() {
+	let value = json!({ "a": [1, { "b": null }] });
+	let ivalue: ijson::IValue = generic_json::to_backend(&value);
+	assert_eq!(shape(&value), shape(&ivalue));
+}
@@ -0,0 +1,88 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use cc_traits::{Get, GetMut, Len, PushBack};
+use generic_json::{to_backend, Json, JsonNew, SharedValue};
+use serde_json::json;
+
+fn document() -> SharedValue {
+	to_backend(&json!({
+		"a": [1, [2, 3]],
+		"b": { "c": "x" }
+	}))
+}
+
+fn array<'a>(value: &'a SharedValue, key: &str) -> &'a generic_json::SharedArray {
+	value
+		.as_object()
+		.unwrap()
+		.get(key)
+		.unwrap()
+		.as_array()
+		.unwrap()
+}
+
+#[test]
+fn clone_does_not_copy() {
+	let value = document();
+	let copy = value.clone();
+
+	assert!(value.as_object().unwrap().ptr_eq(copy.as_object().unwrap()));
+	assert!(array(&value, "a").ptr_eq(array(&copy, "a")));
+}
+
+#[test]
+fn mutation_copies_the_mutated_path_only() {
+	let value = document();
+	let mut copy = value.clone();
+
+	let a = copy
+		.as_object_mut()
+		.unwrap()
+		.get_mut("a")
+		.unwrap()
+		.as_array_mut()
+		.unwrap();
+	a.push_back(SharedValue::null(()));
+
+	// The root object and the mutated array are copied...
+	assert!(!value.as_object().unwrap().ptr_eq(copy.as_object().unwrap()));
+	assert!(!array(&value, "a").ptr_eq(array(&copy, "a")));
+
+	// ...but not their other children.
+	let inner = |v: &SharedValue| array(v, "a").get(1).unwrap().as_array().unwrap().clone();
+	assert!(inner(&value).ptr_eq(&inner(&copy)));
+	let b = |v: &SharedValue| {
+		v.as_object()
+			.unwrap()
+			.get("b")
+			.unwrap()
+			.as_object()
+			.unwrap()
+			.clone()
+	};
+	assert!(b(&value).ptr_eq(&b(&copy)));
+
+	// The original is left unchanged.
+	assert_eq!(value, document());
+	assert_eq!(array(&copy, "a").len(), 3);
+	assert_eq!(array(&value, "a").len(), 2);
+}
+
+#[test]
+fn unshared_mutation_does_not_copy() {
+	let first = |v: &SharedValue| array(v, "a").get(0).unwrap() as *const SharedValue;
+
+	let mut value = document();
+	let before = first(&value);
+	*value
+		.as_object_mut()
+		.unwrap()
+		.get_mut("a")
+		.unwrap()
+		.as_array_mut()
+		.unwrap()
+		.get_mut(0)
+		.unwrap() = SharedValue::null(());
+
+	assert_eq!(first(&value), before);
+	assert!(array(&value, "a").get(0).unwrap().is_null());
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "nightly")]
+mod common;
+
+use common::TestValue;
+use generic_json::{Json, ValueRef};
+
+#[test]
+fn sorted_over_insertion_order() {
+	let value = TestValue::object(vec![
+		("zeta", TestValue::number(1.0)),
+		("alpha", TestValue::Null),
+		("Beta", TestValue::Boolean(true)),
+		("ä", TestValue::string("x")),
+		("a", TestValue::Array(vec![])),
+	]);
+
+	let entries = value.sorted_entries().unwrap();
+	let keys: Vec<_> = entries.iter().map(|(key, _)| *key).collect();
+	assert_eq!(keys, ["Beta", "a", "alpha", "zeta", "ä"]);
+	assert!(matches!(entries[0].1, ValueRef::Boolean(true)));
+	assert!(matches!(entries[4].1, ValueRef::String(s) if s == "x"));
+}
+
+#[test]
+fn duplicate_keys_keep_iteration_order() {
+	let value = TestValue::object(vec![
+		("b", TestValue::number(1.0)),
+		("a", TestValue::number(2.0)),
+		("b", TestValue::number(3.0)),
+	]);
+
+	let values: Vec<_> = value
+		.sorted_entries()
+		.unwrap()
+		.into_iter()
+		.map(|(key, item)| (key, item.as_f64_lossy().unwrap()))
+		.collect();
+	assert_eq!(values, [("a", 2.0), ("b", 1.0), ("b", 3.0)]);
+}
+
+#[test]
+fn non_objects() {
+	assert!(TestValue::Array(vec![]).sorted_entries().is_none());
+	assert!(TestValue::Null.sorted_entries().is_none());
+}
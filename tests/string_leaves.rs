@@ -0,0 +1,33 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::Json;
+use serde_json::json;
+
+fn document() -> serde_json::Value {
+	// Keys are sorted so that the document order does not depend
+	// on `serde_json`'s `preserve_order` feature.
+	json!({
+		"a": ["x", 1, ["y", null], { "b": "z" }],
+		"c": true,
+		"d": "w"
+	})
+}
+
+#[test]
+fn collect_string_values() {
+	let value = document();
+	assert_eq!(
+		value.string_leaves().collect::<Vec<_>>(),
+		["x", "y", "z", "w"]
+	);
+	assert_eq!(json!("s").string_leaves().collect::<Vec<_>>(), ["s"]);
+	assert_eq!(json!([1, {}, []]).string_leaves().count(), 0);
+}
+
+#[test]
+fn collect_strings_with_keys() {
+	let value = document();
+	assert_eq!(
+		value.string_leaves_with_keys().collect::<Vec<_>>(),
+		["a", "x", "y", "b", "z", "c", "d", "w"]
+	);
+}
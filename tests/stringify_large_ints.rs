@@ -0,0 +1,41 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::Json;
+use serde_json::json;
+
+const JS_SAFE: i128 = (1 << 53) - 1;
+
+#[test]
+fn mixed_integers() {
+	let mut value = json!({
+		"small": 42,
+		"limit": 9007199254740991u64,
+		"large": 9007199254740992u64,
+		"negative": -9007199254740993i64,
+		"max": u64::MAX,
+		"nested": [1, { "id": 12345678901234567890u64 }],
+		"float": 1e300,
+		"text": "9007199254740993"
+	});
+
+	value.stringify_large_ints(JS_SAFE);
+	assert_eq!(
+		value,
+		json!({
+			"small": 42,
+			"limit": 9007199254740991u64,
+			"large": "9007199254740992",
+			"negative": "-9007199254740993",
+			"max": "18446744073709551615",
+			"nested": [1, { "id": "12345678901234567890" }],
+			"float": 1e300,
+			"text": "9007199254740993"
+		})
+	)
+}
+
+#[test]
+fn zero_threshold() {
+	let mut value = json!([0, 1, -1, 0.5]);
+	value.stringify_large_ints(0);
+	assert_eq!(value, json!([0, "1", "-1", 0.5]));
+}
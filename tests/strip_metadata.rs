@@ -0,0 +1,89 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{strip_metadata, Json, JsonNew, MetaKey, MetaValue, NumberBuf};
+use serde_json::json;
+use std::ops::Range;
+
+type Span = Range<usize>;
+
+fn number(lexical: &str, span: Span) -> MetaValue<Span> {
+	MetaValue::number(NumberBuf::new(lexical.to_string()).unwrap(), span)
+}
+
+/// `{"a": [1.5, true, null], "b": {"c": "x"}, "d": -7}`, with the span of each token.
+fn document() -> MetaValue<Span> {
+	MetaValue::object(
+		vec![
+			(
+				MetaKey::new("a".to_string(), 1..4),
+				MetaValue::array(
+					vec![
+						number("1.5", 7..10),
+						MetaValue::boolean(true, 12..16),
+						MetaValue::null(18..22),
+					]
+					.into_iter()
+					.collect(),
+					6..23,
+				),
+			),
+			(
+				MetaKey::new("b".to_string(), 25..28),
+				MetaValue::object(
+					vec![(
+						MetaKey::new("c".to_string(), 31..34),
+						MetaValue::string("x".into(), 36..39),
+					)]
+					.into_iter()
+					.collect(),
+					30..40,
+				),
+			),
+			(MetaKey::new("d".to_string(), 42..45), number("-7", 47..49)),
+		]
+		.into_iter()
+		.collect(),
+		0..50,
+	)
+}
+
+#[test]
+fn into_serde_json() {
+	let value: serde_json::Value = strip_metadata(document());
+	assert_eq!(
+		value,
+		json!({ "a": [1.5, true, null], "b": { "c": "x" }, "d": -7 })
+	);
+	assert!(value["d"].is_i64());
+}
+
+#[test]
+fn into_meta_value_with_default_metadata() {
+	let value: MetaValue<Span> = strip_metadata(document());
+	assert_eq!(value.metadata(), &(0..0));
+	assert_eq!(value.as_object().unwrap()["a"].metadata(), &(0..0));
+
+	let value: MetaValue = strip_metadata(document());
+	let value: serde_json::Value = strip_metadata(value);
+	assert_eq!(
+		value,
+		json!({ "a": [1.5, true, null], "b": { "c": "x" }, "d": -7 })
+	);
+}
+
+#[test]
+fn big_numbers() {
+	let big = "123456789012345678901234567890";
+	let value: serde_json::Value = strip_metadata(number(big, 0..30));
+
+	#[cfg(feature = "arbitrary_precision")]
+	assert_eq!(value.to_string(), big);
+
+	#[cfg(not(feature = "arbitrary_precision"))]
+	assert_eq!(value.as_f64(), Some(1.2345678901234568e29));
+
+	let value: MetaValue = strip_metadata(number(big, 0..30));
+	assert_eq!(
+		generic_json::Number::as_str(value.as_number().unwrap()),
+		Some(big)
+	);
+}
@@ -0,0 +1,90 @@
+use cc_traits::Get;
+use generic_json::{Json, StructJson, StructObject};
+use std::cell::Cell;
+
+struct User {
+	name: String,
+	age: u32,
+}
+
+impl User {
+	fn as_json(&self) -> StructJson<'_> {
+		StructObject::new()
+			.field("name", move || self.name.as_str())
+			.field("age", move || self.age)
+			.into()
+	}
+}
+
+#[test]
+fn get_struct_fields() {
+	let user = User {
+		name: "Ada".to_string(),
+		age: 36,
+	};
+	let json = user.as_json();
+
+	let o = json.as_object().unwrap();
+	assert_eq!(o.get("name").unwrap().as_str(), Some("Ada"));
+	assert_eq!(o.get("age").unwrap().as_u64(), Some(36));
+	assert!(o.get("email").is_none());
+
+	let keys: Vec<_> = cc_traits::MapIter::iter(o)
+		.map(|(key, _)| key.clone())
+		.collect();
+	assert_eq!(keys, ["name", "age"]);
+}
+
+/// Returns a copy of the value designated by `ptr`.
+#[cfg(feature = "nightly")]
+fn pointer<'a>(json: &StructJson<'a>, ptr: &str) -> Option<StructJson<'a>> {
+	generic_json::with_pointer(json, ptr, Clone::clone)
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn query_struct_fields() {
+	let user = User {
+		name: "Ada".to_string(),
+		age: 36,
+	};
+	let json = user.as_json();
+
+	assert!(json.is_object());
+	assert_eq!(pointer(&json, "/name").unwrap().as_str(), Some("Ada"));
+	assert_eq!(pointer(&json, "/age").unwrap().as_u64(), Some(36));
+	assert!(pointer(&json, "/email").is_none());
+	assert!(pointer(&json, "/name/0").is_none());
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn nested_objects_and_arrays() {
+	let json: StructJson = StructObject::new()
+		.field("tags", || vec!["a", "b"])
+		.field("inner", || {
+			StructObject::new()
+				.field("x", || Some(1.5))
+				.field("y", || None::<i64>)
+		})
+		.into();
+
+	assert_eq!(pointer(&json, "/tags/1").unwrap().as_str(), Some("b"));
+	assert_eq!(pointer(&json, "/inner/x").unwrap().as_f64(), Some(1.5));
+	assert!(pointer(&json, "/inner/y").unwrap().is_null());
+}
+
+#[test]
+fn fields_are_computed_on_access() {
+	let calls = Cell::new(0);
+	let json: StructJson = StructObject::new()
+		.field("n", || {
+			calls.set(calls.get() + 1);
+			calls.get()
+		})
+		.into();
+
+	let o = json.as_object().unwrap();
+	assert_eq!(o.get("n").unwrap().as_u64(), Some(1));
+	assert_eq!(o.get("n").unwrap().as_u64(), Some(2));
+}
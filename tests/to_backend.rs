@@ -0,0 +1,49 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::{to_backend, BorrowedValue, Json, MetaValue};
+use serde_json::{json, Value};
+
+fn document() -> Value {
+	json!({
+		"a": [1, -2.5, "x", true, null],
+		"b": { "c": { "d": [] }, "e": {} },
+		"f": [i64::MIN, u64::MAX]
+	})
+}
+
+#[test]
+fn same_as_clone() {
+	let value = document();
+	let copy: Value = to_backend(&value);
+	assert_eq!(copy, value.clone());
+
+	// The source is left untouched.
+	assert_eq!(value, document());
+}
+
+#[test]
+fn through_meta_value() {
+	let value = document();
+	let meta: MetaValue<u8> = to_backend(&value);
+	assert_eq!(meta.metadata(), &0);
+
+	let back: Value = to_backend(&meta);
+	assert_eq!(back, value);
+}
+
+#[test]
+fn from_borrowed_documents() {
+	// `BorrowedValue` borrows its strings from the document it was parsed from.
+	let document = r#"{ "a": [1, -2.5, "x", true, null], "b": { "c": { "d": [] }, "e": {} }, "f": [-9223372036854775808, 18446744073709551615] }"#;
+	let borrowed = BorrowedValue::parse(document).unwrap();
+	let copy: Value = to_backend(&borrowed);
+	assert_eq!(copy, self::document());
+}
+
+#[cfg(feature = "ijson-impl")]
+#[test]
+fn across_backends() {
+	let value = document();
+	let ivalue: ijson::IValue = to_backend(&value);
+	let back: Value = to_backend(&ivalue);
+	assert_eq!(back, value);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::json;
+
+#[test]
+fn numeric_array() {
+	assert_eq!(
+		json!([1, -2.5, 1e300, u64::MAX]).to_f64_vec(),
+		Some(vec![1.0, -2.5, 1e300, u64::MAX as f64])
+	)
+}
+
+#[test]
+fn mixed_array() {
+	assert_eq!(json!([1, "2", 3]).to_f64_vec(), None);
+	assert_eq!(json!([1, null]).to_f64_vec(), None);
+	assert_eq!(json!([[1]]).to_f64_vec(), None);
+}
+
+#[test]
+fn empty_array() {
+	assert_eq!(json!([]).to_f64_vec(), Some(Vec::new()))
+}
+
+#[test]
+fn not_an_array() {
+	assert_eq!(json!(1).to_f64_vec(), None);
+	assert_eq!(json!({ "a": 1 }).to_f64_vec(), None);
+}
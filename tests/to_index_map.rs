@@ -0,0 +1,56 @@
+#![cfg(feature = "indexmap")]
+mod common;
+
+use common::TestValue;
+use generic_json::{Json, Number, Value};
+
+fn keys<T: Json>(map: &indexmap::IndexMap<String, Value<T>>) -> Vec<&str> {
+	map.keys().map(String::as_str).collect()
+}
+
+#[test]
+fn keep_insertion_order() {
+	let value = TestValue::object(vec![
+		("z", TestValue::number(1.0)),
+		("a", TestValue::string("x")),
+		("m", TestValue::Array(vec![TestValue::Null])),
+	]);
+
+	let map = value.to_index_map().unwrap();
+	assert_eq!(keys(&map), ["z", "a", "m"]);
+	assert_eq!(map["a"].as_str(), Some("x"));
+	assert!(matches!(&map["m"], Value::Array(a) if a.len() == 1));
+}
+
+#[test]
+fn duplicate_keys() {
+	let value = TestValue::object(vec![
+		("b", TestValue::number(1.0)),
+		("a", TestValue::number(2.0)),
+		("b", TestValue::number(3.0)),
+	]);
+
+	let map = value.to_index_map().unwrap();
+	assert_eq!(keys(&map), ["b", "a"]);
+	assert_eq!(map["b"].as_number().and_then(Number::as_f64), Some(3.0));
+}
+
+#[test]
+fn non_objects() {
+	assert!(TestValue::Null.to_index_map().is_none());
+	assert!(TestValue::Array(Vec::new()).to_index_map().is_none());
+	assert!(TestValue::object(Vec::new())
+		.to_index_map()
+		.unwrap()
+		.is_empty());
+}
+
+#[cfg(feature = "preserve_order")]
+#[test]
+fn serde_json_preserve_order() {
+	let value: serde_json::Value =
+		serde_json::from_str(r#"{ "z": 1, "a": [2], "m": null }"#).unwrap();
+	let map = value.to_index_map().unwrap();
+	assert_eq!(keys(&map), ["z", "a", "m"]);
+	assert_eq!(map["z"].as_number().and_then(Number::as_u64), Some(1));
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::json;
+
+/// Strings with sparse and dense escape characters,
+/// crossing the boundaries of vectorized chunks.
+fn strings() -> Vec<String> {
+	let mut strings = vec![
+		String::new(),
+		"plain ascii text without escapes, long enough for several chunks".to_string(),
+		"\"\\\n\r\t\u{8}\u{c}\u{0}\u{1f}".repeat(5),
+		"é😀\u{7f}/ not escaped".to_string(),
+	];
+
+	for len in 0..40 {
+		for i in 0..len {
+			let mut s: Vec<u8> = vec![b'a'; len];
+			s[i] = b'"';
+			strings.push(String::from_utf8(s).unwrap());
+		}
+
+		strings.push("é\\".repeat(len));
+	}
+
+	strings
+}
+
+#[test]
+fn strings_match_serde_json() {
+	for s in strings() {
+		let value = json!(s);
+		assert_eq!(
+			String::from_utf8(value.to_vec().unwrap()).unwrap(),
+			serde_json::to_string(&value).unwrap(),
+			"{:?}",
+			s
+		)
+	}
+}
+
+#[test]
+fn documents_match_serde_json() {
+	let value = json!({
+		"a\"b": [null, true, false, -1, 18446744073709551615u64, 0.5, "\u{1}"],
+		"c": { "d": [], "e": {} }
+	});
+
+	let mut buffer = Vec::new();
+	value.to_writer(&mut buffer).unwrap();
+	assert_eq!(buffer, serde_json::to_vec(&value).unwrap());
+	assert_eq!(buffer, value.to_vec().unwrap());
+}
+
+#[test]
+fn io_errors_are_reported() {
+	struct Full;
+
+	impl std::io::Write for Full {
+		fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+			Err(std::io::ErrorKind::WriteZero.into())
+		}
+
+		fn flush(&mut self) -> std::io::Result<()> {
+			Ok(())
+		}
+	}
+
+	let err = json!([1]).to_writer(Full).unwrap_err();
+	assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::{TestValue, F64};
+use generic_json::{Json, Number, Value};
+use std::cmp::Ordering;
+
+fn value(v: TestValue) -> Value<TestValue> {
+	v.into_value()
+}
+
+fn number(f: f64) -> Value<TestValue> {
+	value(TestValue::number(f))
+}
+
+#[test]
+fn numbers() {
+	assert_eq!(F64(-0.0).total_cmp(&F64(0.0)), Ordering::Equal);
+	assert_eq!(F64(1.0).total_cmp(&F64(2.0)), Ordering::Less);
+	assert_eq!(F64(f64::NAN).total_cmp(&F64(f64::NAN)), Ordering::Equal);
+	assert_eq!(
+		F64(f64::NAN).total_cmp(&F64(f64::INFINITY)),
+		Ordering::Greater
+	);
+	assert_eq!(F64(f64::NEG_INFINITY).total_cmp(&F64(-1.0)), Ordering::Less);
+}
+
+#[test]
+fn zeros_are_equal() {
+	assert_eq!(number(-0.0).total_cmp(&number(0.0)), Ordering::Equal);
+	assert_eq!(
+		value(TestValue::Array(vec![TestValue::number(-0.0)]))
+			.total_cmp(&value(TestValue::Array(vec![TestValue::number(0.0)]))),
+		Ordering::Equal
+	);
+}
+
+#[test]
+fn nan_sorts_consistently() {
+	let sorted = |input: &[f64]| {
+		let mut values: Vec<_> = input.iter().map(|f| number(*f)).collect();
+		values.sort_by(Value::total_cmp);
+		values
+			.into_iter()
+			.map(|v| match v {
+				Value::Number(n) => n.0,
+				_ => unreachable!(),
+			})
+			.collect::<Vec<_>>()
+	};
+
+	let a = sorted(&[f64::NAN, 1.0, f64::NEG_INFINITY, -2.0, f64::NAN]);
+	let b = sorted(&[-2.0, f64::NAN, f64::NAN, 1.0, f64::NEG_INFINITY]);
+	assert_eq!(a[..3], [f64::NEG_INFINITY, -2.0, 1.0]);
+	assert!(a[3].is_nan() && a[4].is_nan());
+	assert_eq!(
+		a.iter().map(|f| f.to_bits()).collect::<Vec<_>>(),
+		b.iter().map(|f| f.to_bits()).collect::<Vec<_>>()
+	);
+}
+
+#[test]
+fn kinds() {
+	let values = [
+		value(TestValue::Null),
+		value(TestValue::Boolean(false)),
+		value(TestValue::Boolean(true)),
+		number(f64::NAN),
+		value(TestValue::string("")),
+		value(TestValue::Array(Vec::new())),
+		value(TestValue::object(Vec::new())),
+	];
+
+	for (i, a) in values.iter().enumerate() {
+		for (j, b) in values.iter().enumerate() {
+			assert_eq!(a.total_cmp(b), i.cmp(&j))
+		}
+	}
+}
+
+#[test]
+fn containers() {
+	let array = |items: &[f64]| {
+		value(TestValue::Array(
+			items.iter().map(|f| TestValue::number(*f)).collect(),
+		))
+	};
+	assert_eq!(
+		array(&[1.0, 2.0]).total_cmp(&array(&[1.0, 3.0])),
+		Ordering::Less
+	);
+	assert_eq!(array(&[1.0]).total_cmp(&array(&[1.0, 0.0])), Ordering::Less);
+	assert_eq!(
+		array(&[2.0]).total_cmp(&array(&[1.0, 0.0])),
+		Ordering::Greater
+	);
+
+	let object = |members: Vec<(&str, f64)>| {
+		value(TestValue::object(
+			members
+				.into_iter()
+				.map(|(k, f)| (k, TestValue::number(f)))
+				.collect::<Vec<_>>(),
+		))
+	};
+	assert_eq!(
+		object(vec![("b", 1.0), ("a", 2.0)]).total_cmp(&object(vec![("a", 2.0), ("b", 1.0)])),
+		Ordering::Equal
+	);
+	assert_eq!(
+		object(vec![("a", 1.0)]).total_cmp(&object(vec![("b", 0.0)])),
+		Ordering::Less
+	);
+	assert_eq!(
+		object(vec![("a", 1.0), ("b", 0.0)]).total_cmp(&object(vec![("a", 1.0)])),
+		Ordering::Greater
+	);
+}
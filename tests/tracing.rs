@@ -0,0 +1,51 @@
+#![cfg(feature = "tracing")]
+use generic_json::{canonicalize, BorrowedValue, Json};
+use std::sync::{Arc, Mutex};
+use tracing::{span, Subscriber};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+/// Records the name of every created span.
+#[derive(Clone, Default)]
+struct SpanNames(Arc<Mutex<Vec<&'static str>>>);
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanNames {
+	fn on_new_span(&self, attrs: &span::Attributes<'_>, _id: &span::Id, _ctx: Context<'_, S>) {
+		self.0.lock().unwrap().push(attrs.metadata().name())
+	}
+}
+
+/// Runs `f` and returns the names of the spans it created.
+fn spans_of(f: impl FnOnce()) -> Vec<&'static str> {
+	let names = SpanNames::default();
+	let subscriber = tracing_subscriber::registry().with(names.clone());
+	tracing::subscriber::with_default(subscriber, f);
+	let names = names.0.lock().unwrap();
+	names.clone()
+}
+
+#[test]
+fn parse_emits_a_span() {
+	let spans = spans_of(|| {
+		BorrowedValue::parse(r#"{ "a": [1, 2] }"#).unwrap();
+	});
+	assert_eq!(spans, ["parse"]);
+}
+
+#[test]
+fn canonicalize_emits_a_span() {
+	let value = BorrowedValue::parse(r#"{ "b": 1, "a": 2 }"#).unwrap();
+	let spans = spans_of(|| {
+		assert_eq!(canonicalize(&value).unwrap(), r#"{"a":2,"b":1}"#);
+	});
+	assert_eq!(spans, ["canonicalize"]);
+}
+
+#[test]
+fn diff_summary_emits_a_span() {
+	let a = BorrowedValue::parse("[1]").unwrap();
+	let b = BorrowedValue::parse("[2]").unwrap();
+	let spans = spans_of(|| {
+		a.diff_summary(&b);
+	});
+	assert_eq!(spans, ["diff_summary"]);
+}
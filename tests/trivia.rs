@@ -0,0 +1,61 @@
+use generic_json::{Json, JsonNew, NumberBuf, ParseErrorKind, Trivia, TriviaValue};
+
+const DOCUMENT: &str = r#"// Configuration.
+{
+	/* name */ "name" : "generic-json", // trailing
+	"tags": [ "a",
+		"b" /* last */ ],
+	"empty": {  },
+	"n": 1.50e+3
+}
+/* end */
+"#;
+
+#[test]
+fn round_trip_unchanged() {
+	let value = TriviaValue::parse(DOCUMENT).unwrap();
+	assert_eq!(value.to_string(), DOCUMENT);
+
+	assert_eq!(value["name"].as_str(), Some("generic-json"));
+	assert_eq!(value["n"].as_f64_lossy(), Some(1500.0));
+	assert_eq!(value.metadata().before, "// Configuration.\n");
+	assert_eq!(value.metadata().after, "\n/* end */\n");
+	assert_eq!(value["empty"].metadata().inner, "  ");
+}
+
+#[test]
+fn round_trip_scalars() {
+	for input in &[" null ", "true", "\t-0.0e-0\n", r#" "😀" "#, "[]", "{/**/}"] {
+		assert_eq!(TriviaValue::parse(input).unwrap().to_string(), *input)
+	}
+}
+
+#[test]
+fn modified_values_keep_trivia() {
+	let mut value = TriviaValue::parse(DOCUMENT).unwrap();
+	let trivia = value["n"].metadata().clone();
+	value["n"] = TriviaValue::number(NumberBuf::from(2u32), trivia);
+	value["tags"][0] = TriviaValue::string("c".to_string(), Trivia::default());
+
+	assert_eq!(
+		value.to_string(),
+		DOCUMENT
+			.replace("1.50e+3", "2")
+			.replace(r#"[ "a","#, r#"["c","#)
+	);
+}
+
+#[test]
+fn modified_strings_are_escaped() {
+	let mut value = TriviaValue::parse(r#"{ "a": "x\n" }"#).unwrap();
+	value["a"] = TriviaValue::string("\"\n".to_string(), Trivia::default());
+	assert_eq!(value.to_string(), r#"{ "a":"\"\n"}"#);
+}
+
+#[test]
+fn invalid_comments() {
+	let err = TriviaValue::parse("[1] /* unterminated").unwrap_err();
+	assert_eq!(err.kind(), ParseErrorKind::UnexpectedEnd);
+	assert!(TriviaValue::parse("[1] / 2").is_err());
+	assert!(TriviaValue::parse("[1] 2").is_err());
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{Json, ValueRef};
+use serde_json::json;
+
+/// Sums the numbers of the value, failing on the first number above `bound`.
+fn bounded_sum(value: &serde_json::Value, bound: f64) -> Result<f64, f64> {
+	value.try_fold_leaves(0.0, |sum, leaf| match leaf {
+		ValueRef::Number(n) => {
+			let n = generic_json::Number::as_f64_lossy(n);
+			if n > bound {
+				Err(n)
+			} else {
+				Ok(sum + n)
+			}
+		}
+		_ => Ok(sum),
+	})
+}
+
+#[test]
+fn successful_fold() {
+	let value = json!({ "a": [1, 2, { "b": 3.5 }], "c": "x", "d": null, "e": [] });
+	assert_eq!(bounded_sum(&value, 10.0), Ok(6.5));
+	assert_eq!(bounded_sum(&json!(4), 10.0), Ok(4.0));
+	assert_eq!(bounded_sum(&json!({}), 10.0), Ok(0.0));
+}
+
+#[test]
+fn early_error() {
+	let value = json!([1, [20, 30], 2]);
+	assert_eq!(bounded_sum(&value, 10.0), Err(20.0));
+
+	let mut visited = 0;
+	let result: Result<(), ()> = value.try_fold_leaves((), |(), _| {
+		visited += 1;
+		if visited == 2 {
+			Err(())
+		} else {
+			Ok(())
+		}
+	});
+	assert_eq!(result, Err(()));
+	assert_eq!(visited, 2);
+}
+
+#[test]
+fn leaves_in_document_order() {
+	let value = json!([null, [true, "s"], { "a": 1 }]);
+	let kinds = value.try_fold_leaves(Vec::new(), |mut kinds, leaf| {
+		kinds.push(leaf.kind());
+		Ok::<_, ()>(kinds)
+	});
+
+	use generic_json::ValueKind::*;
+	assert_eq!(kinds, Ok(vec![Null, Boolean, String, Number]));
+}
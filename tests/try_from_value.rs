@@ -0,0 +1,57 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{Json, TryFromArrayError, TryFromValueError};
+use serde_json::json;
+use std::{
+	convert::{TryFrom, TryInto},
+	error::Error,
+};
+
+#[test]
+fn scalars() {
+	assert_eq!(u64::try_from(json!(42).into_value()), Ok(42));
+	assert_eq!(i32::try_from(json!(-3).into_value()), Ok(-3));
+	assert_eq!(f64::try_from(json!(0.5).into_value()), Ok(0.5));
+	assert_eq!(bool::try_from(json!(true).into_value()), Ok(true));
+	assert_eq!(
+		String::try_from(json!("a").into_value()),
+		Ok("a".to_string())
+	);
+
+	let err = u32::try_from(json!(-1).into_value()).unwrap_err();
+	assert_eq!(err.expected(), "u32");
+	assert_eq!(err.to_string(), "value is not a valid `u32`");
+	assert!(u64::try_from(json!("1").into_value()).is_err());
+	assert!(bool::try_from(json!(null).into_value()).is_err());
+}
+
+#[test]
+fn array_of_numbers() {
+	let ids: Vec<u64> = json!([1, 2, 3]).into_value().try_into().unwrap();
+	assert_eq!(ids, [1, 2, 3]);
+
+	let nested: Vec<Vec<bool>> = json!([[true], []]).into_value().try_into().unwrap();
+	assert_eq!(nested, [vec![true], vec![]]);
+}
+
+#[test]
+fn invalid_element() {
+	let result: Result<Vec<u64>, _> = json!([1, "2", 3]).into_value().try_into();
+	let err = result.unwrap_err();
+	match &err {
+		TryFromArrayError::Element(1, e) => assert_eq!(e.expected(), "u64"),
+		_ => panic!("unexpected error {:?}", err),
+	}
+	assert_eq!(err.to_string(), "invalid array element at index 1");
+	assert_eq!(
+		err.source().unwrap().to_string(),
+		"value is not a valid `u64`"
+	);
+}
+
+#[test]
+fn not_an_array() {
+	let result: Result<Vec<u64>, TryFromArrayError<TryFromValueError>> =
+		json!({ "0": 1 }).into_value().try_into();
+	assert_eq!(result, Err(TryFromArrayError::NotAnArray));
+	assert!(result.unwrap_err().source().is_none());
+}
@@ -0,0 +1,50 @@
+#![cfg(all(feature = "nightly", feature = "serde_json-impl"))]
+use generic_json::Json;
+use serde_json::json;
+
+#[test]
+fn unwrap_under_key_path() {
+	let mut value = json!({
+		"items": [
+			{ "name": ["a"], "tags": ["x"] },
+			{ "name": ["b", "c"], "tags": ["y"] }
+		],
+		"name": ["root"]
+	});
+
+	value.unwrap_single_arrays(|path| path == ["items", "name"]);
+	assert_eq!(
+		value,
+		json!({
+			"items": [
+				{ "name": "a", "tags": ["x"] },
+				{ "name": ["b", "c"], "tags": ["y"] }
+			],
+			"name": ["root"]
+		})
+	)
+}
+
+#[test]
+fn visited_paths() {
+	let mut value = json!({ "a": [{ "b": [1] }], "c": [[2]], "d": [] });
+	let mut paths = Vec::new();
+	value.unwrap_single_arrays(|path| {
+		paths.push(path.join("/"));
+		false
+	});
+
+	paths.sort();
+	assert_eq!(paths, ["a", "a/b", "c", "c"]);
+}
+
+#[test]
+fn unwrap_once() {
+	let mut value = json!([[["x"]]]);
+	value.unwrap_single_arrays(|_| true);
+	assert_eq!(value, json!(["x"]));
+
+	let mut value = json!({ "a": [[1]], "b": [[1], [2]] });
+	value.unwrap_single_arrays(|_| true);
+	assert_eq!(value, json!({ "a": [1], "b": [1, 2] }));
+}
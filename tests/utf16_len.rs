@@ -0,0 +1,23 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::json;
+
+#[test]
+fn bmp_characters() {
+	assert_eq!(json!("").utf16_len(), Some(0));
+	assert_eq!(json!("abc").utf16_len(), Some(3));
+	assert_eq!(json!("é€中").utf16_len(), Some(3));
+}
+
+#[test]
+fn astral_characters() {
+	assert_eq!(json!("😀").utf16_len(), Some(2));
+	assert_eq!(json!("a😀b𝄞").utf16_len(), Some(6));
+	assert_eq!(json!("😀").as_value_ref().utf16_len(), Some(2));
+}
+
+#[test]
+fn not_a_string() {
+	assert_eq!(json!(12).utf16_len(), None);
+	assert_eq!(json!(["a"]).utf16_len(), None);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::Json;
+use serde_json::{json, Value};
+
+fn schema() -> Value {
+	json!({
+		"type": "object",
+		"required": ["id", "name"],
+		"properties": {
+			"id": { "type": "integer", "minimum": 1 },
+			"kind": { "enum": ["a", "b", 3] },
+			"name": { "type": "string" },
+			"tags": { "type": "array", "items": { "type": ["string", "null"] } }
+		}
+	})
+}
+
+fn errors(value: Value) -> Vec<(String, &'static str)> {
+	match value.validate_schema(&schema()) {
+		Ok(()) => Vec::new(),
+		Err(errors) => errors
+			.iter()
+			.map(|e| (e.pointer().to_string(), e.keyword()))
+			.collect(),
+	}
+}
+
+#[test]
+fn valid_instance() {
+	assert_eq!(
+		json!({ "id": 2.0, "kind": 3.0, "name": "x", "tags": ["y", null], "other": 1 })
+			.validate_schema(&schema()),
+		Ok(())
+	);
+}
+
+#[test]
+fn missing_required() {
+	assert_eq!(
+		errors(json!({ "name": "x" })),
+		[("/id".to_string(), "required")]
+	);
+	assert_eq!(
+		errors(json!({})),
+		[
+			("/id".to_string(), "required"),
+			("/name".to_string(), "required")
+		]
+	);
+}
+
+#[test]
+fn wrong_types() {
+	assert_eq!(errors(json!([])), [(String::new(), "type")]);
+	assert_eq!(
+		errors(json!({ "id": 1.5, "name": 1, "tags": ["a", 2] })),
+		[
+			("/id".to_string(), "type"),
+			("/name".to_string(), "type"),
+			("/tags/1".to_string(), "type")
+		]
+	);
+}
+
+#[test]
+fn enum_and_bounds() {
+	assert_eq!(
+		errors(json!({ "id": 0, "kind": "c", "name": "x" })),
+		[
+			("/id".to_string(), "minimum"),
+			("/kind".to_string(), "enum")
+		]
+	);
+
+	let schema = json!({ "maximum": 10 });
+	assert!(json!(10).validate_schema(&schema).is_ok());
+	assert_eq!(
+		json!(10.5).validate_schema(&schema).unwrap_err()[0].keyword(),
+		"maximum"
+	);
+	assert!(json!("11").validate_schema(&schema).is_ok());
+}
+
+#[test]
+fn escaped_pointers() {
+	let schema = json!({ "required": ["a/b"], "properties": { "~": { "type": "null" } } });
+	let errors = json!({ "~": 1 }).validate_schema(&schema).unwrap_err();
+	assert_eq!(errors[0].pointer(), "/a~1b");
+	assert_eq!(errors[1].pointer(), "/~0");
+	assert_eq!(errors[1].to_string(), "`type` failed at `/~0`");
+}
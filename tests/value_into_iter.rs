@@ -0,0 +1,46 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{Json, Value};
+use serde_json::json;
+
+fn value(value: serde_json::Value) -> Value<serde_json::Value> {
+	value.into_value()
+}
+
+#[test]
+fn consume_array() {
+	let items: Vec<Value<serde_json::Value>> =
+		value(json!([1, "a", [null], {}])).into_iter().collect();
+	assert_eq!(items.len(), 4);
+	assert_eq!(items[0].as_value_ref().as_u64(), Some(1));
+	assert_eq!(items[1].as_str(), Some("a"));
+	assert!(items[2].is_array());
+	assert!(items[3].is_object());
+
+	let nested: Vec<_> = items.into_iter().nth(2).unwrap().into_iter().collect();
+	assert_eq!(nested.len(), 1);
+	assert!(nested[0].is_null());
+}
+
+#[test]
+fn scalars_and_objects_yield_no_element() {
+	assert_eq!(value(json!(1)).into_iter().count(), 0);
+	assert_eq!(value(json!("a")).into_iter().count(), 0);
+	assert_eq!(value(json!({ "a": 1 })).into_iter().count(), 0);
+	assert_eq!(value(json!([])).into_iter().count(), 0);
+}
+
+#[test]
+fn consume_object() {
+	let mut members: Vec<_> = value(json!({ "b": [1], "a": true }))
+		.into_object_iter()
+		.collect();
+	members.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+	assert_eq!(members.len(), 2);
+	assert_eq!(members[0].0, "a");
+	assert_eq!(members[0].1.as_bool(), Some(true));
+	assert_eq!(members[1].0, "b");
+	assert_eq!(members[1].1.clone().into_iter().count(), 1);
+
+	assert_eq!(value(json!([1])).into_object_iter().count(), 0);
+}
@@ -0,0 +1,53 @@
+#![cfg(all(
+	feature = "nightly",
+	feature = "serde_json-impl",
+	feature = "ijson-impl"
+))]
+use generic_json::{to_backend, Json, Value};
+use ijson::IValue;
+use serde_json::json;
+
+fn ivalue(value: serde_json::Value) -> Value<IValue> {
+	to_backend::<_, IValue>(&value).into_value()
+}
+
+#[test]
+fn equal_across_backends() {
+	let value = json!({
+		"a": [null, true, "x", { "b": -3 }],
+		"c": 18446744073709551615u64,
+		"d": 0.5
+	});
+
+	assert!(value.as_value_ref() == ivalue(value.clone()));
+	assert!(ivalue(value.clone()).as_value_ref() == value.clone().into_value());
+}
+
+#[test]
+fn numbers_compared_by_value() {
+	assert!(json!(1).as_value_ref() == ivalue(json!(1.0)));
+	assert!(json!([-0.0]).as_value_ref() == ivalue(json!([0])));
+	assert!(json!(1).as_value_ref() != ivalue(json!(1.5)));
+	assert!(json!(-1).as_value_ref() != ivalue(json!(u64::MAX)));
+}
+
+#[test]
+fn objects_compared_by_key() {
+	let a = json!({ "x": 1, "y": [2] });
+	let b = ivalue(json!({ "y": [2], "x": 1 }));
+	assert!(a.as_value_ref() == b);
+
+	assert!(json!({ "x": 1 }).as_value_ref() != ivalue(json!({ "x": 1, "y": 2 })));
+	assert!(json!({ "x": 1, "y": 2 }).as_value_ref() != ivalue(json!({ "x": 1 })));
+	assert!(json!({ "x": 1 }).as_value_ref() != ivalue(json!({ "y": 1 })));
+	assert!(json!({ "x": 1 }).as_value_ref() != ivalue(json!({ "x": 2 })));
+}
+
+#[test]
+fn mismatches() {
+	assert!(json!([1, 2]).as_value_ref() != ivalue(json!([2, 1])));
+	assert!(json!([1]).as_value_ref() != ivalue(json!([1, 1])));
+	assert!(json!("1").as_value_ref() != ivalue(json!(1)));
+	assert!(json!(null).as_value_ref() != ivalue(json!(false)));
+	assert!(json!({}).as_value_ref() != ivalue(json!([])));
+}
@@ -0,0 +1,76 @@
+use generic_json::{Json, JsonNew, MetaValue, NumberBuf, ValueMut, ValueRef};
+
+fn meta_value() -> MetaValue {
+	// `{ "a": [1, { "b": "x" }] }`
+	vec![(
+		"a".to_string(),
+		vec![
+			MetaValue::number(NumberBuf::from(1u32), ()),
+			vec![("b".to_string(), MetaValue::string("x".to_string(), ()))]
+				.into_iter()
+				.collect(),
+		]
+		.into_iter()
+		.collect(),
+	)]
+	.into_iter()
+	.collect()
+}
+
+#[test]
+fn get_on_meta_value() {
+	let value = meta_value();
+	let a = value.as_value_ref().get_key("a").unwrap();
+	assert_eq!(a.get_index(0).unwrap().as_u64(), Some(1));
+	assert!(matches!(
+		a.get_index(1).and_then(|o| o.get_key("b")),
+		Some(ValueRef::String(s)) if s == "x"
+	));
+
+	assert!(a.get_index(2).is_none());
+	assert!(a.get_key("a").is_none());
+	assert!(value.as_value_ref().get_key("b").is_none());
+	assert!(value.as_value_ref().get_index(0).is_none());
+}
+
+#[test]
+fn get_mut_on_meta_value() {
+	let mut value = meta_value();
+	match value
+		.as_value_mut()
+		.get_mut_key("a")
+		.and_then(|a| a.get_mut_index(1))
+		.and_then(|o| o.get_mut_key("b"))
+	{
+		Some(ValueMut::String(s)) => s.push('y'),
+		_ => panic!("not a string"),
+	}
+
+	assert_eq!(value["a"][1]["b"].as_str(), Some("xy"));
+	assert!(value.as_value_mut().get_mut_index(0).is_none());
+	assert!(value.as_value_mut().get_mut_key("z").is_none());
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn get_on_serde_json() {
+	use serde_json::json;
+
+	let mut value = json!({ "a": [1, { "b": "x" }] });
+	let b = value
+		.as_value_ref()
+		.get_key("a")
+		.and_then(|a| a.get_index(1))
+		.and_then(|o| o.get_key("b"));
+	assert!(matches!(b, Some(ValueRef::String(s)) if s == "x"));
+
+	match value
+		.as_value_mut()
+		.get_mut_key("a")
+		.and_then(|a| a.get_mut_index(0))
+	{
+		Some(ValueMut::Number(n)) => *n = 2.into(),
+		_ => panic!("not a number"),
+	}
+	assert_eq!(value, json!({ "a": [2, { "b": "x" }] }));
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "serde_json-impl")]
+mod common;
+
+use common::TestValue;
+use generic_json::{valueref_to_serde, Json, JsonNew, MetaValue, NumberBuf};
+use serde_json::json;
+
+#[test]
+fn from_meta_value() {
+	let value: MetaValue = vec![
+		(
+			"a".to_string(),
+			MetaValue::number(NumberBuf::from(1u32), ()),
+		),
+		(
+			"b".to_string(),
+			vec![MetaValue::null(()), MetaValue::string("x".to_string(), ())]
+				.into_iter()
+				.collect(),
+		),
+		(
+			"c".to_string(),
+			MetaValue::number(NumberBuf::new("-0.25".to_string()).unwrap(), ()),
+		),
+	]
+	.into_iter()
+	.collect();
+
+	assert_eq!(
+		valueref_to_serde(value.as_value_ref()),
+		Ok(json!({ "a": 1, "b": [null, "x"], "c": -0.25 }))
+	);
+}
+
+#[test]
+fn reject_unrepresentable_numbers() {
+	#[cfg(not(feature = "arbitrary_precision"))]
+	{
+		let value: MetaValue = vec![MetaValue::number(
+			NumberBuf::new("1e400".to_string()).unwrap(),
+			(),
+		)]
+		.into_iter()
+		.collect();
+		let err = valueref_to_serde(value.as_value_ref()).unwrap_err();
+		assert_eq!(err.lexical(), "1e400");
+	}
+
+	let value = TestValue::object(vec![("a", TestValue::number(f64::INFINITY))]);
+	assert!(valueref_to_serde(value.as_value_ref()).is_err());
+	let value = TestValue::number(f64::NAN);
+	assert!(valueref_to_serde(value.as_value_ref()).is_err());
+}
+
+#[cfg(not(feature = "arbitrary_precision"))]
+#[test]
+fn reject_rounded_numbers() {
+	let value = MetaValue::number(
+		NumberBuf::new("123456789012345678901234567890".to_string()).unwrap(),
+		(),
+	);
+	assert!(valueref_to_serde(value.as_value_ref()).is_err());
+}
+
+#[cfg(feature = "arbitrary_precision")]
+#[test]
+fn keep_lexical_numbers() {
+	for lexical in &["123456789012345678901234567890", "1e+400", "0.10"] {
+		let value = MetaValue::number(NumberBuf::new(lexical.to_string()).unwrap(), ());
+		let converted = valueref_to_serde(value.as_value_ref()).unwrap();
+		assert_eq!(converted.to_string(), *lexical);
+	}
+}
+
+#[cfg(all(feature = "nightly", feature = "ijson-impl"))]
+#[test]
+fn from_ijson() {
+	let value = json!({ "a": [1, -2, 0.5, u64::MAX], "b": { "c": true } });
+	let ivalue: ijson::IValue = generic_json::to_backend(&value);
+	assert_eq!(valueref_to_serde(ivalue.as_value_ref()), Ok(value.clone()));
+	assert_eq!(
+		valueref_to_serde(ivalue["a"].as_value_ref()),
+		Ok(value["a"].clone())
+	);
+}
@@ -0,0 +1,107 @@
+use cc_traits::{Iter, MapIter};
+use generic_json::{Json, JsonNew, MetaValue, NumberBuf, Visit};
+
+/// Counts the strings of a value, object keys excluded.
+struct CountStrings;
+
+impl<T: Json> Visit<T> for CountStrings {
+	type Output = usize;
+
+	fn visit_default(&mut self) -> usize {
+		0
+	}
+
+	fn visit_string(&mut self, _s: &T::String) -> usize {
+		1
+	}
+
+	fn visit_array(&mut self, a: &T::Array) -> usize {
+		Iter::iter(a)
+			.map(|item| item.as_value_ref().accept(self))
+			.sum()
+	}
+
+	fn visit_object(&mut self, o: &T::Object) -> usize {
+		MapIter::iter(o)
+			.map(|(_, item)| item.as_value_ref().accept(self))
+			.sum()
+	}
+}
+
+/// Records the name of the visited method.
+struct Kind;
+
+impl<T: Json> Visit<T> for Kind {
+	type Output = &'static str;
+
+	fn visit_default(&mut self) -> &'static str {
+		"default"
+	}
+
+	fn visit_bool(&mut self, b: bool) -> &'static str {
+		if b {
+			"true"
+		} else {
+			"false"
+		}
+	}
+
+	fn visit_number(&mut self, _n: &T::Number) -> &'static str {
+		"number"
+	}
+}
+
+fn string(s: &str) -> MetaValue {
+	MetaValue::string(s.into(), ())
+}
+
+#[test]
+fn count_strings_in_tree() {
+	// `{ "a": ["x", 1, { "b": "y", "c": null }], "d": "z" }`
+	let value: MetaValue = vec![
+		(
+			"a".to_string(),
+			vec![
+				string("x"),
+				MetaValue::number(NumberBuf::from(1u32), ()),
+				vec![
+					("b".to_string(), string("y")),
+					("c".to_string(), MetaValue::null(())),
+				]
+				.into_iter()
+				.collect(),
+			]
+			.into_iter()
+			.collect(),
+		),
+		("d".to_string(), string("z")),
+	]
+	.into_iter()
+	.collect();
+
+	assert_eq!(value.as_value_ref().accept(&mut CountStrings), 3);
+	assert_eq!(string("x").as_value_ref().accept(&mut CountStrings), 1);
+	assert_eq!(
+		MetaValue::null(()).as_value_ref().accept(&mut CountStrings),
+		0
+	);
+}
+
+#[test]
+fn dispatch_and_defaults() {
+	let kind = |value: MetaValue| value.as_value_ref().accept(&mut Kind);
+	assert_eq!(kind(MetaValue::boolean(true, ())), "true");
+	assert_eq!(kind(MetaValue::boolean(false, ())), "false");
+	assert_eq!(kind(MetaValue::number(NumberBuf::from(0u32), ())), "number");
+	assert_eq!(kind(MetaValue::null(())), "default");
+	assert_eq!(kind(string("x")), "default");
+	assert_eq!(kind(MetaValue::empty_array(())), "default");
+	assert_eq!(kind(MetaValue::empty_object(())), "default");
+}
+
+#[cfg(feature = "serde_json-impl")]
+#[test]
+fn serde_json_backend() {
+	let value = serde_json::json!([["a", "b"], { "c": ["d"] }, 1]);
+	assert_eq!(value.as_value_ref().accept(&mut CountStrings), 3);
+}
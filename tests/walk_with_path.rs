@@ -0,0 +1,47 @@
+#![cfg(feature = "serde_json-impl")]
+use generic_json::{Json, ValueRef};
+use serde_json::json;
+
+#[test]
+fn string_leaf_paths() {
+	let value = json!({
+		"a": ["x", 1, { "b": "y" }],
+		"c/d": { "~e": "z" },
+		"f": null
+	});
+
+	let mut paths = Vec::new();
+	value.walk_with_path(|path, node| {
+		if let ValueRef::String(s) = node {
+			paths.push((path.to_string(), s.to_string()))
+		}
+	});
+
+	assert_eq!(
+		paths,
+		[
+			("/a/0".to_string(), "x".to_string()),
+			("/a/2/b".to_string(), "y".to_string()),
+			("/c~1d/~0e".to_string(), "z".to_string()),
+		]
+	)
+}
+
+#[test]
+fn visits_every_node_in_pre_order() {
+	let value = json!({ "a": [true, []], "b": {} });
+
+	let mut paths = Vec::new();
+	value.walk_with_path(|path, node| paths.push(format!("{} {}", path, node.kind())));
+
+	assert_eq!(
+		paths,
+		[
+			" object",
+			"/a array",
+			"/a/0 boolean",
+			"/a/1 array",
+			"/b object"
+		]
+	)
+}